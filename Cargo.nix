@@ -9367,6 +9367,27 @@ rec {
         ];
 
       };
+      "similar" = rec {
+        crateName = "similar";
+        version = "2.6.0";
+        edition = "2018";
+        sha256 = "0vk89dx2mmjp81pmszsa1s3mpzvbiy4krvfbq3s3mc3k27wd9q8x";
+        authors = [
+          "Armin Ronacher <armin.ronacher@active-4.com>"
+          "Pierre-Étienne Meunier <pe@pijul.org>"
+          "Brandon Williams <bwilliams.eng@gmail.com>"
+        ];
+        features = {
+          "bstr" = [ "dep:bstr" ];
+          "bytes" = [ "bstr" "text" ];
+          "default" = [ "text" ];
+          "inline" = [ "text" ];
+          "serde" = [ "dep:serde" ];
+          "unicode" = [ "text" "unicode-segmentation" "bstr?/unicode" "bstr?/std" ];
+          "unicode-segmentation" = [ "dep:unicode-segmentation" ];
+        };
+        resolvedDefaultFeatures = [ "default" "text" ];
+      };
       "siphasher" = rec {
         crateName = "siphasher";
         version = "0.3.11";
@@ -9641,6 +9662,10 @@ rec {
           "Stackable GmbH <info@stackable.tech>"
         ];
         dependencies = [
+          {
+            name = "base64";
+            packageId = "base64 0.22.1";
+          }
           {
             name = "bcrypt";
             packageId = "bcrypt";
@@ -9653,6 +9678,10 @@ rec {
             name = "helm-sys";
             packageId = "helm-sys";
           }
+          {
+            name = "http";
+            packageId = "http";
+          }
           {
             name = "indexmap";
             packageId = "indexmap";
@@ -9668,7 +9697,11 @@ rec {
             name = "kube";
             packageId = "kube";
             usesDefaultFeatures = false;
-            features = [ "client" "rustls-tls" "ws" "socks5" "http-proxy" ];
+            features = [ "client" "runtime" "rustls-tls" "ws" "socks5" "http-proxy" ];
+          }
+          {
+            name = "openssl-probe";
+            packageId = "openssl-probe";
           }
           {
             name = "rand";
@@ -9702,6 +9735,10 @@ rec {
             name = "sha2";
             packageId = "sha2";
           }
+          {
+            name = "similar";
+            packageId = "similar";
+          }
           {
             name = "snafu";
             packageId = "snafu 0.8.4";
@@ -9718,7 +9755,7 @@ rec {
           {
             name = "tokio";
             packageId = "tokio";
-            features = [ "rt-multi-thread" "macros" "fs" "process" "io-std" ];
+            features = [ "rt-multi-thread" "macros" "fs" "process" "io-std" "time" ];
           }
           {
             name = "tracing";
@@ -9738,6 +9775,11 @@ rec {
             optional = true;
             features = [ "indexmap" ];
           }
+          {
+            name = "uuid";
+            packageId = "uuid";
+            features = [ "v4" ];
+          }
           {
             name = "which";
             packageId = "which";
@@ -9844,7 +9886,7 @@ rec {
           {
             name = "tokio";
             packageId = "tokio";
-            features = [ "rt-multi-thread" "macros" "fs" "process" "io-std" ];
+            features = [ "rt-multi-thread" "macros" "fs" "process" "io-std" "time" ];
           }
           {
             name = "tower-http";
@@ -10177,7 +10219,7 @@ rec {
           {
             name = "tokio";
             packageId = "tokio";
-            features = [ "rt-multi-thread" "macros" "fs" "process" "io-std" ];
+            features = [ "rt-multi-thread" "macros" "fs" "process" "io-std" "time" ];
           }
           {
             name = "tracing";
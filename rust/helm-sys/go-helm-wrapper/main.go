@@ -6,14 +6,25 @@ package main
 import "C"
 
 import (
+	"bytes"
 	"context"
+	"crypto/tls"
+	"crypto/x509"
 	"encoding/json"
 	"fmt"
+	"io"
+	"net"
+	"net/http"
+	"os"
+	"strings"
 	"time"
 	"unsafe"
 
 	gohelm "github.com/mittwald/go-helm-client"
 	"helm.sh/helm/v3/pkg/action"
+	"helm.sh/helm/v3/pkg/downloader"
+	"helm.sh/helm/v3/pkg/getter"
+	"helm.sh/helm/v3/pkg/registry"
 	"helm.sh/helm/v3/pkg/repo"
 
 	// Needed for authentication against clusters, e.g. GCP
@@ -23,6 +34,22 @@ import (
 
 const HELM_ERROR_PREFIX = "ERROR:"
 
+// HTTP client used for all requests to Helm repositories and OCI registries,
+// see go_configure_transfer.
+var httpClient = http.DefaultClient
+
+// Transport and retry settings the httpClient is built from. Helm requires an
+// *http.Transport for repository requests, see repoTransport.
+var (
+	httpTransport  = http.DefaultTransport.(*http.Transport).Clone()
+	httpRetries    = 0
+	httpRetryDelay = time.Duration(0)
+)
+
+// Path of the repositories file used by the Helm client, which contains the
+// TLS settings of the configured repositories.
+var repositoryConfig = ""
+
 type Release struct {
 	Name        string `json:"name"`
 	Version     string `json:"version"`
@@ -39,10 +66,16 @@ func main() {
 func go_install_helm_release(releaseName *C.char, chartName *C.char, chartVersion *C.char, valuesYaml *C.char, namespace *C.char, suppressOutput bool) *C.char {
 	helmClient := getHelmClient(namespace, suppressOutput)
 
+	chartPath, cleanup, err := downloadChart(helmClient, C.GoString(chartName), C.GoString(chartVersion))
+	if err != nil {
+		return C.CString(fmt.Sprintf("%s%s", HELM_ERROR_PREFIX, err))
+	}
+	defer cleanup()
+
 	timeout, _ := time.ParseDuration("20m")
 	chartSpec := gohelm.ChartSpec{
 		ReleaseName: C.GoString(releaseName),
-		ChartName:   C.GoString(chartName),
+		ChartName:   chartPath,
 		Version:     C.GoString(chartVersion),
 		ValuesYaml:  C.GoString(valuesYaml),
 		Namespace:   C.GoString(namespace),
@@ -58,6 +91,45 @@ func go_install_helm_release(releaseName *C.char, chartName *C.char, chartVersio
 	return C.CString("")
 }
 
+// Renders the manifests of a Helm chart without installing it. The chart is
+// rendered client-side, so no connection to the cluster is required. If a
+// repoURL is provided, the chart is looked up in that repository directly,
+// so that the configured Helm repositories are left untouched. We also
+// introduce magic return values here. Any non-empty result string starting
+// with 'ERROR:' will be treated as an error by the Rust code and it will abort
+// operations.
+//
+//export go_template_helm_release
+func go_template_helm_release(releaseName *C.char, repoURL *C.char, chartName *C.char, chartVersion *C.char, valuesYaml *C.char, namespace *C.char) *C.char {
+	helmClient := getHelmClient(namespace, true)
+
+	chartRef, refVersion, err := resolveChartRef(C.GoString(repoURL), C.GoString(chartName), C.GoString(chartVersion))
+	if err != nil {
+		return C.CString(fmt.Sprintf("%s%s", HELM_ERROR_PREFIX, err))
+	}
+
+	chartPath, cleanup, err := downloadChart(helmClient, chartRef, refVersion)
+	if err != nil {
+		return C.CString(fmt.Sprintf("%s%s", HELM_ERROR_PREFIX, err))
+	}
+	defer cleanup()
+
+	chartSpec := gohelm.ChartSpec{
+		ReleaseName: C.GoString(releaseName),
+		ChartName:   chartPath,
+		Version:     C.GoString(chartVersion),
+		ValuesYaml:  C.GoString(valuesYaml),
+		Namespace:   C.GoString(namespace),
+	}
+
+	manifests, err := helmClient.TemplateChart(&chartSpec, nil)
+	if err != nil {
+		return C.CString(fmt.Sprintf("%s%s", HELM_ERROR_PREFIX, err))
+	}
+
+	return C.CString(string(manifests))
+}
+
 //export go_uninstall_helm_release
 func go_uninstall_helm_release(releaseName *C.char, namespace *C.char, suppressOutput bool) *C.char {
 	helmClient := getHelmClient(namespace, suppressOutput)
@@ -132,6 +204,79 @@ func go_add_helm_repo(name *C.char, url *C.char) *C.char {
 	return C.CString("")
 }
 
+// Returns if a Helm repo with the name and URL is configured in the
+// repositories file, without changing it.
+//
+//export go_helm_repo_exists
+func go_helm_repo_exists(name *C.char, url *C.char) bool {
+	helmClient := getHelmClient(C.CString("default"), true) // Namespace doesn't matter
+	settings := helmClient.(*gohelm.HelmClient).Settings
+
+	repoFile, err := repo.LoadFile(settings.RepositoryConfig)
+	if err != nil {
+		return false
+	}
+
+	entry := repoFile.Get(C.GoString(name))
+	return entry != nil && entry.URL == C.GoString(url)
+}
+
+// Configures the proxy, CA certificate, timeouts and retries used by all
+// subsequent requests to Helm repositories and OCI registries. The CA
+// certificates are trusted in addition to the system certificates. This needs
+// to be called before any other function, as the proxy environment variables
+// are only read once. We also introduce magic return values here. Any
+// non-empty result string starting with 'ERROR:' will be treated as an error
+// by the Rust code and it will abort operations.
+//
+//export go_configure_transfer
+func go_configure_transfer(proxy *C.char, caCert *C.char, connectTimeoutMs int64, readTimeoutMs int64, retries int64, retryDelayMs int64) *C.char {
+	if proxy := C.GoString(proxy); proxy != "" {
+		for _, key := range []string{"HTTP_PROXY", "HTTPS_PROXY"} {
+			if err := os.Setenv(key, proxy); err != nil {
+				return C.CString(fmt.Sprintf("%s%s", HELM_ERROR_PREFIX, err))
+			}
+		}
+	}
+
+	connectTimeout := time.Duration(connectTimeoutMs) * time.Millisecond
+	transport := http.DefaultTransport.(*http.Transport).Clone()
+	transport.DialContext = (&net.Dialer{Timeout: connectTimeout, KeepAlive: 30 * time.Second}).DialContext
+	transport.TLSHandshakeTimeout = connectTimeout
+	transport.ResponseHeaderTimeout = time.Duration(readTimeoutMs) * time.Millisecond
+
+	if caFile := C.GoString(caCert); caFile != "" {
+		pool, err := x509.SystemCertPool()
+		if err != nil {
+			return C.CString(fmt.Sprintf("%s%s", HELM_ERROR_PREFIX, err))
+		}
+
+		pem, err := os.ReadFile(caFile)
+		if err != nil {
+			return C.CString(fmt.Sprintf("%s%s", HELM_ERROR_PREFIX, err))
+		}
+
+		if !pool.AppendCertsFromPEM(pem) {
+			return C.CString(fmt.Sprintf("%sno CA certificates found in %s", HELM_ERROR_PREFIX, caFile))
+		}
+
+		transport.TLSClientConfig = &tls.Config{RootCAs: pool}
+	}
+
+	httpTransport = transport
+	httpRetries = int(retries)
+	httpRetryDelay = time.Duration(retryDelayMs) * time.Millisecond
+	httpClient = &http.Client{
+		Transport: &retryTransport{
+			base:    transport,
+			retries: httpRetries,
+			delay:   httpRetryDelay,
+		},
+	}
+
+	return C.CString("")
+}
+
 //export free_go_string
 func free_go_string(ptr *C.char) {
 	C.free(unsafe.Pointer(ptr))
@@ -152,5 +297,234 @@ func getHelmClient(namespace *C.char, suppressOutput bool) gohelm.Client {
 		panic(err)
 	}
 
+	// Use the configured HTTP client when downloading repository index files
+	repositoryConfig = helmClient.(*gohelm.HelmClient).Settings.RepositoryConfig
+	helmClient.(*gohelm.HelmClient).Providers = getterProviders()
+
 	return helmClient
 }
+
+// Downloads a chart from a Helm repository or OCI registry into a temporary
+// directory using the configured HTTP client. Helm itself would use a
+// separate client for each repository, which doesn't honor the configured CA
+// certificate, timeouts and retries. The returned function removes the
+// temporary directory.
+func downloadChart(helmClient gohelm.Client, chartName string, chartVersion string) (string, func(), error) {
+	settings := helmClient.(*gohelm.HelmClient).Settings
+
+	registryClient, err := registry.NewClient(
+		registry.ClientOptHTTPClient(httpClient),
+		registry.ClientOptCredentialsFile(settings.RegistryConfig),
+	)
+	if err != nil {
+		return "", nil, err
+	}
+
+	dir, err := os.MkdirTemp("", "stackable-chart-")
+	if err != nil {
+		return "", nil, err
+	}
+	cleanup := func() { os.RemoveAll(dir) }
+
+	chartDownloader := downloader.ChartDownloader{
+		Out:              io.Discard,
+		Verify:           downloader.VerifyNever,
+		Getters:          getterProviders(),
+		Options:          []getter.Option{getter.WithRegistryClient(registryClient)},
+		RegistryClient:   registryClient,
+		RepositoryConfig: settings.RepositoryConfig,
+		RepositoryCache:  settings.RepositoryCache,
+	}
+
+	chartPath, _, err := chartDownloader.DownloadTo(chartName, chartVersion, dir)
+	if err != nil {
+		cleanup()
+		return "", nil, err
+	}
+
+	return chartPath, cleanup, nil
+}
+
+// Resolves the reference and version of a chart which is looked up directly
+// in the Helm repository or OCI registry at repoURL, without using or changing
+// the configured Helm repositories. If repoURL is empty, the chart name refers
+// to a configured repository and is returned as is.
+func resolveChartRef(repoURL string, chartName string, chartVersion string) (string, string, error) {
+	if repoURL == "" {
+		return chartName, chartVersion, nil
+	}
+
+	if registry.IsOCI(repoURL) {
+		return strings.TrimSuffix(repoURL, "/") + "/" + chartName, chartVersion, nil
+	}
+
+	chartURL, err := repo.FindChartInRepoURL(repoURL, chartName, getterProviders(), repo.WithChartVersion(chartVersion))
+	if err != nil {
+		return "", "", err
+	}
+
+	// The chart URL already points to the resolved version
+	return chartURL, "", nil
+}
+
+// Returns the getters for Helm repositories and OCI registries. Requests to
+// Helm repositories use the configured transport, requests to OCI registries
+// use the registry client passed via the getter options.
+func getterProviders() getter.Providers {
+	return getter.Providers{
+		{
+			Schemes: []string{"http", "https"},
+			New: func(options ...getter.Option) (getter.Getter, error) {
+				return &httpGetter{options: options}, nil
+			},
+		},
+		{
+			Schemes: []string{registry.OCIScheme},
+			New:     getter.NewOCIGetter,
+		},
+	}
+}
+
+// Retrieves files from Helm repositories using Helm's HTTP getter, so that
+// the getter options like basic authentication are honored. The getter uses
+// the configured transport for the URL, see repoTransport.
+type httpGetter struct {
+	options []getter.Option
+}
+
+func (g *httpGetter) Get(url string, options ...getter.Option) (*bytes.Buffer, error) {
+	transport, err := repoTransport(url)
+	if err != nil {
+		return nil, err
+	}
+
+	allOptions := append([]getter.Option{}, g.options...)
+	allOptions = append(allOptions, options...)
+	allOptions = append(allOptions, getter.WithTransport(transport))
+
+	helmGetter, err := getter.NewHTTPGetter(allOptions...)
+	if err != nil {
+		return nil, err
+	}
+
+	return helmGetter.Get(url)
+}
+
+// Returns the transport used to retrieve url from a Helm repository. Helm
+// ignores the TLS getter options if a transport is provided, so the TLS
+// settings of the configured repository containing url are applied to a copy
+// of the configured transport instead. Helm only accepts an *http.Transport,
+// so the retries are added by registering the retrying transport for the
+// http and https schemes.
+func repoTransport(url string) (*http.Transport, error) {
+	base := httpTransport.Clone()
+
+	entry, err := findRepoEntry(url)
+	if err != nil {
+		return nil, err
+	}
+
+	if entry != nil && (entry.CAFile != "" || entry.CertFile != "" || entry.InsecureSkipTLSverify) {
+		tlsConfig := &tls.Config{}
+		if base.TLSClientConfig != nil {
+			tlsConfig = base.TLSClientConfig.Clone()
+		}
+
+		if entry.CAFile != "" {
+			pool := tlsConfig.RootCAs
+			if pool == nil {
+				if pool, err = x509.SystemCertPool(); err != nil {
+					return nil, err
+				}
+			}
+
+			pem, err := os.ReadFile(entry.CAFile)
+			if err != nil {
+				return nil, err
+			}
+
+			if !pool.AppendCertsFromPEM(pem) {
+				return nil, fmt.Errorf("no CA certificates found in %s", entry.CAFile)
+			}
+			tlsConfig.RootCAs = pool
+		}
+
+		if entry.CertFile != "" && entry.KeyFile != "" {
+			cert, err := tls.LoadX509KeyPair(entry.CertFile, entry.KeyFile)
+			if err != nil {
+				return nil, err
+			}
+			tlsConfig.Certificates = []tls.Certificate{cert}
+		}
+
+		tlsConfig.InsecureSkipVerify = entry.InsecureSkipTLSverify
+		base.TLSClientConfig = tlsConfig
+	}
+
+	// The transport only dispatches to the retrying transport, so HTTP/2 is
+	// disabled for it to not register its own https protocol
+	transport := &http.Transport{TLSNextProto: map[string]func(string, *tls.Conn) http.RoundTripper{}}
+	retrying := &retryTransport{base: base, retries: httpRetries, delay: httpRetryDelay}
+	transport.RegisterProtocol("http", retrying)
+	transport.RegisterProtocol("https", retrying)
+
+	return transport, nil
+}
+
+// Returns the configured repository containing url, if any.
+func findRepoEntry(url string) (*repo.Entry, error) {
+	// The repositories file doesn't exist until the first repo is added
+	if repositoryConfig == "" {
+		return nil, nil
+	}
+	if _, err := os.Stat(repositoryConfig); os.IsNotExist(err) {
+		return nil, nil
+	}
+
+	repoFile, err := repo.LoadFile(repositoryConfig)
+	if err != nil {
+		return nil, err
+	}
+
+	for _, entry := range repoFile.Repositories {
+		if strings.HasPrefix(url, strings.TrimSuffix(entry.URL, "/")+"/") {
+			return entry, nil
+		}
+	}
+
+	return nil, nil
+}
+
+// Retries GET and HEAD requests failing with connection errors, timeouts or
+// server errors. The delay between retries doubles with every attempt.
+type retryTransport struct {
+	base    http.RoundTripper
+	retries int
+	delay   time.Duration
+}
+
+func (t *retryTransport) RoundTrip(req *http.Request) (*http.Response, error) {
+	delay := t.delay
+
+	for attempt := 0; ; attempt++ {
+		resp, err := t.base.RoundTrip(req)
+
+		idempotent := req.Method == http.MethodGet || req.Method == http.MethodHead
+		failed := err != nil || resp.StatusCode >= http.StatusInternalServerError
+		if !idempotent || !failed || attempt >= t.retries {
+			return resp, err
+		}
+
+		if resp != nil {
+			resp.Body.Close()
+		}
+
+		select {
+		case <-req.Context().Done():
+			return nil, req.Context().Err()
+		case <-time.After(delay):
+		}
+
+		delay *= 2
+	}
+}
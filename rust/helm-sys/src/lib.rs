@@ -5,7 +5,10 @@
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
-use std::ffi::{c_char, CStr, CString};
+use std::{
+    ffi::{c_char, CStr, CString},
+    time::Duration,
+};
 
 pub const HELM_ERROR_PREFIX: &str = "ERROR:";
 
@@ -37,6 +40,35 @@ pub fn install_helm_release(
     }
 }
 
+pub fn template_helm_release(
+    release_name: &str,
+    repository_url: &str,
+    chart_name: &str,
+    chart_version: &str,
+    values_yaml: &str,
+    namespace: &str,
+) -> String {
+    let release_name = CString::new(release_name).unwrap();
+    let repository_url = CString::new(repository_url).unwrap();
+    let chart_name = CString::new(chart_name).unwrap();
+    let chart_version = CString::new(chart_version).unwrap();
+    let values_yaml = CString::new(values_yaml).unwrap();
+    let namespace = CString::new(namespace).unwrap();
+
+    unsafe {
+        let c = go_template_helm_release(
+            release_name.as_ptr() as *mut c_char,
+            repository_url.as_ptr() as *mut c_char,
+            chart_name.as_ptr() as *mut c_char,
+            chart_version.as_ptr() as *mut c_char,
+            values_yaml.as_ptr() as *mut c_char,
+            namespace.as_ptr() as *mut c_char,
+        );
+
+        cstr_ptr_to_string(c)
+    }
+}
+
 pub fn uninstall_helm_release(
     release_name: &str,
     namespace: &str,
@@ -91,6 +123,43 @@ pub fn add_helm_repository(repository_name: &str, repository_url: &str) -> Strin
     }
 }
 
+pub fn check_helm_repository_exists(repository_name: &str, repository_url: &str) -> bool {
+    let repository_name = CString::new(repository_name).unwrap();
+    let repository_url = CString::new(repository_url).unwrap();
+
+    unsafe {
+        go_helm_repo_exists(
+            repository_name.as_ptr() as *mut c_char,
+            repository_url.as_ptr() as *mut c_char,
+        ) != 0
+    }
+}
+
+pub fn configure_transfer(
+    proxy: &str,
+    ca_cert: &str,
+    connect_timeout: Duration,
+    read_timeout: Duration,
+    retries: u32,
+    retry_delay: Duration,
+) -> String {
+    let proxy = CString::new(proxy).unwrap();
+    let ca_cert = CString::new(ca_cert).unwrap();
+
+    unsafe {
+        let c = go_configure_transfer(
+            proxy.as_ptr() as *mut c_char,
+            ca_cert.as_ptr() as *mut c_char,
+            connect_timeout.as_millis() as i64,
+            read_timeout.as_millis() as i64,
+            retries as i64,
+            retry_delay.as_millis() as i64,
+        );
+
+        cstr_ptr_to_string(c)
+    }
+}
+
 /// Checks if the result string is an error, and if so, returns the error message as a string.
 pub fn to_helm_error(result: &str) -> Option<String> {
     if !result.is_empty() && result.starts_with(HELM_ERROR_PREFIX) {
@@ -5,8 +5,12 @@ use serde::{Deserialize, Serialize};
 use snafu::{ResultExt, Snafu};
 
 use crate::{
+    platform::install_record::SpecSource,
     utils::path::PathOrUrl,
-    xfer::{self, processor::Yaml},
+    xfer::{
+        self,
+        processor::{Processor, ProcessorError, Text, Yaml},
+    },
 };
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -15,12 +19,24 @@ type Result<T, E = Error> = std::result::Result<T, E>;
 pub enum Error {
     #[snafu(display("failed to transfer the list file"))]
     FileTransfer { source: xfer::Error },
+
+    #[snafu(display("failed to deserialize the list file {file}"))]
+    DeserializeList {
+        source: ProcessorError,
+        file: String,
+    },
 }
 
 pub trait SpecIter<S> {
     fn inner(self) -> IndexMap<String, S>;
 }
 
+/// Specs which reference other files, like manifests. Relative references
+/// are resolved against the file the spec is defined in.
+pub trait ResolveReferences {
+    fn resolve_references(&mut self, origin: &PathOrUrl);
+}
+
 /// A [`List`] describes a list of specs. The list can contain any specs, for
 /// example demos, stacks or releases. The generic parameter `L` represents
 /// the initial type of the spec list, directly deserialized from YAML. This
@@ -30,30 +46,45 @@ pub trait SpecIter<S> {
 pub struct List<L, S>
 where
     L: for<'a> Deserialize<'a> + Serialize + SpecIter<S>,
-    S: for<'a> Deserialize<'a> + Serialize + Clone,
+    S: for<'a> Deserialize<'a> + Serialize + Clone + ResolveReferences,
 {
     inner: IndexMap<String, S>,
     list_type: PhantomData<L>,
+
+    #[serde(skip)]
+    sources: Vec<SpecSource>,
 }
 
 impl<L, S> List<L, S>
 where
     L: for<'a> Deserialize<'a> + Serialize + SpecIter<S>,
-    S: for<'a> Deserialize<'a> + Serialize + Clone,
+    S: for<'a> Deserialize<'a> + Serialize + Clone + ResolveReferences,
 {
     /// Builds a list of specs of type `S` based on a list of files. These files
     /// can be located locally (on disk) or remotely. Remote files will get
-    /// downloaded.
+    /// downloaded. Relative references of the specs are resolved against the
+    /// file they are defined in. The content hash of each file is recorded,
+    /// see [`List::sources`].
     pub async fn build(files: &[PathOrUrl], transfer_client: &xfer::Client) -> Result<Self> {
         let mut map = IndexMap::new();
+        let mut sources = Vec::new();
 
         for file in files {
-            let specs = transfer_client
-                .get(file, &Yaml::<L>::new())
+            let content = transfer_client
+                .get(file, &Text)
                 .await
                 .context(FileTransferSnafu)?;
 
-            for (spec_name, spec) in specs.inner() {
+            sources.push(SpecSource::new(file.to_string(), &content));
+
+            let specs = Yaml::<L>::new()
+                .process(content)
+                .context(DeserializeListSnafu {
+                    file: file.to_string(),
+                })?;
+
+            for (spec_name, mut spec) in specs.inner() {
+                spec.resolve_references(file);
                 map.insert(spec_name, spec);
             }
         }
@@ -61,14 +92,40 @@ where
         Ok(Self {
             list_type: PhantomData,
             inner: map,
+            sources,
         })
     }
+
+    /// Returns the files this list was built from, together with the SHA-256
+    /// hash of the exact content the specs were deserialized from.
+    pub fn sources(&self) -> &[SpecSource] {
+        &self.sources
+    }
+}
+
+impl<L, S> List<L, S>
+where
+    L: for<'a> Deserialize<'a> + Serialize + SpecIter<S>,
+    S: for<'a> Deserialize<'a> + Serialize + Clone + ResolveReferences + FilterableSpec,
+{
+    /// Returns the list with only the specs matching the `filter`.
+    pub fn filter(self, filter: &ListFilter) -> Self {
+        Self {
+            inner: self
+                .inner
+                .into_iter()
+                .filter(|(name, spec)| filter.matches(name, spec))
+                .collect(),
+            list_type: PhantomData,
+            sources: self.sources,
+        }
+    }
 }
 
 impl<L, S> Deref for List<L, S>
 where
     L: for<'a> Deserialize<'a> + Serialize + SpecIter<S>,
-    S: for<'a> Deserialize<'a> + Serialize + Clone,
+    S: for<'a> Deserialize<'a> + Serialize + Clone + ResolveReferences,
 {
     type Target = IndexMap<String, S>;
 
@@ -76,3 +133,118 @@ where
         &self.inner
     }
 }
+
+/// Specs which can be filtered using a [`ListFilter`].
+pub trait FilterableSpec {
+    /// Returns the labels (tags) of the spec.
+    fn labels(&self) -> &[String];
+
+    /// Returns the description of the spec.
+    fn description(&self) -> &str;
+
+    /// Returns the release the spec, named `name`, belongs to. Specs which
+    /// don't belong to a specific release return [`None`].
+    fn release<'a>(&'a self, name: &'a str) -> Option<&'a str>;
+}
+
+/// Criteria to filter the specs of a [`List`]. Specs need to match all set
+/// criteria.
+#[derive(Debug, Default, Clone)]
+pub struct ListFilter {
+    /// Labels the spec must have (all of them). Labels are compared
+    /// case-insensitively.
+    pub labels: Vec<String>,
+
+    /// Text which must be contained in the name or description of the spec,
+    /// compared case-insensitively.
+    pub search: Option<String>,
+
+    /// The release the spec must belong to. A release also matches all more
+    /// specific releases, e.g. `24` matches `24.7` and `24.11`.
+    pub release: Option<String>,
+}
+
+impl ListFilter {
+    /// Returns if the spec named `name` matches this filter.
+    pub fn matches<S: FilterableSpec>(&self, name: &str, spec: &S) -> bool {
+        let has_labels = self.labels.iter().all(|label| {
+            spec.labels()
+                .iter()
+                .any(|spec_label| spec_label.eq_ignore_ascii_case(label))
+        });
+
+        let matches_search = self.search.as_ref().is_none_or(|search| {
+            let search = search.to_lowercase();
+
+            name.to_lowercase().contains(&search)
+                || spec.description().to_lowercase().contains(&search)
+        });
+
+        let matches_release = match (&self.release, spec.release(name)) {
+            (Some(release), Some(spec_release)) => {
+                spec_release == release
+                    || spec_release
+                        .strip_prefix(release.as_str())
+                        .is_some_and(|rest| rest.starts_with('.'))
+            }
+            _ => true,
+        };
+
+        has_labels && matches_search && matches_release
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    struct Spec {
+        labels: Vec<String>,
+        release: String,
+    }
+
+    impl FilterableSpec for Spec {
+        fn labels(&self) -> &[String] {
+            &self.labels
+        }
+
+        fn description(&self) -> &str {
+            "Data lakehouse using Trino and Apache Iceberg"
+        }
+
+        fn release<'a>(&'a self, _name: &'a str) -> Option<&'a str> {
+            Some(&self.release)
+        }
+    }
+
+    #[rstest]
+    #[case(&[], None, None, true)]
+    #[case(&["Trino"], None, None, true)]
+    #[case(&["trino", "kafka"], None, None, false)]
+    #[case(&[], Some("LAKEHOUSE"), None, true)]
+    #[case(&[], Some("iceberg"), None, true)]
+    #[case(&[], Some("druid"), None, false)]
+    #[case(&[], None, Some("24"), true)]
+    #[case(&[], None, Some("24.11"), true)]
+    #[case(&[], None, Some("24.1"), false)]
+    fn filter_matches(
+        #[case] labels: &[&str],
+        #[case] search: Option<&str>,
+        #[case] release: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let spec = Spec {
+            labels: vec!["trino".into(), "iceberg".into()],
+            release: "24.11".into(),
+        };
+        let filter = ListFilter {
+            labels: labels.iter().map(ToString::to_string).collect(),
+            search: search.map(Into::into),
+            release: release.map(Into::into),
+        };
+
+        assert_eq!(filter.matches("data-lakehouse", &spec), expected);
+    }
+}
@@ -1,12 +1,258 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "openapi")]
 use utoipa::ToSchema;
 
+use crate::{constants::DEFAULT_WAIT_TIMEOUT_SECONDS, utils::path::PathOrUrl};
+
+/// A single manifest entry of a demo or stack. Manifests are installed in the
+/// order they are listed. Optional wait conditions are evaluated after the
+/// manifest got installed and block the installation of subsequent manifests
+/// until they are met. Entries with a condition are only installed if the
+/// condition renders to `true`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ManifestSpec {
+    /// The source of the manifest, either a Helm chart or plain YAML
+    #[serde(flatten)]
+    pub source: ManifestSource,
+
+    /// A variable number of conditions which need to be met before the next
+    /// manifest is installed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wait_for: Vec<WaitCondition>,
+
+    /// A templated condition, like `{{ parameters.enableMonitoring }}`. The
+    /// manifest is only installed if the condition renders to `true`.
+    #[serde(rename = "if", default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+
+    /// Additional parameters which are only used to render this manifest.
+    /// They take precedence over the demo or stack parameters.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub parameters: BTreeMap<String, String>,
+}
+
+impl ManifestSpec {
+    /// Returns the demo or stack `parameters` extended by the parameters of
+    /// this manifest entry.
+    pub fn merge_parameters(
+        &self,
+        parameters: &HashMap<String, String>,
+    ) -> HashMap<String, String> {
+        let mut parameters = parameters.clone();
+        parameters.extend(self.parameters.clone());
+        parameters
+    }
+}
+
+/// Evaluates the `rendered` condition of a manifest entry. Conditions which
+/// render to `true` are met, conditions which render to `false` or an empty
+/// string are not met. Returns [`None`] for all other values.
+pub fn evaluate_condition(rendered: &str) -> Option<bool> {
+    match rendered.trim().to_lowercase().as_str() {
+        "true" => Some(true),
+        "false" | "" => Some(false),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
-pub enum ManifestSpec {
+pub enum ManifestSource {
     HelmChart(String),
     PlainYaml(String),
 }
+
+impl ManifestSource {
+    /// Resolves a relative manifest reference against the `origin` file the
+    /// demo or stack is defined in. See [`PathOrUrl::resolve`].
+    pub fn resolve(&mut self, origin: &PathOrUrl) {
+        match self {
+            ManifestSource::HelmChart(path) | ManifestSource::PlainYaml(path) => {
+                *path = origin.resolve(path)
+            }
+        }
+    }
+}
+
+impl Display for ManifestSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManifestSource::HelmChart(path) => write!(f, "Helm chart {path}"),
+            ManifestSource::PlainYaml(path) => write!(f, "YAML manifest {path}"),
+        }
+    }
+}
+
+/// A condition which needs to be met after installing a manifest. Each
+/// condition references a single object by name.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum WaitCondition {
+    /// Wait until all replicas of the Deployment are ready
+    DeploymentReady(WaitTarget),
+
+    /// Wait until all replicas of the StatefulSet are ready
+    StatefulSetReady(WaitTarget),
+
+    /// Wait until the Job completed successfully
+    JobCompleted(WaitTarget),
+
+    /// Wait until the CustomResourceDefinition is established
+    CrdEstablished(WaitTarget),
+}
+
+impl WaitCondition {
+    /// Returns the object targeted by this condition.
+    pub fn target(&self) -> &WaitTarget {
+        match self {
+            WaitCondition::DeploymentReady(target)
+            | WaitCondition::StatefulSetReady(target)
+            | WaitCondition::JobCompleted(target)
+            | WaitCondition::CrdEstablished(target) => target,
+        }
+    }
+}
+
+impl Display for WaitCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            WaitCondition::DeploymentReady(_) => "Deployment",
+            WaitCondition::StatefulSetReady(_) => "StatefulSet",
+            WaitCondition::JobCompleted(_) => "Job",
+            WaitCondition::CrdEstablished(_) => "CRD",
+        };
+
+        write!(f, "{kind} {name:?}", name = self.target().name)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct WaitTarget {
+    /// Name of the object to wait for
+    pub name: String,
+
+    /// Namespace of the object. Defaults to the product namespace. This is
+    /// ignored for cluster-scoped objects, like CRDs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// Maximum number of seconds to wait for the condition to be met
+    #[serde(default = "default_wait_timeout_seconds")]
+    pub timeout_seconds: u64,
+}
+
+fn default_wait_timeout_seconds() -> u64 {
+    DEFAULT_WAIT_TIMEOUT_SECONDS
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+    use serde::de::DeserializeOwned;
+
+    use super::*;
+
+    /// Deserializes the YAML input the same way demo and stack lists are
+    /// deserialized.
+    fn from_yaml<T: DeserializeOwned>(input: &str) -> T {
+        serde_yaml::with::singleton_map_recursive::deserialize(serde_yaml::Deserializer::from_str(
+            input,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn manifest_without_wait_conditions() {
+        let manifests: Vec<ManifestSpec> = from_yaml(
+            "- helmChart: stacks/_templates/minio.yaml\n- plainYaml: stacks/foo/bar.yaml",
+        );
+
+        assert!(
+            matches!(&manifests[0].source, ManifestSource::HelmChart(path) if path == "stacks/_templates/minio.yaml")
+        );
+        assert!(
+            matches!(&manifests[1].source, ManifestSource::PlainYaml(path) if path == "stacks/foo/bar.yaml")
+        );
+        assert!(manifests.iter().all(|m| m.wait_for.is_empty()));
+        assert!(manifests.iter().all(|m| m.condition.is_none()));
+    }
+
+    #[test]
+    fn manifest_with_condition_and_parameters() {
+        let manifest: ManifestSpec = from_yaml(
+            "helmChart: stacks/_templates/prometheus.yaml
+if: \"{{ parameters.enableMonitoring }}\"
+parameters:
+  retention: 7d",
+        );
+
+        assert_eq!(
+            manifest.condition.as_deref(),
+            Some("{{ parameters.enableMonitoring }}")
+        );
+
+        let parameters = HashMap::from([
+            ("retention".to_string(), "1d".to_string()),
+            ("enableMonitoring".to_string(), "true".to_string()),
+        ]);
+        let parameters = manifest.merge_parameters(&parameters);
+        assert_eq!(parameters["retention"], "7d");
+        assert_eq!(parameters["enableMonitoring"], "true");
+    }
+
+    #[rstest]
+    #[case("true", Some(true))]
+    #[case(" True\n", Some(true))]
+    #[case("false", Some(false))]
+    #[case("", Some(false))]
+    #[case("yes", None)]
+    fn evaluate_conditions(#[case] rendered: &str, #[case] expected: Option<bool>) {
+        assert_eq!(evaluate_condition(rendered), expected);
+    }
+
+    #[test]
+    fn manifest_with_wait_conditions() {
+        let manifest: ManifestSpec = from_yaml(
+            "plainYaml: stacks/foo/bar.yaml
+waitFor:
+  - deploymentReady:
+      name: minio
+      timeoutSeconds: 60
+  - jobCompleted:
+      name: create-bucket
+      namespace: other",
+        );
+
+        assert_eq!(manifest.wait_for.len(), 2);
+
+        match &manifest.wait_for[0] {
+            WaitCondition::DeploymentReady(target) => {
+                assert_eq!(target.name, "minio");
+                assert_eq!(target.namespace, None);
+                assert_eq!(target.timeout_seconds, 60);
+            }
+            condition => panic!("unexpected wait condition {condition:?}"),
+        }
+
+        match &manifest.wait_for[1] {
+            WaitCondition::JobCompleted(target) => {
+                assert_eq!(target.name, "create-bucket");
+                assert_eq!(target.namespace.as_deref(), Some("other"));
+                assert_eq!(target.timeout_seconds, DEFAULT_WAIT_TIMEOUT_SECONDS);
+            }
+            condition => panic!("unexpected wait condition {condition:?}"),
+        }
+    }
+}
@@ -2,6 +2,7 @@ use std::time::Duration;
 
 pub const REDACTED_PASSWORD: &str = "<redacted>";
 pub const PASSWORD_LENGTH: usize = 32;
+pub const GENERATED_PASSWORDS_SECRET_NAME: &str = "stackablectl-generated-passwords";
 
 pub const DEFAULT_OPERATOR_NAMESPACE: &str = "stackable-operators";
 // TODO (Techassi): Change this to "stackable" once we switch to this version.
@@ -11,11 +12,23 @@ pub const DEFAULT_OPERATOR_NAMESPACE: &str = "stackable-operators";
 pub const DEFAULT_PRODUCT_NAMESPACE: &str = "default";
 
 pub const DEFAULT_LOCAL_CLUSTER_NAME: &str = "stackable-data-platform";
+pub const DEFAULT_CLUSTER_DOMAIN: &str = "cluster.local";
 
 pub const DEFAULT_AUTO_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 15); // 15 minutes
 pub const DEFAULT_CACHE_MAX_AGE: Duration = Duration::from_secs(60 * 60); // One hour
+pub const DEFAULT_CACHE_STALE_MAX_AGE: Duration = Duration::from_secs(60 * 60 * 24 * 30); // 30 days
+pub const DEFAULT_CACHE_MAX_SIZE: u64 = 512 * 1024 * 1024; // 512 MiB
 pub const CACHE_LAST_AUTO_PURGE_FILEPATH: &str = ".cache-last-purge";
 pub const CACHE_PROTECTED_FILES: &[&str] = &[".cache-last-purge"];
+pub const CACHE_METADATA_SUFFIX: &str = ".meta";
+pub const CACHE_TEMPORARY_SUFFIX: &str = ".tmp";
+pub const CACHE_CHART_SOURCE_FRAGMENT: &str = "chart-source-metadata";
+
+pub const HTTP_USER_AGENT: &str = concat!("stackable-cockpit/", env!("CARGO_PKG_VERSION"));
+pub const DEFAULT_HTTP_RETRIES: u32 = 3;
+pub const DEFAULT_HTTP_RETRY_DELAY: Duration = Duration::from_millis(500);
+pub const DEFAULT_HTTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+pub const DEFAULT_HTTP_READ_TIMEOUT: Duration = Duration::from_secs(30);
 
 pub const HELM_REPO_NAME_STABLE: &str = "stackable-stable";
 pub const HELM_REPO_NAME_TEST: &str = "stackable-test";
@@ -27,6 +40,8 @@ pub const HELM_OCI_REGISTRY: &str = "oci://oci.stackable.tech/sdp-charts";
 
 pub const HELM_DEFAULT_CHART_VERSION: &str = ">0.0.0-0";
 
+pub const DEFAULT_WAIT_TIMEOUT_SECONDS: u64 = 60 * 10; // 10 minutes
+
 pub const PRODUCT_NAMES: &[&str] = &[
     "airflow",
     "druid",
@@ -7,8 +7,9 @@ use tracing::{debug, error, info, instrument};
 use url::Url;
 
 use crate::{
-    constants::{HELM_DEFAULT_CHART_VERSION, HELM_REPO_INDEX_FILE},
-    utils::chartsource::ChartSourceMetadata,
+    constants::{DEFAULT_HTTP_RETRY_DELAY, HELM_DEFAULT_CHART_VERSION, HELM_REPO_INDEX_FILE},
+    utils::{chartsource::ChartSourceMetadata, path::PathOrUrl},
+    xfer::{self, processor::Yaml},
 };
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -45,11 +46,14 @@ pub enum Error {
     #[snafu(display("failed to deserialize JSON data"))]
     DeserializeJson { source: serde_json::Error },
 
-    #[snafu(display("failed to deserialize YAML data"))]
-    DeserializeYaml { source: serde_yaml::Error },
+    #[snafu(display("failed to retrieve Helm index file"))]
+    FetchIndex { source: xfer::Error },
 
-    #[snafu(display("failed to retrieve remote content"))]
-    FetchRemoteContent { source: reqwest::Error },
+    #[snafu(display("failed to cache Helm index"))]
+    CacheIndex { source: xfer::Error },
+
+    #[snafu(display("failed to configure Helm transfer settings ({error})"))]
+    ConfigureTransfer { error: String },
 
     #[snafu(display("failed to add Helm repo ({error})"))]
     AddRepo { error: String },
@@ -62,6 +66,9 @@ pub enum Error {
 
     #[snafu(display("failed to uninstall Helm release ({error})"))]
     UninstallRelease { error: String },
+
+    #[snafu(display("failed to template Helm release ({error})"))]
+    TemplateRelease { error: String },
 }
 
 #[derive(Debug, Snafu)]
@@ -330,6 +337,55 @@ pub fn uninstall_release(
     ))
 }
 
+/// Renders the manifests of a Helm release from a repo without installing it.
+/// The chart is rendered client-side, so the returned manifests don't reflect
+/// the state of the cluster (e.g. lookups return no results). If a `repo_url`
+/// is provided, the chart is looked up in that repo directly, without adding
+/// it to the Helm repos. Otherwise, `chart_source` needs to be the name of an
+/// added Helm repo.
+#[instrument(skip(values_yaml))]
+pub fn template_release(
+    release_name: &str,
+    ChartVersion {
+        chart_source,
+        chart_name,
+        chart_version,
+    }: ChartVersion,
+    repo_url: Option<&str>,
+    values_yaml: Option<&str>,
+    namespace: &str,
+) -> Result<String, Error> {
+    debug!("Template Helm release");
+
+    let full_chart_name = match repo_url {
+        Some(_) => chart_name.to_string(),
+        None => format!("{chart_source}/{chart_name}"),
+    };
+    let chart_version = chart_version.unwrap_or(HELM_DEFAULT_CHART_VERSION);
+
+    let result = block_in_place(|| {
+        helm_sys::template_helm_release(
+            release_name,
+            repo_url.unwrap_or_default(),
+            &full_chart_name,
+            chart_version,
+            values_yaml.unwrap_or(""),
+            namespace,
+        )
+    });
+
+    if let Some(error) = helm_sys::to_helm_error(&result) {
+        error!(
+            "Go wrapper function go_template_helm_release encountered an error: {}",
+            error
+        );
+
+        return Err(Error::TemplateRelease { error });
+    }
+
+    Ok(result)
+}
+
 /// Returns if a Helm release exists
 #[instrument]
 pub fn check_release_exists(release_name: &str, namespace: &str) -> Result<bool, Error> {
@@ -368,6 +424,50 @@ pub fn get_release(release_name: &str, namespace: &str) -> Result<Option<Release
         .find(|r| r.name == release_name))
 }
 
+/// Configures the proxy, CA certificate, timeouts and retries of the
+/// `http_settings` for all requests to Helm repositories and OCI registries,
+/// like adding repositories and downloading charts. This needs to be called
+/// before any other Helm function.
+#[instrument]
+pub fn configure_transfer(http_settings: &xfer::HttpSettings) -> Result<(), Error> {
+    debug!("Configure Helm transfer settings");
+
+    let proxy = http_settings.proxy.as_deref().unwrap_or_default();
+    let ca_cert = http_settings
+        .ca_cert
+        .as_ref()
+        .map(|path| path.to_string_lossy())
+        .unwrap_or_default();
+
+    let result = helm_sys::configure_transfer(
+        proxy,
+        &ca_cert,
+        http_settings.connect_timeout,
+        http_settings.read_timeout,
+        http_settings.retries,
+        DEFAULT_HTTP_RETRY_DELAY,
+    );
+
+    if let Some(err) = helm_sys::to_helm_error(&result) {
+        error!(
+            "Go wrapper function go_configure_transfer encountered an error: {}",
+            err
+        );
+
+        return Err(Error::ConfigureTransfer { error: err });
+    }
+
+    Ok(())
+}
+
+/// Returns if a Helm repo with `repo_name` and `repo_url` was added already.
+#[instrument]
+pub fn check_repo_exists(repository_name: &str, repository_url: &str) -> bool {
+    debug!("Check if Helm repo exists");
+
+    helm_sys::check_helm_repository_exists(repository_name, repository_url)
+}
+
 /// Adds a Helm repo with `repo_name` and `repo_url`.
 #[instrument]
 pub fn add_repo(repository_name: &str, repository_url: &str) -> Result<(), Error> {
@@ -387,9 +487,15 @@ pub fn add_repo(repository_name: &str, repository_url: &str) -> Result<(), Error
     Ok(())
 }
 
-/// Retrieves the Helm index file from the repository URL.
-#[instrument]
-pub async fn get_helm_index<T>(repo_url: T) -> Result<ChartSourceMetadata, Error>
+/// Retrieves the Helm index file from the repository URL using the
+/// `transfer_client`. The chart versions extracted from the index file are
+/// cached separately, so that the (large) index file is only parsed again
+/// once the cache expired.
+#[instrument(skip(transfer_client))]
+pub async fn get_helm_index<T>(
+    repo_url: T,
+    transfer_client: &xfer::Client,
+) -> Result<ChartSourceMetadata, Error>
 where
     T: AsRef<str> + std::fmt::Debug,
 {
@@ -400,13 +506,24 @@ where
 
     debug!("Using {} to retrieve Helm index file", url);
 
-    // TODO (Techassi): Use the FileTransferClient for that
-    let index_file_content = reqwest::get(url)
+    let cache_key = ChartSourceMetadata::cache_key(&url);
+    if let Some(index) = transfer_client
+        .cached_value(&cache_key)
+        .await
+        .context(CacheIndexSnafu)?
+    {
+        return Ok(index);
+    }
+
+    let index: ChartSourceMetadata = transfer_client
+        .get(&PathOrUrl::Url(url), &Yaml::new())
         .await
-        .context(FetchRemoteContentSnafu)?
-        .text()
+        .context(FetchIndexSnafu)?;
+
+    transfer_client
+        .store_value(&cache_key, &index)
         .await
-        .context(FetchRemoteContentSnafu)?;
+        .context(CacheIndexSnafu)?;
 
-    serde_yaml::from_str(&index_file_content).context(DeserializeYamlSnafu)
+    Ok(index)
 }
@@ -11,22 +11,23 @@ use crate::{
         HELM_OCI_BASE, HELM_REPO_NAME_DEV, HELM_REPO_NAME_STABLE, HELM_REPO_NAME_TEST,
         OCI_INDEX_PAGE_SIZE,
     },
-    utils::chartsource::{ChartSourceEntry, ChartSourceMetadata},
+    utils::{
+        chartsource::{ChartSourceEntry, ChartSourceMetadata},
+        path::PathOrUrl,
+    },
+    xfer::{self, processor::Json},
 };
 
 #[derive(Debug, Snafu)]
 pub enum Error {
     #[snafu(display("cannot get repositories"))]
-    GetRepositories { source: reqwest::Error },
-
-    #[snafu(display("cannot parse repositories"))]
-    ParseRepositories { source: reqwest::Error },
+    GetRepositories { source: xfer::Error },
 
     #[snafu(display("cannot get artifacts"))]
-    GetArtifacts { source: reqwest::Error },
+    GetArtifacts { source: xfer::Error },
 
-    #[snafu(display("cannot parse artifacts"))]
-    ParseArtifacts { source: reqwest::Error },
+    #[snafu(display("cannot cache OCI index"))]
+    CacheIndex { source: xfer::Error },
 
     #[snafu(display("unexpected OCI repository name"))]
     UnexpectedOciRepositoryName,
@@ -117,7 +118,58 @@ impl OciUrlExt for Url {
     }
 }
 
-pub async fn get_oci_index<'a>() -> Result<HashMap<&'a str, ChartSourceMetadata>, Error> {
+/// Retrieves the index of all Helm charts published to the OCI registry using
+/// the `transfer_client`. The assembled index is cached, so that the registry
+/// is only paged through again once the cache expired.
+pub async fn get_oci_index<'a>(
+    transfer_client: &xfer::Client,
+) -> Result<HashMap<&'a str, ChartSourceMetadata>, Error> {
+    let base_url = format!("https://{}/api/v2.0", HELM_OCI_BASE);
+
+    // fetch all operators
+    let url = format!(
+        "{}/repositories?page_size={}&q=name=~sdp-charts/",
+        base_url, 100
+    );
+    let url = Url::parse(&url).context(UrlParseSnafu)?;
+
+    let cache_key = ChartSourceMetadata::cache_key(&url);
+    if let Some(mut index) = transfer_client
+        .cached_value::<HashMap<String, ChartSourceMetadata>>(&cache_key)
+        .await
+        .context(CacheIndexSnafu)?
+    {
+        let mut source_index_files = HashMap::new();
+        for repo_name in [
+            HELM_REPO_NAME_STABLE,
+            HELM_REPO_NAME_TEST,
+            HELM_REPO_NAME_DEV,
+        ] {
+            if let Some(metadata) = index.remove(repo_name) {
+                source_index_files.insert(repo_name, metadata);
+            }
+        }
+
+        return Ok(source_index_files);
+    }
+
+    let source_index_files = build_oci_index(&base_url, url, transfer_client).await?;
+
+    transfer_client
+        .store_value(&cache_key, &source_index_files)
+        .await
+        .context(CacheIndexSnafu)?;
+
+    Ok(source_index_files)
+}
+
+/// Pages through all repositories and artifacts of the OCI registry and
+/// groups the chart versions by stable, test and dev lines.
+async fn build_oci_index<'a>(
+    base_url: &str,
+    url: Url,
+    transfer_client: &xfer::Client,
+) -> Result<HashMap<&'a str, ChartSourceMetadata>, Error> {
     let mut source_index_files: HashMap<&str, ChartSourceMetadata> = HashMap::new();
 
     // initialize map
@@ -133,25 +185,11 @@ pub async fn get_oci_index<'a>() -> Result<HashMap<&'a str, ChartSourceMetadata>
             },
         );
     }
-    let base_url = format!("https://{}/api/v2.0", HELM_OCI_BASE);
-
-    // fetch all operators
-    let url = format!(
-        "{}/repositories?page_size={}&q=name=~sdp-charts/",
-        base_url, 100
-    );
-
-    // reuse connections
-    let client = reqwest::Client::new();
 
-    let repositories: Vec<OciRepository> = client
-        .get(&url)
-        .send()
+    let repositories: Vec<OciRepository> = transfer_client
+        .get(&PathOrUrl::Url(url), &Json::new())
         .await
-        .context(GetRepositoriesSnafu)?
-        .json()
-        .await
-        .context(ParseRepositoriesSnafu)?;
+        .context(GetRepositoriesSnafu)?;
 
     debug!("OCI repos {:?}", repositories);
 
@@ -168,17 +206,13 @@ pub async fn get_oci_index<'a>() -> Result<HashMap<&'a str, ChartSourceMetadata>
         let mut page = 1;
 
         loop {
-            let root = Url::parse(base_url.as_str()).context(UrlParseSnafu)?;
+            let root = Url::parse(base_url).context(UrlParseSnafu)?;
             let url =
                 root.oci_artifacts_page(project_name, repository_name, OCI_INDEX_PAGE_SIZE, page)?;
-            let artifacts_page = client
-                .get(url)
-                .send()
-                .await
-                .context(GetArtifactsSnafu)?
-                .json::<Vec<Artifact>>()
+            let artifacts_page: Vec<Artifact> = transfer_client
+                .get(&PathOrUrl::Url(url), &Json::new())
                 .await
-                .context(ParseArtifactsSnafu)?;
+                .context(GetArtifactsSnafu)?;
             let count = artifacts_page.len();
             artifacts.extend(artifacts_page);
             if count < OCI_INDEX_PAGE_SIZE {
@@ -0,0 +1,497 @@
+use k8s_openapi::{
+    api::{
+        apps::v1::{Deployment, StatefulSet},
+        core::v1::{PersistentVolumeClaim, PersistentVolumeClaimSpec},
+    },
+    apimachinery::pkg::api::resource::Quantity,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use snafu::{OptionExt, ResultExt, Snafu};
+use stackable_operator::{cpu::CpuQuantity, memory::MemoryQuantity};
+use tracing::debug;
+
+use crate::platform::cluster::{
+    bytes_to_quantity, parse_bytes, pod_requests, PodResourceRequests, ResourceRequests,
+};
+
+/// API group suffix of all Stackable custom resources
+const STACKABLE_API_GROUP_SUFFIX: &str = ".stackable.tech";
+
+#[derive(Debug, Snafu)]
+pub enum ResourceEstimateError {
+    /// This error indicates that a rendered manifest is not valid YAML.
+    #[snafu(display("failed to deserialize YAML manifest"))]
+    DeserializeManifest { source: serde_yaml::Error },
+
+    /// This error indicates that an object of a well-known kind, e.g. a
+    /// Deployment, doesn't match the expected schema.
+    #[snafu(display("failed to deserialize {kind} {name:?}"))]
+    DeserializeObject {
+        source: serde_json::Error,
+        kind: String,
+        name: String,
+    },
+
+    /// This error indicates that the resource requests of the containers of
+    /// a workload could not be parsed.
+    #[snafu(display("failed to parse resource requests of {kind} {name:?}"))]
+    ParseRequests {
+        source: super::Error,
+        kind: String,
+        name: String,
+    },
+
+    /// This error indicates that a resource quantity of an object could not
+    /// be parsed.
+    #[snafu(display("failed to parse quantity {quantity:?} of {kind} {name:?}"))]
+    ParseQuantity {
+        quantity: String,
+        kind: String,
+        name: String,
+    },
+}
+
+/// Resources requested by a single pod (replica) of a workload.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodResources {
+    pub cpu_millis: usize,
+    pub memory_bytes: f64,
+    pub storage_bytes: f64,
+}
+
+impl PodResources {
+    pub fn cpu(&self) -> CpuQuantity {
+        CpuQuantity::from_millis(self.cpu_millis)
+    }
+
+    pub fn memory(&self) -> MemoryQuantity {
+        bytes_to_quantity(self.memory_bytes)
+    }
+
+    pub fn storage(&self) -> MemoryQuantity {
+        bytes_to_quantity(self.storage_bytes)
+    }
+}
+
+/// The estimated resources of a single workload, e.g. a role group of a
+/// Stackable cluster or a Deployment.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadResources {
+    pub kind: String,
+    pub name: String,
+    pub replicas: usize,
+
+    /// Resources requested by each replica
+    pub per_replica: PodResources,
+}
+
+impl WorkloadResources {
+    /// Returns the resources requested by all replicas of the workload.
+    pub fn total(&self) -> PodResources {
+        PodResources {
+            cpu_millis: self.per_replica.cpu_millis * self.replicas,
+            memory_bytes: self.per_replica.memory_bytes * self.replicas as f64,
+            storage_bytes: self.per_replica.storage_bytes * self.replicas as f64,
+        }
+    }
+}
+
+/// [`ResourceEstimate`] contains the resources requested by the workloads of
+/// rendered manifests. Supported are Deployments, StatefulSets,
+/// PersistentVolumeClaims and the role groups of Stackable custom resources.
+/// Other objects are ignored.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ResourceEstimate {
+    pub workloads: Vec<WorkloadResources>,
+}
+
+impl ResourceEstimate {
+    /// Estimates the resources of all objects in the (multi-document) YAML
+    /// `manifests`.
+    pub fn from_manifests(manifests: &str) -> Result<Self, ResourceEstimateError> {
+        let mut workloads = Vec::new();
+
+        for document in serde_yaml::Deserializer::from_str(manifests) {
+            let object = Value::deserialize(document).context(DeserializeManifestSnafu)?;
+
+            if !object.is_null() {
+                workloads.extend(estimate_object(&object)?);
+            }
+        }
+
+        Ok(Self { workloads })
+    }
+
+    /// Adds all workloads of `other` to this estimate.
+    pub fn extend(&mut self, other: ResourceEstimate) {
+        self.workloads.extend(other.workloads)
+    }
+
+    /// Returns the resources requested by all replicas of all workloads.
+    pub fn total(&self) -> PodResources {
+        self.workloads.iter().map(WorkloadResources::total).fold(
+            PodResources::default(),
+            |total, workload| PodResources {
+                cpu_millis: total.cpu_millis + workload.cpu_millis,
+                memory_bytes: total.memory_bytes + workload.memory_bytes,
+                storage_bytes: total.storage_bytes + workload.storage_bytes,
+            },
+        )
+    }
+
+    /// Returns the resources of the pod requesting the most memory, using
+    /// the requested CPU as tie-breaker.
+    pub fn largest_pod(&self) -> Option<PodResources> {
+        self.workloads
+            .iter()
+            .filter(|workload| workload.replicas > 0)
+            .map(|workload| workload.per_replica)
+            .max_by(|a, b| {
+                a.memory_bytes
+                    .total_cmp(&b.memory_bytes)
+                    .then(a.cpu_millis.cmp(&b.cpu_millis))
+            })
+    }
+
+    /// Converts the estimate into [`ResourceRequests`], which can be
+    /// validated against the cluster and used in stack and demo specs.
+    pub fn to_resource_requests(&self) -> ResourceRequests {
+        let total = self.total();
+
+        ResourceRequests {
+            cpu: total.cpu().into(),
+            memory: total.memory().into(),
+            pvc: total.storage().into(),
+            storage_class: None,
+            largest_pod: self.largest_pod().map(|pod| PodResourceRequests {
+                cpu: pod.cpu().into(),
+                memory: pod.memory().into(),
+            }),
+        }
+    }
+}
+
+fn estimate_object(object: &Value) -> Result<Vec<WorkloadResources>, ResourceEstimateError> {
+    let api_version = object["apiVersion"].as_str().unwrap_or_default();
+    let kind = object["kind"].as_str().unwrap_or_default();
+    let name = object["metadata"]["name"].as_str().unwrap_or_default();
+
+    let workload = match kind {
+        "Deployment" => {
+            let deployment: Deployment = deserialize_object(object, kind, name)?;
+            let spec = deployment.spec.unwrap_or_default();
+
+            let (cpu_millis, memory_bytes) = match &spec.template.spec {
+                Some(pod_spec) => {
+                    pod_requests(pod_spec).context(ParseRequestsSnafu { kind, name })?
+                }
+                None => (0, 0.0),
+            };
+
+            WorkloadResources {
+                kind: kind.to_string(),
+                name: name.to_string(),
+                replicas: spec.replicas.unwrap_or(1) as usize,
+                per_replica: PodResources {
+                    cpu_millis,
+                    memory_bytes,
+                    storage_bytes: 0.0,
+                },
+            }
+        }
+        "StatefulSet" => {
+            let stateful_set: StatefulSet = deserialize_object(object, kind, name)?;
+            let spec = stateful_set.spec.unwrap_or_default();
+
+            let (cpu_millis, memory_bytes) = match &spec.template.spec {
+                Some(pod_spec) => {
+                    pod_requests(pod_spec).context(ParseRequestsSnafu { kind, name })?
+                }
+                None => (0, 0.0),
+            };
+
+            let mut storage_bytes = 0.0;
+            for claim in spec.volume_claim_templates.iter().flatten() {
+                if let Some(claim_spec) = &claim.spec {
+                    storage_bytes += claim_storage_bytes(claim_spec, kind, name)?;
+                }
+            }
+
+            WorkloadResources {
+                kind: kind.to_string(),
+                name: name.to_string(),
+                replicas: spec.replicas.unwrap_or(1) as usize,
+                per_replica: PodResources {
+                    cpu_millis,
+                    memory_bytes,
+                    storage_bytes,
+                },
+            }
+        }
+        "PersistentVolumeClaim" => {
+            let claim: PersistentVolumeClaim = deserialize_object(object, kind, name)?;
+
+            let storage_bytes = match &claim.spec {
+                Some(claim_spec) => claim_storage_bytes(claim_spec, kind, name)?,
+                None => 0.0,
+            };
+
+            WorkloadResources {
+                kind: kind.to_string(),
+                name: name.to_string(),
+                replicas: 1,
+                per_replica: PodResources {
+                    storage_bytes,
+                    ..Default::default()
+                },
+            }
+        }
+        _ if api_version
+            .split('/')
+            .next()
+            .is_some_and(|group| group.ends_with(STACKABLE_API_GROUP_SUFFIX)) =>
+        {
+            return estimate_stackable_resource(object, kind, name);
+        }
+        _ => return Ok(Vec::new()),
+    };
+
+    Ok(vec![workload])
+}
+
+/// Estimates the resources of a Stackable custom resource. Every field of the
+/// spec containing `roleGroups` is treated as a role. The resources of a role
+/// group are configured in `config.resources` of the role and can be
+/// overridden per role group.
+fn estimate_stackable_resource(
+    object: &Value,
+    kind: &str,
+    name: &str,
+) -> Result<Vec<WorkloadResources>, ResourceEstimateError> {
+    let Some(spec) = object["spec"].as_object() else {
+        return Ok(Vec::new());
+    };
+
+    let mut workloads = Vec::new();
+
+    for (role_name, role) in spec {
+        let Some(role_groups) = role["roleGroups"].as_object() else {
+            continue;
+        };
+
+        for (role_group_name, role_group) in role_groups {
+            let mut resources = role["config"]["resources"].clone();
+            merge_values(&mut resources, &role_group["config"]["resources"]);
+
+            if resources.is_null() {
+                debug!(
+                    kind,
+                    name,
+                    role = role_name,
+                    role_group = role_group_name,
+                    "No resources configured, the defaults of the operator are not included in the estimate"
+                );
+            }
+
+            workloads.push(WorkloadResources {
+                kind: kind.to_string(),
+                name: format!("{name}/{role_name}/{role_group_name}"),
+                replicas: role_group["replicas"].as_u64().unwrap_or(1) as usize,
+                per_replica: stackable_resources(&resources, kind, name)?,
+            });
+        }
+    }
+
+    Ok(workloads)
+}
+
+/// Parses the `resources` of a Stackable role group. The CPU request
+/// corresponds to `cpu.min` and the memory request to `memory.limit`. All
+/// `capacity` fields below `storage` are summed up, multiplied by their
+/// `count` (if set).
+fn stackable_resources(
+    resources: &Value,
+    kind: &str,
+    name: &str,
+) -> Result<PodResources, ResourceEstimateError> {
+    let cpu_millis = match as_quantity(&resources["cpu"]["min"]) {
+        Some(quantity) => CpuQuantity::try_from(&quantity)
+            .ok()
+            .context(ParseQuantitySnafu {
+                quantity: quantity.0.clone(),
+                kind,
+                name,
+            })?
+            .as_milli_cpus(),
+        None => 0,
+    };
+
+    let memory_bytes = match as_quantity(&resources["memory"]["limit"]) {
+        Some(quantity) => parse_bytes(&quantity).context(ParseQuantitySnafu {
+            quantity: quantity.0.clone(),
+            kind,
+            name,
+        })?,
+        None => 0.0,
+    };
+
+    Ok(PodResources {
+        cpu_millis,
+        memory_bytes,
+        storage_bytes: storage_bytes(&resources["storage"], kind, name)?,
+    })
+}
+
+fn storage_bytes(storage: &Value, kind: &str, name: &str) -> Result<f64, ResourceEstimateError> {
+    let Some(fields) = storage.as_object() else {
+        return Ok(0.0);
+    };
+
+    if let Some(quantity) = fields.get("capacity").and_then(as_quantity) {
+        let capacity = parse_bytes(&quantity).context(ParseQuantitySnafu {
+            quantity: quantity.0.clone(),
+            kind,
+            name,
+        })?;
+        let count = fields.get("count").and_then(Value::as_u64).unwrap_or(1);
+
+        return Ok(capacity * count as f64);
+    }
+
+    fields
+        .values()
+        .map(|value| storage_bytes(value, kind, name))
+        .sum()
+}
+
+fn claim_storage_bytes(
+    claim_spec: &PersistentVolumeClaimSpec,
+    kind: &str,
+    name: &str,
+) -> Result<f64, ResourceEstimateError> {
+    let storage = claim_spec
+        .resources
+        .as_ref()
+        .and_then(|resources| resources.requests.as_ref())
+        .and_then(|requests| requests.get("storage"));
+
+    match storage {
+        Some(quantity) => parse_bytes(quantity).context(ParseQuantitySnafu {
+            quantity: quantity.0.clone(),
+            kind,
+            name,
+        }),
+        None => Ok(0.0),
+    }
+}
+
+fn deserialize_object<T>(object: &Value, kind: &str, name: &str) -> Result<T, ResourceEstimateError>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_value(object.clone()).context(DeserializeObjectSnafu { kind, name })
+}
+
+/// Quantities in custom resources can be specified as strings or numbers.
+fn as_quantity(value: &Value) -> Option<Quantity> {
+    match value {
+        Value::String(quantity) => Some(Quantity(quantity.clone())),
+        Value::Number(quantity) => Some(Quantity(quantity.to_string())),
+        _ => None,
+    }
+}
+
+/// Recursively merges `overrides` into `base`. Fields of objects are merged,
+/// all other values are replaced.
+fn merge_values(base: &mut Value, overrides: &Value) {
+    match (base, overrides) {
+        (_, Value::Null) => {}
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                merge_values(base.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+        (base, overrides) => *base = overrides.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn estimate_rendered_manifests() {
+        let estimate = ResourceEstimate::from_manifests(
+            "
+apiVersion: hdfs.stackable.tech/v1alpha1
+kind: HdfsCluster
+metadata:
+  name: hdfs
+spec:
+  clusterConfig:
+    zookeeperConfigMapName: hdfs-znode
+  dataNodes:
+    config:
+      resources:
+        cpu:
+          min: 500m
+        memory:
+          limit: 1Gi
+        storage:
+          data:
+            capacity: 2Gi
+            count: 2
+    roleGroups:
+      default:
+        replicas: 2
+      large:
+        config:
+          resources:
+            memory:
+              limit: 4Gi
+---
+apiVersion: apps/v1
+kind: StatefulSet
+metadata:
+  name: postgresql
+spec:
+  selector: {}
+  serviceName: postgresql
+  template:
+    spec:
+      containers:
+        - name: postgresql
+          resources:
+            requests:
+              cpu: 250m
+              memory: 256Mi
+  volumeClaimTemplates:
+    - spec:
+        resources:
+          requests:
+            storage: 8Gi
+---
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: ignored
+",
+        )
+        .unwrap();
+
+        assert_eq!(estimate.workloads.len(), 3);
+        assert_eq!(estimate.workloads[0].name, "hdfs/dataNodes/default");
+
+        let total = estimate.total();
+        assert_eq!(total.cpu_millis, 1750);
+        assert_eq!(total.memory_bytes, 6.25 * 1024f64.powi(3));
+        assert_eq!(total.storage_bytes, 20.0 * 1024f64.powi(3));
+
+        let largest_pod = estimate.largest_pod().unwrap();
+        assert_eq!(largest_pod.cpu_millis, 500);
+        assert_eq!(largest_pod.memory_bytes, 4.0 * 1024f64.powi(3));
+    }
+}
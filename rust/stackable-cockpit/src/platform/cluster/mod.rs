@@ -1,11 +1,20 @@
-use k8s_openapi::api::core::v1::Node;
-use kube::core::ObjectList;
-use snafu::{ResultExt, Snafu};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use k8s_openapi::{
+    api::core::v1::{Container, Node, Pod, PodSpec},
+    apimachinery::pkg::api::resource::Quantity,
+};
+use kube::{core::ObjectList, ResourceExt};
+use snafu::{OptionExt, ResultExt, Snafu};
 use stackable_operator::{cpu::CpuQuantity, memory::MemoryQuantity};
 
+mod estimate;
 mod resource_request;
+mod storage;
 
+pub use estimate::*;
 pub use resource_request::*;
+pub use storage::*;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -20,6 +29,14 @@ pub enum Error {
     ParseNodeMemoryQuantity {
         source: stackable_operator::memory::Error,
     },
+
+    #[snafu(display("failed to parse pod cpu quantity"))]
+    ParsePodCpuQuantity {
+        source: stackable_operator::cpu::Error,
+    },
+
+    #[snafu(display("failed to parse pod memory quantity {quantity:?}"))]
+    ParsePodMemoryQuantity { quantity: String },
 }
 
 /// [`ClusterInfo`] contains information about the Kubernetes cluster, such as
@@ -34,6 +51,9 @@ pub struct ClusterInfo {
     /// not available when installing a stack or demo.
     pub untainted_node_count: usize,
 
+    /// Distinct CPU architectures of all nodes, e.g. `amd64` or `arm64`
+    pub node_architectures: BTreeSet<String>,
+
     /// Sum of allocatable cpu resources on all untainted nodes. Please note that allocatable
     /// is comparable to the total capacity of the node, not the free capacity!
     pub untainted_allocatable_cpu: CpuQuantity,
@@ -41,48 +61,345 @@ pub struct ClusterInfo {
     /// Sum of allocatable memory resources on all untainted nodes. Please note that allocatable
     /// is comparable to the total capacity of the node, not the free capacity!
     pub untainted_allocatable_memory: MemoryQuantity,
-    // TODO (Techassi + sbernauer): Take actual usage of nodes in consideration
-    // and calculate untainted_free_cpu and untainted_free_memory
+
+    /// Sum of free cpu resources on all untainted nodes, which is the allocatable cpu minus
+    /// the cpu requests of all pods running on the nodes.
+    pub untainted_free_cpu: CpuQuantity,
+
+    /// Sum of free memory resources on all untainted nodes, which is the allocatable memory
+    /// minus the memory requests of all pods running on the nodes.
+    pub untainted_free_memory: MemoryQuantity,
+
+    /// Allocatable and free resources of each untainted node
+    pub untainted_nodes: Vec<NodeResources>,
+}
+
+/// [`NodeResources`] contains the allocatable and free resources of a single
+/// node.
+#[derive(Debug)]
+pub struct NodeResources {
+    pub name: String,
+    pub allocatable_cpu: CpuQuantity,
+    pub allocatable_memory: MemoryQuantity,
+    pub free_cpu: CpuQuantity,
+    pub free_memory: MemoryQuantity,
 }
 
 impl ClusterInfo {
-    pub fn from_nodes(nodes: ObjectList<Node>) -> Result<Self> {
+    /// Creates [`ClusterInfo`] from the `nodes` of the cluster. The resource
+    /// requests of the `pods` are subtracted from the allocatable resources of
+    /// the nodes they are scheduled on. Callers should only pass pods which
+    /// are not terminated. Pods carrying all `excluded_labels` are skipped, so
+    /// that the pods of a previous installation of the same demo or stack are
+    /// counted as free resources.
+    pub fn from_nodes_and_pods(
+        nodes: ObjectList<Node>,
+        pods: ObjectList<Pod>,
+        excluded_labels: &BTreeMap<String, String>,
+    ) -> Result<Self> {
         // FIXME (Techassi): Also retrieve number of control plane nodes
         let node_count = nodes.items.len();
 
-        let untainted_nodes = nodes.into_iter().filter(|node| {
-            node.spec
-                .as_ref()
-                .and_then(|spec| spec.taints.as_ref().map(|taints| taints.is_empty()))
-                .unwrap_or(true)
-        });
-        let untainted_node_count = untainted_nodes.clone().count();
+        let node_architectures = nodes
+            .iter()
+            .filter_map(|node| node.status.as_ref()?.node_info.as_ref())
+            .map(|node_info| node_info.architecture.clone())
+            .collect();
 
-        let untainted_allocatable = untainted_nodes
+        let untainted_nodes: Vec<_> = nodes
             .into_iter()
-            .filter_map(|node| node.status)
-            .filter_map(|status| status.allocatable);
+            .filter(|node| {
+                node.spec
+                    .as_ref()
+                    .and_then(|spec| spec.taints.as_ref().map(|taints| taints.is_empty()))
+                    .unwrap_or(true)
+            })
+            .collect();
+        let untainted_node_count = untainted_nodes.len();
+
+        // Requested cpu (in millis) and memory (in bytes) per node name
+        let mut requests: HashMap<String, (usize, f64)> = HashMap::new();
+
+        for pod in &pods {
+            let Some(spec) = &pod.spec else {
+                continue;
+            };
+
+            let pod_labels = pod.labels();
+            if !excluded_labels.is_empty()
+                && excluded_labels
+                    .iter()
+                    .all(|(key, value)| pod_labels.get(key) == Some(value))
+            {
+                continue;
+            }
+
+            // Pods which are not scheduled yet don't request resources on
+            // any node
+            let Some(node_name) = &spec.node_name else {
+                continue;
+            };
+
+            let (cpu, memory) = pod_requests(spec)?;
+            let node_requests = requests.entry(node_name.clone()).or_default();
+            node_requests.0 += cpu;
+            node_requests.1 += memory;
+        }
 
         let mut untainted_allocatable_memory = MemoryQuantity::from_mebi(0.0);
         let mut untainted_allocatable_cpu = CpuQuantity::from_millis(0);
+        let mut untainted_free_memory = MemoryQuantity::from_mebi(0.0);
+        let mut untainted_free_cpu = CpuQuantity::from_millis(0);
+        let mut untainted_node_resources = Vec::new();
 
-        for mut node in untainted_allocatable {
-            if let Some(q) = node.remove("cpu") {
-                let cpu = CpuQuantity::try_from(q).context(ParseNodeCpuQuantitySnafu)?;
-                untainted_allocatable_cpu += cpu;
-            }
+        for node in untainted_nodes {
+            let name = node.name_any();
+            let mut allocatable = node
+                .status
+                .and_then(|status| status.allocatable)
+                .unwrap_or_default();
 
-            if let Some(q) = node.remove("memory") {
-                let memory = MemoryQuantity::try_from(q).context(ParseNodeMemoryQuantitySnafu)?;
-                untainted_allocatable_memory += memory;
-            }
+            let allocatable_cpu = match allocatable.remove("cpu") {
+                Some(q) => CpuQuantity::try_from(q).context(ParseNodeCpuQuantitySnafu)?,
+                None => CpuQuantity::from_millis(0),
+            };
+
+            let allocatable_memory = match allocatable.remove("memory") {
+                Some(q) => MemoryQuantity::try_from(q).context(ParseNodeMemoryQuantitySnafu)?,
+                None => MemoryQuantity::from_mebi(0.0),
+            };
+
+            let (requested_cpu, requested_memory) =
+                requests.get(&name).copied().unwrap_or_default();
+
+            // Nodes can be overcommitted, e.g. by static pods, so the free
+            // resources are capped at zero.
+            let free_cpu = CpuQuantity::from_millis(
+                allocatable_cpu
+                    .as_milli_cpus()
+                    .saturating_sub(requested_cpu),
+            );
+            let free_memory = match allocatable_memory - bytes_to_quantity(requested_memory) {
+                free if free > MemoryQuantity::from_mebi(0.0) => free,
+                _ => MemoryQuantity::from_mebi(0.0),
+            };
+
+            untainted_allocatable_cpu += allocatable_cpu;
+            untainted_allocatable_memory += allocatable_memory;
+            untainted_free_cpu += free_cpu;
+            untainted_free_memory += free_memory;
+
+            untainted_node_resources.push(NodeResources {
+                name,
+                allocatable_cpu,
+                allocatable_memory,
+                free_cpu,
+                free_memory,
+            });
         }
 
         Ok(ClusterInfo {
             node_count,
             untainted_node_count,
+            node_architectures,
             untainted_allocatable_cpu,
             untainted_allocatable_memory,
+            untainted_free_cpu,
+            untainted_free_memory,
+            untainted_nodes: untainted_node_resources,
         })
     }
+
+    /// Returns if at least one untainted node has enough free resources to
+    /// run a single pod requesting `cpu` and `memory`.
+    pub fn fits_on_single_node(&self, cpu: CpuQuantity, memory: MemoryQuantity) -> bool {
+        self.untainted_nodes
+            .iter()
+            .any(|node| node.free_cpu >= cpu && node.free_memory >= memory)
+    }
+}
+
+/// Returns the effective cpu (in millis) and memory (in bytes) requests of
+/// a pod with the given `spec`. Like the Kubernetes scheduler, this takes the
+/// maximum of the sum of all containers and each init container, plus the pod
+/// overhead.
+fn pod_requests(spec: &PodSpec) -> Result<(usize, f64)> {
+    let mut cpu = 0;
+    let mut memory = 0.0;
+
+    for container in &spec.containers {
+        let (container_cpu, container_memory) = container_requests(container)?;
+        cpu += container_cpu;
+        memory += container_memory;
+    }
+
+    for container in spec.init_containers.iter().flatten() {
+        let (container_cpu, container_memory) = container_requests(container)?;
+        cpu = cpu.max(container_cpu);
+        memory = f64::max(memory, container_memory);
+    }
+
+    let (overhead_cpu, overhead_memory) = parse_requests(spec.overhead.as_ref())?;
+
+    Ok((cpu + overhead_cpu, memory + overhead_memory))
+}
+
+fn container_requests(container: &Container) -> Result<(usize, f64)> {
+    parse_requests(
+        container
+            .resources
+            .as_ref()
+            .and_then(|resources| resources.requests.as_ref()),
+    )
+}
+
+fn parse_requests(requests: Option<&BTreeMap<String, Quantity>>) -> Result<(usize, f64)> {
+    let Some(requests) = requests else {
+        return Ok((0, 0.0));
+    };
+
+    let cpu = match requests.get("cpu") {
+        Some(q) => CpuQuantity::try_from(q)
+            .context(ParsePodCpuQuantitySnafu)?
+            .as_milli_cpus(),
+        None => 0,
+    };
+
+    let memory = match requests.get("memory") {
+        Some(q) => parse_bytes(q).context(ParsePodMemoryQuantitySnafu {
+            quantity: q.0.clone(),
+        })?,
+        None => 0.0,
+    };
+
+    Ok((cpu, memory))
+}
+
+#[cfg(test)]
+mod test {
+    use kube::core::ListMeta;
+
+    use super::*;
+
+    fn object_list<K>(yaml: &str) -> ObjectList<K>
+    where
+        K: serde::de::DeserializeOwned + Clone,
+    {
+        ObjectList {
+            metadata: ListMeta::default(),
+            items: serde_yaml::from_str(yaml).unwrap(),
+            types: Default::default(),
+        }
+    }
+
+    #[test]
+    fn subtract_pod_requests_from_nodes() {
+        let nodes = object_list::<Node>(
+            "
+- metadata:
+    name: control-plane
+  spec:
+    taints:
+      - key: node-role.kubernetes.io/control-plane
+        effect: NoSchedule
+  status:
+    allocatable:
+      cpu: '4'
+      memory: 8Gi
+- metadata:
+    name: worker-1
+  status:
+    allocatable:
+      cpu: '4'
+      memory: 8Gi
+- metadata:
+    name: worker-2
+  status:
+    allocatable:
+      cpu: '4'
+      memory: 8Gi",
+        );
+
+        let pods = object_list::<Pod>(
+            "
+- metadata:
+    name: app
+  spec:
+    nodeName: worker-1
+    initContainers:
+      - name: init
+        resources:
+          requests:
+            cpu: '2'
+    containers:
+      - name: main
+        resources:
+          requests:
+            cpu: 500m
+            memory: 2Gi
+      - name: sidecar
+        resources:
+          requests:
+            cpu: 500m
+            memory: 1Gi
+- metadata:
+    name: pending
+  spec:
+    containers:
+      - name: main
+        resources:
+          requests:
+            cpu: '1'
+- metadata:
+    name: previous-install
+    labels:
+      stackable.tech/stack: test
+      stackable.tech/vendor: Stackable
+  spec:
+    nodeName: worker-2
+    containers:
+      - name: main
+        resources:
+          requests:
+            cpu: '2'
+            memory: 4Gi
+- metadata:
+    name: other-stack
+    labels:
+      stackable.tech/stack: other
+      stackable.tech/vendor: Stackable
+  spec:
+    nodeName: worker-1
+    containers:
+      - name: main
+        resources:
+          requests:
+            cpu: 500m",
+        );
+
+        let excluded_labels = BTreeMap::from([
+            ("stackable.tech/stack".to_string(), "test".to_string()),
+            ("stackable.tech/vendor".to_string(), "Stackable".to_string()),
+        ]);
+        let cluster_info = ClusterInfo::from_nodes_and_pods(nodes, pods, &excluded_labels).unwrap();
+
+        assert_eq!(cluster_info.untainted_node_count, 2);
+        assert_eq!(
+            cluster_info.untainted_free_cpu,
+            CpuQuantity::from_millis(5500)
+        );
+        assert_eq!(
+            cluster_info.untainted_free_memory,
+            MemoryQuantity::from_gibi(13.0)
+        );
+
+        assert!(cluster_info.fits_on_single_node(
+            CpuQuantity::from_millis(4000),
+            MemoryQuantity::from_gibi(8.0)
+        ));
+        assert!(!cluster_info.fits_on_single_node(
+            CpuQuantity::from_millis(5000),
+            MemoryQuantity::from_gibi(1.0)
+        ));
+    }
 }
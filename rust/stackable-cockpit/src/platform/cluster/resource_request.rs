@@ -4,11 +4,16 @@ use std::fmt::Display;
 use utoipa::ToSchema;
 
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::ResourceExt;
 use serde::{Deserialize, Serialize};
-use snafu::{ResultExt, Snafu};
-use stackable_operator::{cpu::CpuQuantity, memory::MemoryQuantity};
+use snafu::{OptionExt, ResultExt, Snafu};
+use stackable_operator::{cpu::CpuQuantity, kvp::Labels, memory::MemoryQuantity};
+use tracing::{debug, warn};
 
-use crate::utils::k8s::{Client, Error};
+use crate::{
+    platform::cluster::{storage, PodResources},
+    utils::k8s::{Client, Error},
+};
 
 type Result<T, E = ResourceRequestsError> = std::result::Result<T, E>;
 
@@ -17,6 +22,7 @@ type Result<T, E = ResourceRequestsError> = std::result::Result<T, E>;
 /// how many CPU cores and how much memory and disk space are required to run
 /// the demo/stack.
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct ResourceRequests {
     #[cfg_attr(feature = "openapi", schema(value_type = String))]
@@ -27,6 +33,27 @@ pub struct ResourceRequests {
 
     #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub pvc: Quantity,
+
+    /// The StorageClass used by the PersistentVolumeClaims. The default
+    /// StorageClass of the cluster is used if not specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_class: Option<String>,
+
+    /// The resources requested by the largest single pod. If specified, at
+    /// least one node needs to have enough free resources to run this pod.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub largest_pod: Option<PodResourceRequests>,
+}
+
+/// CPU and memory requested by a single pod.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PodResourceRequests {
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub memory: Quantity,
+
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub cpu: Quantity,
 }
 
 impl Display for ResourceRequests {
@@ -59,6 +86,15 @@ pub enum ResourceRequestsError {
         source: stackable_operator::memory::Error,
     },
 
+    #[snafu(display("failed to parse memory resource requirements {quantity:?}"))]
+    ParseMemoryQuantity { quantity: String },
+
+    #[snafu(display("failed to parse pvc resource requirements {quantity:?}"))]
+    ParsePvcResourceRequirements { quantity: String },
+
+    #[snafu(display("failed to retrieve storage information"))]
+    StorageInformation { source: Error },
+
     #[snafu(display("invalid resource requirements"))]
     ValidationErrors {
         errors: Vec<ResourceRequestsValidationError>,
@@ -85,14 +121,120 @@ pub enum ResourceRequestsValidationError {
         required: MemoryQuantity,
         object_name: String,
     },
+
+    #[snafu(display(
+        "The {object_name} contains a pod which requires {required_cpu} CPU and {required_memory} of memory, but no node has enough free resources to run it"
+    ))]
+    InsufficientNodeResources {
+        required_cpu: CpuQuantity,
+        required_memory: MemoryQuantity,
+        object_name: String,
+    },
+
+    #[snafu(display(
+        "The {object_name} requires {required} of persistent storage, but there is no default StorageClass in the cluster"
+    ))]
+    NoDefaultStorageClass {
+        required: MemoryQuantity,
+        object_name: String,
+    },
+
+    #[snafu(display(
+        "The {object_name} requires the StorageClass {storage_class:?}, but it doesn't exist in the cluster"
+    ))]
+    MissingStorageClass {
+        storage_class: String,
+        object_name: String,
+    },
+
+    #[snafu(display(
+        "The {object_name} requires {required} of persistent storage, but there is only {available} of storage available in the StorageClass {storage_class:?}"
+    ))]
+    InsufficientStorage {
+        available: MemoryQuantity,
+        required: MemoryQuantity,
+        storage_class: String,
+        object_name: String,
+    },
 }
 
 impl ResourceRequests {
+    /// Adds up the `requests`, e.g. of a stack and the stacks it includes.
+    /// The largest pod of all `requests` is kept and the first StorageClass
+    /// specified is used.
+    pub fn sum<'a>(requests: impl IntoIterator<Item = &'a ResourceRequests>) -> Result<Self> {
+        let mut total = PodResources::default();
+        let mut storage_class = None;
+        let mut largest_pod: Option<(PodResources, &PodResourceRequests)> = None;
+
+        for requests in requests {
+            total.cpu_millis += CpuQuantity::try_from(&requests.cpu)
+                .context(ParseCpuResourceRequirementsSnafu)?
+                .as_milli_cpus();
+            total.memory_bytes +=
+                storage::parse_bytes(&requests.memory).context(ParseMemoryQuantitySnafu {
+                    quantity: requests.memory.0.clone(),
+                })?;
+            total.storage_bytes +=
+                storage::parse_bytes(&requests.pvc).context(ParsePvcResourceRequirementsSnafu {
+                    quantity: requests.pvc.0.clone(),
+                })?;
+
+            if storage_class.is_none() {
+                storage_class.clone_from(&requests.storage_class);
+            }
+
+            if let Some(pod) = &requests.largest_pod {
+                let resources = PodResources {
+                    cpu_millis: CpuQuantity::try_from(&pod.cpu)
+                        .context(ParseCpuResourceRequirementsSnafu)?
+                        .as_milli_cpus(),
+                    memory_bytes: storage::parse_bytes(&pod.memory).context(
+                        ParseMemoryQuantitySnafu {
+                            quantity: pod.memory.0.clone(),
+                        },
+                    )?,
+                    storage_bytes: 0.0,
+                };
+
+                let is_larger = match &largest_pod {
+                    Some((largest, _)) => resources
+                        .memory_bytes
+                        .total_cmp(&largest.memory_bytes)
+                        .then(resources.cpu_millis.cmp(&largest.cpu_millis))
+                        .is_gt(),
+                    None => true,
+                };
+                if is_larger {
+                    largest_pod = Some((resources, pod));
+                }
+            }
+        }
+
+        Ok(Self {
+            cpu: total.cpu().into(),
+            memory: total.memory().into(),
+            pvc: total.storage().into(),
+            storage_class,
+            largest_pod: largest_pod.map(|(_, pod)| pod.clone()),
+        })
+    }
+
     /// Validates the struct [`ResourceRequests`] by comparing the required
     /// resources to the available ones in the current cluster. `object_name`
-    /// should be `stack` or `demo`.
-    pub async fn validate_cluster_size(&self, client: &Client, object_name: &str) -> Result<()> {
-        let cluster_info = client.get_cluster_info().await.context(ClusterInfoSnafu)?;
+    /// should be `stack` or `demo`. Pods carrying the install `labels` of the
+    /// stack or demo are replaced by the installation, so their resources
+    /// are counted as available.
+    pub async fn validate_cluster_size(
+        &self,
+        client: &Client,
+        object_name: &str,
+        labels: &Labels,
+    ) -> Result<()> {
+        let cluster_info = client
+            .get_cluster_info(labels)
+            .await
+            .context(ClusterInfoSnafu)?;
 
         let stack_cpu =
             CpuQuantity::try_from(&self.cpu).context(ParseCpuResourceRequirementsSnafu)?;
@@ -105,26 +247,130 @@ impl ResourceRequests {
         // information during troubleshooting.
         let mut errors = Vec::new();
 
-        if stack_cpu > cluster_info.untainted_allocatable_cpu {
+        if stack_cpu > cluster_info.untainted_free_cpu {
             errors.push(ResourceRequestsValidationError::InsufficientCpu {
-                available: cluster_info.untainted_allocatable_cpu,
+                available: cluster_info.untainted_free_cpu,
                 object_name: object_name.to_string(),
                 required: stack_cpu,
             });
         }
 
-        if stack_memory > cluster_info.untainted_allocatable_memory {
+        if stack_memory > cluster_info.untainted_free_memory {
             errors.push(ResourceRequestsValidationError::InsufficientMemory {
-                available: cluster_info.untainted_allocatable_memory,
+                available: cluster_info.untainted_free_memory,
                 object_name: object_name.to_string(),
                 required: stack_memory,
             });
         }
 
+        if let Some(largest_pod) = &self.largest_pod {
+            let pod_cpu = CpuQuantity::try_from(&largest_pod.cpu)
+                .context(ParseCpuResourceRequirementsSnafu)?;
+            let pod_memory = MemoryQuantity::try_from(&largest_pod.memory)
+                .context(ParseMemoryResourceRequirementsSnafu)?;
+
+            if !cluster_info.fits_on_single_node(pod_cpu, pod_memory) {
+                errors.push(ResourceRequestsValidationError::InsufficientNodeResources {
+                    required_cpu: pod_cpu,
+                    required_memory: pod_memory,
+                    object_name: object_name.to_string(),
+                });
+            }
+        }
+
+        self.validate_storage(client, object_name, &mut errors)
+            .await?;
+
         if !errors.is_empty() {
             return Err(ResourceRequestsError::ValidationErrors { errors });
         }
 
         Ok(())
     }
+
+    /// Validates the required persistent storage. The StorageClass requested
+    /// by the stack or demo (or the default StorageClass) needs to exist. If
+    /// the capacity of the StorageClass is known, it needs to be sufficient.
+    /// Validation errors are added to `errors`.
+    async fn validate_storage(
+        &self,
+        client: &Client,
+        object_name: &str,
+        errors: &mut Vec<ResourceRequestsValidationError>,
+    ) -> Result<()> {
+        let required =
+            storage::parse_bytes(&self.pvc).context(ParsePvcResourceRequirementsSnafu {
+                quantity: self.pvc.0.clone(),
+            })?;
+
+        // Stacks and demos without persistent storage work with any cluster
+        if required == 0.0 {
+            return Ok(());
+        }
+
+        let storage_classes = match client.list_storage_classes().await {
+            Ok(storage_classes) => storage_classes.items,
+            Err(err) if err.is_forbidden() => {
+                warn!("Skipping storage validation, listing StorageClasses is not permitted");
+                return Ok(());
+            }
+            Err(err) => return Err(err).context(StorageInformationSnafu),
+        };
+
+        let storage_class = match &self.storage_class {
+            Some(name) => storage_classes
+                .iter()
+                .find(|storage_class| storage_class.metadata.name.as_ref() == Some(name)),
+            None => storage::default_storage_class(&storage_classes),
+        };
+
+        let Some(storage_class) = storage_class else {
+            errors.push(match &self.storage_class {
+                Some(name) => ResourceRequestsValidationError::MissingStorageClass {
+                    storage_class: name.clone(),
+                    object_name: object_name.to_string(),
+                },
+                None => ResourceRequestsValidationError::NoDefaultStorageClass {
+                    required: storage::bytes_to_quantity(required),
+                    object_name: object_name.to_string(),
+                },
+            });
+
+            return Ok(());
+        };
+
+        let csi_storage_capacities = match client.list_csi_storage_capacities().await {
+            Ok(csi_storage_capacities) => csi_storage_capacities.items,
+            Err(err) if err.is_forbidden() => {
+                warn!("Ignoring the published storage capacities, listing CSIStorageCapacities is not permitted");
+                Vec::new()
+            }
+            Err(err) => return Err(err).context(StorageInformationSnafu),
+        };
+        let nodes = client
+            .list_nodes()
+            .await
+            .context(StorageInformationSnafu)?
+            .items;
+
+        let storage_class_name = storage_class.name_any();
+
+        match storage::known_capacity(storage_class, &csi_storage_capacities, &nodes) {
+            Some(available) if required > available => {
+                errors.push(ResourceRequestsValidationError::InsufficientStorage {
+                    available: storage::bytes_to_quantity(available),
+                    required: storage::bytes_to_quantity(required),
+                    storage_class: storage_class_name,
+                    object_name: object_name.to_string(),
+                });
+            }
+            Some(_) => {}
+            None => debug!(
+                storage_class = storage_class_name,
+                "Capacity of StorageClass is unknown, skipping storage capacity validation"
+            ),
+        }
+
+        Ok(())
+    }
 }
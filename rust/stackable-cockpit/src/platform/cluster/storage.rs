@@ -0,0 +1,209 @@
+use k8s_openapi::{
+    api::{
+        core::v1::Node,
+        storage::v1::{CSIStorageCapacity, StorageClass},
+    },
+    apimachinery::pkg::api::resource::Quantity,
+};
+use kube::ResourceExt;
+use stackable_operator::memory::MemoryQuantity;
+
+/// Annotation which marks a [`StorageClass`] as the default one.
+const DEFAULT_STORAGE_CLASS_ANNOTATION: &str = "storageclass.kubernetes.io/is-default-class";
+
+/// Deprecated beta annotation which marks a [`StorageClass`] as the default
+/// one. Older clusters might still use it.
+const DEFAULT_STORAGE_CLASS_BETA_ANNOTATION: &str =
+    "storageclass.beta.kubernetes.io/is-default-class";
+
+/// Provisioner name of the local-path provisioner, which is used by kind and
+/// k3s. It provisions volumes on the local disk of the nodes.
+const LOCAL_PATH_PROVISIONER: &str = "rancher.io/local-path";
+
+const BYTES_PER_GIBI: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// Returns the default [`StorageClass`], if any. If multiple storage classes
+/// are marked as default, Kubernetes uses the most recently created one.
+pub fn default_storage_class(storage_classes: &[StorageClass]) -> Option<&StorageClass> {
+    storage_classes
+        .iter()
+        .filter(|storage_class| {
+            let annotations = storage_class.annotations();
+
+            [
+                DEFAULT_STORAGE_CLASS_ANNOTATION,
+                DEFAULT_STORAGE_CLASS_BETA_ANNOTATION,
+            ]
+            .iter()
+            .any(|key| annotations.get(*key).is_some_and(|value| value == "true"))
+        })
+        .max_by_key(|storage_class| storage_class.creation_timestamp())
+}
+
+/// Returns the storage capacity in bytes which is known to be available for
+/// the `storage_class`. CSI drivers can publish their capacity using
+/// [`CSIStorageCapacity`] objects. These are published per topology segment
+/// (e.g. per node or zone), which are not necessarily disjoint, so the largest
+/// capacity is used. For the local-path provisioner, the
+/// allocatable ephemeral storage of the untainted `nodes` is used as an
+/// estimate, as volumes are stored on the same disk. Returns [`None`] if the
+/// capacity is unknown.
+pub fn known_capacity(
+    storage_class: &StorageClass,
+    csi_storage_capacities: &[CSIStorageCapacity],
+    nodes: &[Node],
+) -> Option<f64> {
+    let storage_class_name = storage_class.name_any();
+
+    let csi_capacities: Vec<f64> = csi_storage_capacities
+        .iter()
+        .filter(|capacity| capacity.storage_class_name == storage_class_name)
+        .filter_map(|capacity| capacity.capacity.as_ref())
+        .filter_map(parse_bytes)
+        .collect();
+
+    if let Some(max_capacity) = csi_capacities.into_iter().reduce(f64::max) {
+        return Some(max_capacity);
+    }
+
+    if storage_class.provisioner == LOCAL_PATH_PROVISIONER {
+        let node_capacities = nodes
+            .iter()
+            .filter(|node| {
+                node.spec
+                    .as_ref()
+                    .and_then(|spec| spec.taints.as_ref().map(|taints| taints.is_empty()))
+                    .unwrap_or(true)
+            })
+            .filter_map(|node| node.status.as_ref())
+            .filter_map(|status| status.allocatable.as_ref())
+            .filter_map(|allocatable| allocatable.get("ephemeral-storage"))
+            .filter_map(parse_bytes);
+
+        return Some(node_capacities.sum());
+    }
+
+    None
+}
+
+/// Parses a storage or memory [`Quantity`] into bytes. In contrast to
+/// [`MemoryQuantity`], the full Kubernetes quantity format is supported,
+/// including decimal suffixes (e.g. `G`), decimal exponents (e.g. `1e9`) and
+/// plain byte values, which are commonly used for PVCs and container requests.
+/// Returns [`None`] if the quantity cannot be parsed.
+pub fn parse_bytes(quantity: &Quantity) -> Option<f64> {
+    let quantity = quantity.0.trim();
+    let unsigned = quantity.strip_prefix(['+', '-']).unwrap_or(quantity);
+
+    let start_of_suffix = unsigned
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(unsigned.len());
+    let (value, suffix) = quantity.split_at(quantity.len() - unsigned.len() + start_of_suffix);
+
+    let factor = match suffix {
+        "" => 1.0,
+        "m" => 1e-3,
+        "k" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        "P" => 1e15,
+        "E" => 1e18,
+        "Ki" => 1024f64,
+        "Mi" => 1024f64.powi(2),
+        "Gi" => 1024f64.powi(3),
+        "Ti" => 1024f64.powi(4),
+        "Pi" => 1024f64.powi(5),
+        "Ei" => 1024f64.powi(6),
+        _ => {
+            let exponent = suffix.strip_prefix(['e', 'E'])?;
+            10f64.powi(exponent.parse().ok()?)
+        }
+    };
+
+    value.parse::<f64>().ok().map(|value| value * factor)
+}
+
+/// Converts `bytes` into a [`MemoryQuantity`] to display storage sizes the
+/// same way as memory sizes.
+pub fn bytes_to_quantity(bytes: f64) -> MemoryQuantity {
+    MemoryQuantity::from_gibi((bytes / BYTES_PER_GIBI) as f32)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use kube::core::ObjectMeta;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn storage_class(name: &str, default: Option<&str>) -> StorageClass {
+        StorageClass {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                annotations: default.map(|value| {
+                    BTreeMap::from([(
+                        DEFAULT_STORAGE_CLASS_ANNOTATION.to_string(),
+                        value.to_string(),
+                    )])
+                }),
+                ..Default::default()
+            },
+            provisioner: LOCAL_PATH_PROVISIONER.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case("1073741824", Some(1073741824.0))]
+    #[case("1Gi", Some(1073741824.0))]
+    #[case("1.5Ki", Some(1536.0))]
+    #[case("10G", Some(10e9))]
+    #[case("500M", Some(500e6))]
+    #[case("1e9", Some(1e9))]
+    #[case("1.5E3", Some(1500.0))]
+    #[case("2e-3", Some(0.002))]
+    #[case("1E", Some(1e18))]
+    #[case("1500m", Some(1.5))]
+    #[case("+1Ki", Some(1024.0))]
+    #[case(".5Ki", Some(512.0))]
+    #[case("10X", None)]
+    #[case("1e", None)]
+    #[case("Gi", None)]
+    #[case(".", None)]
+    fn parse_storage_quantities(#[case] input: &str, #[case] expected: Option<f64>) {
+        assert_eq!(parse_bytes(&Quantity(input.to_string())), expected);
+    }
+
+    #[test]
+    fn use_largest_csi_storage_capacity() {
+        let storage_class = storage_class("csi", None);
+        let capacities = ["10Gi", "20Gi", "5Gi"].map(|capacity| CSIStorageCapacity {
+            storage_class_name: "csi".to_string(),
+            capacity: Some(Quantity(capacity.to_string())),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            known_capacity(&storage_class, &capacities, &[]),
+            Some(20.0 * BYTES_PER_GIBI)
+        );
+    }
+
+    #[test]
+    fn find_default_storage_class() {
+        let storage_classes = [
+            storage_class("standard", Some("false")),
+            storage_class("local-path", Some("true")),
+            storage_class("fast", None),
+        ];
+
+        assert_eq!(
+            default_storage_class(&storage_classes).map(|storage_class| storage_class.name_any()),
+            Some("local-path".to_string())
+        );
+        assert!(default_storage_class(&storage_classes[2..]).is_none());
+    }
+}
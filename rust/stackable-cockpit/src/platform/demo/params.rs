@@ -1,16 +1,51 @@
 use stackable_operator::kvp::Labels;
 
-use crate::platform::operator::ChartSourceType;
+use crate::platform::{
+    install_record::SpecSource, lockfile::Lockfile, operator::ChartSourceType, prune::PruneMode,
+};
 
 pub struct DemoInstallParameters {
+    pub demo_name: String,
+
     pub operator_namespace: String,
     pub product_namespace: String,
 
     pub stack_parameters: Vec<String>,
     pub parameters: Vec<String>,
     pub skip_release: bool,
+    pub rollback_on_failure: bool,
+
+    /// Skips estimating the resources of the demo from its rendered
+    /// manifests if the spec doesn't define resource requests. The resource
+    /// validation is skipped in that case.
+    pub skip_resource_estimate: bool,
+
+    /// Deletes (or only reports in dry-run mode) objects of previous
+    /// installations which are not part of the demo anymore.
+    pub prune: Option<PruneMode>,
 
     pub stack_labels: Labels,
     pub labels: Labels,
     pub chart_source: ChartSourceType,
+
+    /// Demo, stack and release files used to install the demo, together with
+    /// the hash of their content. These are persisted in the install record.
+    pub spec_sources: Vec<SpecSource>,
+
+    /// Installs exactly the files and versions recorded in the lockfile.
+    /// The installation fails if anything changed.
+    pub lockfile: Option<Lockfile>,
+}
+
+pub struct DemoDiffParameters {
+    pub product_namespace: String,
+
+    pub stack_parameters: Vec<String>,
+    pub parameters: Vec<String>,
+
+    pub stack_labels: Labels,
+    pub labels: Labels,
+
+    /// Renders Helm charts using the versions recorded in the lockfile.
+    pub lockfile: Option<Lockfile>,
 }
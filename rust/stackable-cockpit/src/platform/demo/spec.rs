@@ -6,19 +6,28 @@ use tracing::{debug, info, instrument, warn};
 use utoipa::ToSchema;
 
 use crate::{
-    common::manifest::ManifestSpec,
+    common::{
+        list::{FilterableSpec, ResolveReferences},
+        manifest::ManifestSpec,
+    },
     platform::{
-        cluster::{ResourceRequests, ResourceRequestsError},
-        demo::DemoInstallParameters,
-        manifests::{self, InstallManifestsExt},
+        cluster::{ResourceEstimate, ResourceRequests, ResourceRequestsError},
+        demo::{DemoDiffParameters, DemoInstallParameters},
+        diff::ObjectDiff,
+        install_record::{self, InstallRecord, InstallRecordKind},
+        lockfile::{self, LockedManifests},
+        manifests::{self, InstallManifestsExt, InstalledManifests},
+        prune::{self, ManagedObject},
         release::ReleaseList,
-        stack::{self, StackInstallParameters, StackList},
+        rollback::Rollback,
+        stack::{self, StackDiffParameters, StackInstallParameters, StackList, StackSpec},
     },
     utils::{
         k8s::Client,
         params::{
             IntoParameters, IntoParametersError, Parameter, RawParameter, RawParameterParseError,
         },
+        path::PathOrUrl,
     },
     xfer,
 };
@@ -44,11 +53,35 @@ pub enum Error {
     #[snafu(display("failed to parse demo / stack parameters"))]
     ParseParameters { source: IntoParametersError },
 
+    #[snafu(display("failed to resolve stacks included by stack '{name}'"))]
+    ResolveStackIncludes { source: stack::Error, name: String },
+
     #[snafu(display("failed to install stack"))]
     InstallStack { source: stack::Error },
 
     #[snafu(display("failed to install stack manifests"))]
     InstallManifests { source: manifests::Error },
+
+    #[snafu(display("failed to diff stack"))]
+    DiffStack { source: stack::Error },
+
+    #[snafu(display("failed to diff demo manifests"))]
+    DiffManifests { source: manifests::Error },
+
+    #[snafu(display("failed to estimate resources of stack"))]
+    EstimateStackResources { source: stack::Error },
+
+    #[snafu(display("failed to estimate resources of demo manifests"))]
+    EstimateResources { source: manifests::Error },
+
+    #[snafu(display("failed to write install record"))]
+    WriteInstallRecord { source: install_record::Error },
+
+    #[snafu(display("failed to prune orphaned objects"))]
+    PruneObjects { source: prune::Error },
+
+    #[snafu(display("failed to verify demo against the lockfile"))]
+    VerifyLockfile { source: lockfile::Error },
 }
 
 impl InstallManifestsExt for DemoSpec {}
@@ -90,18 +123,50 @@ pub struct DemoSpec {
     pub parameters: Vec<Parameter>,
 }
 
+impl ResolveReferences for DemoSpec {
+    fn resolve_references(&mut self, origin: &PathOrUrl) {
+        for manifest in &mut self.manifests {
+            manifest.source.resolve(origin);
+        }
+    }
+}
+
+impl FilterableSpec for DemoSpec {
+    fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    // Demos are filtered by release using the branch they are loaded from
+    fn release<'a>(&'a self, _name: &'a str) -> Option<&'a str> {
+        None
+    }
+}
+
 impl DemoSpec {
     /// Checks if the prerequisites to run this demo are met. These checks
     /// include:
     ///
     /// - Does the demo support to be installed in the requested namespace?
     /// - Does the cluster have enough resources available to run this demo?
+    ///
+    /// If the demo doesn't specify resource requests, they are estimated from
+    /// the rendered manifests of the demo and the underlying stack from the
+    /// `stack_list`, unless the estimate is skipped in the
+    /// `install_parameters`. In that case, the resource validation is skipped.
     #[instrument(skip_all)]
     pub async fn check_prerequisites(
         &self,
+        stack_list: &StackList,
+        install_parameters: &DemoInstallParameters,
         client: &Client,
-        product_namespace: &str,
+        transfer_client: &xfer::Client,
     ) -> Result<(), Error> {
+        let product_namespace = install_parameters.product_namespace.as_str();
+
         debug!("Checking prerequisites before installing demo");
 
         // Returns an error if the demo doesn't support to be installed in the
@@ -113,11 +178,38 @@ impl DemoSpec {
             });
         }
 
+        let resource_requests = match &self.resource_requests {
+            Some(resource_requests) => Some(resource_requests.clone()),
+            None if install_parameters.skip_resource_estimate => {
+                debug!("Demo doesn't specify resource requests, skipping validation");
+                None
+            }
+            None => {
+                match self
+                    .estimate_resources(
+                        stack_list,
+                        &install_parameters.stack_parameters,
+                        &install_parameters.parameters,
+                        product_namespace,
+                        Some(client),
+                        transfer_client,
+                    )
+                    .await
+                {
+                    Ok(estimate) => Some(estimate.to_resource_requests()),
+                    Err(err) => {
+                        warn!("Failed to estimate resource requests of demo ({err}), skipping validation");
+                        None
+                    }
+                }
+            }
+        };
+
         // Checks if the available cluster resources are sufficient to deploy
         // the demo.
-        if let Some(resource_requests) = &self.resource_requests {
+        if let Some(resource_requests) = &resource_requests {
             if let Err(err) = resource_requests
-                .validate_cluster_size(client, "demo")
+                .validate_cluster_size(client, "demo", &install_parameters.labels)
                 .await
             {
                 match err {
@@ -134,6 +226,10 @@ impl DemoSpec {
         Ok(())
     }
 
+    /// Installs the demo and the underlying stack. If pruning is enabled,
+    /// objects of previous installations which are not part of the demo or
+    /// stack anymore are deleted afterwards. Returns the pruned objects, or
+    /// the objects which would be pruned in dry-run mode.
     pub async fn install(
         &self,
         stack_list: StackList,
@@ -141,14 +237,85 @@ impl DemoSpec {
         install_parameters: DemoInstallParameters,
         client: &Client,
         transfer_client: &xfer::Client,
-    ) -> Result<(), Error> {
+    ) -> Result<Vec<ManagedObject>, Error> {
+        let mut record = self.install_record(&stack_list, &install_parameters)?;
+        let labels = install_parameters.labels.clone();
+        let prune_mode = install_parameters.prune;
+
+        let rollback = install_parameters
+            .rollback_on_failure
+            .then(Rollback::default);
+
+        let result = self
+            .install_with_rollback(
+                stack_list,
+                release_list,
+                install_parameters,
+                client,
+                transfer_client,
+                rollback.as_ref(),
+            )
+            .await;
+
+        if let (Err(err), Some(rollback)) = (&result, rollback) {
+            warn!("Failed to install demo ({err}), rolling back");
+
+            let failed = rollback.run(client).await;
+            if failed > 0 {
+                warn!("Failed to roll back {failed} change(s), manual cleanup required");
+            }
+        }
+        let installed = result?;
+
+        let pruned = match prune_mode {
+            Some(mode) => {
+                prune::prune_objects(&installed.objects, &record, &labels, false, mode, client)
+                    .await
+                    .context(PruneObjectsSnafu)?
+            }
+            None => Vec::new(),
+        };
+
+        record.manifests = installed.files;
+        record.kinds = prune::resource_kinds(&installed.objects);
+
+        record
+            .write(labels, client)
+            .await
+            .context(WriteInstallRecordSnafu)?;
+
+        Ok(pruned)
+    }
+
+    /// Installs the demo and the underlying stack while recording all changes
+    /// to the cluster in the provided `rollback`. Returns all objects applied
+    /// from plain YAML manifests and the installed manifest files of both the
+    /// demo and the stack.
+    async fn install_with_rollback(
+        &self,
+        stack_list: StackList,
+        release_list: ReleaseList,
+        install_parameters: DemoInstallParameters,
+        client: &Client,
+        transfer_client: &xfer::Client,
+        rollback: Option<&Rollback>,
+    ) -> Result<InstalledManifests, Error> {
         // Get the stack spec based on the name defined in the demo spec
-        let stack = stack_list.get(&self.stack).context(NoSuchStackSnafu {
-            name: self.stack.clone(),
-        })?;
+        let stack = self.get_stack(&stack_list)?;
+
+        // Verify the demo manifests against the lockfile before the stack is
+        // installed. The stack verifies its own manifests before installing
+        // the release. The verified content is installed afterwards.
+        let locked = lockfile::verify_manifests(
+            install_parameters.lockfile.as_ref(),
+            &self.manifests,
+            transfer_client,
+        )
+        .await
+        .context(VerifyLockfileSnafu)?;
 
         // Check demo prerequisites
-        self.check_prerequisites(client, &install_parameters.product_namespace)
+        self.check_prerequisites(&stack_list, &install_parameters, client, transfer_client)
             .await?;
 
         let stack_install_parameters = StackInstallParameters {
@@ -157,24 +324,43 @@ impl DemoSpec {
             parameters: install_parameters.stack_parameters.clone(),
             labels: install_parameters.stack_labels.clone(),
             skip_release: install_parameters.skip_release,
+            rollback_on_failure: install_parameters.rollback_on_failure,
+            // The stack is already part of the demo estimate
+            skip_resource_estimate: true,
             stack_name: self.stack.clone(),
-            demo_name: None,
+            demo_name: Some(install_parameters.demo_name.clone()),
+            spec_sources: install_parameters.spec_sources.clone(),
             chart_source: install_parameters.chart_source.clone(),
+            lockfile: install_parameters.lockfile.clone(),
+            // The stack objects are pruned together with the demo objects
+            prune: None,
         };
 
-        stack
-            .install(
+        let mut installed = stack
+            .install_with_rollback(
+                &stack_list,
                 release_list,
                 stack_install_parameters,
                 client,
                 transfer_client,
+                rollback,
             )
             .await
             .context(InstallStackSnafu)?;
 
         // Install demo manifests
-        self.prepare_manifests(install_parameters, client, transfer_client)
-            .await
+        let demo_installed = self
+            .prepare_manifests(
+                install_parameters,
+                client,
+                transfer_client,
+                rollback,
+                locked.as_ref(),
+            )
+            .await?;
+
+        installed.extend(demo_installed);
+        Ok(installed)
     }
 
     #[instrument(skip_all)]
@@ -183,7 +369,9 @@ impl DemoSpec {
         install_params: DemoInstallParameters,
         client: &Client,
         transfer_client: &xfer::Client,
-    ) -> Result<(), Error> {
+        rollback: Option<&Rollback>,
+        locked: Option<&LockedManifests>,
+    ) -> Result<InstalledManifests, Error> {
         info!("Installing demo manifests");
 
         let params = install_params
@@ -199,11 +387,178 @@ impl DemoSpec {
             install_params.labels,
             client,
             transfer_client,
+            rollback,
+            locked,
         )
         .await
         .context(InstallManifestsSnafu)
     }
 
+    /// Renders the manifests of the demo and the underlying stack from the
+    /// `stack_list` (including Helm charts) and estimates the resources
+    /// requested by the contained workloads. Nothing is installed. Without a
+    /// `client`, the manifests are rendered offline.
+    #[instrument(skip_all)]
+    pub async fn estimate_resources(
+        &self,
+        stack_list: &StackList,
+        stack_parameters: &[String],
+        parameters: &[String],
+        product_namespace: &str,
+        client: Option<&Client>,
+        transfer_client: &xfer::Client,
+    ) -> Result<ResourceEstimate, Error> {
+        let mut estimate = self
+            .get_stack(stack_list)?
+            .estimate_resources(
+                &self.stack,
+                stack_list,
+                stack_parameters,
+                product_namespace,
+                client,
+                transfer_client,
+            )
+            .await
+            .context(EstimateStackResourcesSnafu)?;
+
+        let parameters = parameters
+            .to_owned()
+            .into_params(&self.parameters)
+            .context(ParseParametersSnafu)?;
+
+        let demo_estimate = Self::estimate_manifest_resources(
+            &self.manifests,
+            &parameters,
+            product_namespace,
+            client,
+            transfer_client,
+        )
+        .await
+        .context(EstimateResourcesSnafu)?;
+
+        estimate.extend(demo_estimate);
+        Ok(estimate)
+    }
+
+    /// Compares the manifests of the demo and the underlying stack against the
+    /// live objects in the cluster without changing anything.
+    #[instrument(skip_all)]
+    pub async fn diff(
+        &self,
+        stack_list: &StackList,
+        diff_params: DemoDiffParameters,
+        client: &Client,
+        transfer_client: &xfer::Client,
+    ) -> Result<Vec<ObjectDiff>, Error> {
+        // Get the stack spec based on the name defined in the demo spec
+        let stack = self.get_stack(stack_list)?;
+
+        let stack_diff_params = StackDiffParameters {
+            stack_name: self.stack.clone(),
+            product_namespace: diff_params.product_namespace.clone(),
+            parameters: diff_params.stack_parameters,
+            labels: diff_params.stack_labels,
+            lockfile: diff_params.lockfile.clone(),
+        };
+
+        let mut diffs = stack
+            .diff(stack_list, stack_diff_params, client, transfer_client)
+            .await
+            .context(DiffStackSnafu)?;
+
+        info!("Diffing demo manifests");
+
+        let locked = lockfile::verify_manifests(
+            diff_params.lockfile.as_ref(),
+            &self.manifests,
+            transfer_client,
+        )
+        .await
+        .context(VerifyLockfileSnafu)?;
+
+        let parameters = diff_params
+            .parameters
+            .into_params(&self.parameters)
+            .context(ParseParametersSnafu)?;
+
+        let demo_diffs = Self::diff_manifests(
+            &self.manifests,
+            &parameters,
+            &diff_params.product_namespace,
+            diff_params.labels,
+            client,
+            transfer_client,
+            locked.as_ref(),
+        )
+        .await
+        .context(DiffManifestsSnafu)?;
+
+        diffs.extend(demo_diffs);
+        Ok(diffs)
+    }
+
+    /// Builds the install record which is persisted after the demo was
+    /// installed successfully.
+    fn install_record(
+        &self,
+        stack_list: &StackList,
+        install_params: &DemoInstallParameters,
+    ) -> Result<InstallRecord, Error> {
+        let stack = self.resolve_stack(stack_list)?;
+
+        let parameters = install_params
+            .parameters
+            .to_owned()
+            .into_params(&self.parameters)
+            .context(ParseParametersSnafu)?;
+
+        let stack_parameters = install_params
+            .stack_parameters
+            .to_owned()
+            .into_params(&stack.parameters)
+            .context(ParseParametersSnafu)?;
+
+        let mut record = InstallRecord::new(
+            InstallRecordKind::Demo,
+            &install_params.demo_name,
+            &stack.release,
+            &install_params.operator_namespace,
+            &install_params.product_namespace,
+        );
+
+        record.stack = Some(self.stack.clone());
+        record.parameters = install_record::public_parameters(&parameters, &self.parameters);
+        record.stack_parameters =
+            install_record::public_parameters(&stack_parameters, &stack.parameters);
+        record.sources = install_params.spec_sources.clone();
+
+        if let Some(lockfile) = &install_params.lockfile {
+            lockfile
+                .verify_target(InstallRecordKind::Demo, &install_params.demo_name)
+                .and_then(|_| lockfile.verify_sources(&record.sources))
+                .context(VerifyLockfileSnafu)?;
+        }
+
+        Ok(record)
+    }
+
+    /// Returns the stack of this demo from the `stack_list`.
+    fn get_stack<'a>(&self, stack_list: &'a StackList) -> Result<&'a StackSpec, Error> {
+        stack_list.get(&self.stack).context(NoSuchStackSnafu {
+            name: self.stack.clone(),
+        })
+    }
+
+    /// Returns the stack of this demo from the `stack_list`, with all stacks
+    /// it includes resolved.
+    fn resolve_stack(&self, stack_list: &StackList) -> Result<StackSpec, Error> {
+        self.get_stack(stack_list)?
+            .resolve_includes(&self.stack, stack_list)
+            .context(ResolveStackIncludesSnafu {
+                name: self.stack.clone(),
+            })
+    }
+
     fn supports_namespace(&self, namespace: impl Into<String>) -> bool {
         self.supported_namespaces.is_empty()
             || self.supported_namespaces.contains(&namespace.into())
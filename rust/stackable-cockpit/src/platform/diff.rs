@@ -0,0 +1,264 @@
+use std::fmt::Display;
+
+use kube::core::DynamicObject;
+use serde::Serialize;
+use serde_json::Value;
+use similar::TextDiff;
+use snafu::{ResultExt, Snafu};
+
+use crate::platform::prune::ManagedObject;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    /// This error indicates that an object could not be serialized to YAML
+    /// to compare it.
+    #[snafu(display("failed to serialize {object} to YAML"))]
+    SerializeObject {
+        source: serde_yaml::Error,
+        object: ManagedObject,
+    },
+
+    /// This error indicates that an object could not be converted into a
+    /// generic value to strip fields before comparing it.
+    #[snafu(display("failed to convert {object} into a generic value"))]
+    ConvertObject {
+        source: serde_json::Error,
+        object: ManagedObject,
+    },
+}
+
+/// An object of a manifest which was applied using a server-side dry-run.
+#[derive(Debug)]
+pub struct DryRunObject {
+    pub object: ManagedObject,
+
+    /// The current state of the object, [`None`] if it doesn't exist yet
+    pub live: Option<DynamicObject>,
+
+    /// The state the object would have after applying the manifest
+    pub dry_run: DynamicObject,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ObjectDiffStatus {
+    /// The object doesn't exist yet and would be created
+    New,
+
+    /// The object exists and would be changed
+    Changed,
+
+    /// The object exists and would stay the same
+    Unchanged,
+}
+
+impl Display for ObjectDiffStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectDiffStatus::New => write!(f, "new"),
+            ObjectDiffStatus::Changed => write!(f, "changed"),
+            ObjectDiffStatus::Unchanged => write!(f, "unchanged"),
+        }
+    }
+}
+
+/// The difference between the live state of an object and the state it
+/// would have after applying a demo or stack.
+#[derive(Clone, Debug, Serialize)]
+pub struct ObjectDiff {
+    pub object: ManagedObject,
+    pub status: ObjectDiffStatus,
+
+    /// Unified diff of the YAML representation of the object. This is empty
+    /// for unchanged objects.
+    pub diff: String,
+}
+
+impl TryFrom<DryRunObject> for ObjectDiff {
+    type Error = Error;
+
+    fn try_from(dry_run_object: DryRunObject) -> Result<Self, Self::Error> {
+        let DryRunObject {
+            object,
+            live,
+            dry_run,
+        } = dry_run_object;
+
+        let old = match &live {
+            Some(live) => to_comparable_yaml(live, &object)?,
+            None => String::new(),
+        };
+        let new = to_comparable_yaml(&dry_run, &object)?;
+
+        let status = match live {
+            None => ObjectDiffStatus::New,
+            Some(_) if old == new => ObjectDiffStatus::Unchanged,
+            Some(_) => ObjectDiffStatus::Changed,
+        };
+
+        let diff = match status {
+            ObjectDiffStatus::Unchanged => String::new(),
+            _ => TextDiff::from_lines(&old, &new)
+                .unified_diff()
+                .header("live", "applied")
+                .to_string(),
+        };
+
+        Ok(Self {
+            object,
+            status,
+            diff,
+        })
+    }
+}
+
+/// Metadata fields which are set by the API server and change independently
+/// of the manifests.
+const SERVER_METADATA_FIELDS: &[&str] = &[
+    "creationTimestamp",
+    "generation",
+    "managedFields",
+    "resourceVersion",
+    "uid",
+];
+
+/// Annotations which are set by the API server, controllers or kubectl and
+/// are not part of the manifests.
+const SERVER_ANNOTATIONS: &[&str] = &[
+    "deployment.kubernetes.io/revision",
+    "kubectl.kubernetes.io/last-applied-configuration",
+];
+
+/// Serializes the `object` into YAML, leaving out fields which are not
+/// controlled by the manifests, namely server-side metadata, annotations set
+/// by the server and the status.
+fn to_comparable_yaml(object: &DynamicObject, managed: &ManagedObject) -> Result<String, Error> {
+    let mut value = serde_json::to_value(object).context(ConvertObjectSnafu {
+        object: managed.clone(),
+    })?;
+
+    if let Value::Object(fields) = &mut value {
+        fields.remove("status");
+
+        if let Some(Value::Object(metadata)) = fields.get_mut("metadata") {
+            for field in SERVER_METADATA_FIELDS {
+                metadata.remove(*field);
+            }
+
+            if let Some(Value::Object(annotations)) = metadata.get_mut("annotations") {
+                for annotation in SERVER_ANNOTATIONS {
+                    annotations.remove(*annotation);
+                }
+
+                if annotations.is_empty() {
+                    metadata.remove("annotations");
+                }
+            }
+        }
+    }
+
+    serde_yaml::to_string(&value).context(SerializeObjectSnafu {
+        object: managed.clone(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dynamic_object(yaml: &str) -> DynamicObject {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn managed_object() -> ManagedObject {
+        ManagedObject {
+            group: String::new(),
+            kind: "ConfigMap".into(),
+            namespace: Some("default".into()),
+            name: "test".into(),
+        }
+    }
+
+    #[test]
+    fn diff_ignores_server_side_fields_and_status() {
+        let diff = ObjectDiff::try_from(DryRunObject {
+            object: managed_object(),
+            live: Some(dynamic_object(
+                "apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: test
+  uid: 5b1d7f4e-2c1a-4d8f-9c3b-6e2a1f0b7d9c
+  resourceVersion: \"1234\"
+  generation: 3
+  creationTimestamp: \"2024-11-18T12:00:00Z\"
+  annotations:
+    deployment.kubernetes.io/revision: \"2\"
+    kubectl.kubernetes.io/last-applied-configuration: \"{}\"
+  managedFields:
+    - manager: kubectl
+data:
+  foo: bar
+status:
+  some: thing",
+            )),
+            dry_run: dynamic_object(
+                "apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: test
+  uid: 5b1d7f4e-2c1a-4d8f-9c3b-6e2a1f0b7d9c
+  resourceVersion: \"1235\"
+  generation: 4
+  creationTimestamp: \"2024-11-18T12:00:00Z\"
+  managedFields:
+    - manager: stackablectl
+data:
+  foo: bar",
+            ),
+        })
+        .unwrap();
+
+        assert_eq!(diff.status, ObjectDiffStatus::Unchanged);
+        assert!(diff.diff.is_empty());
+    }
+
+    #[test]
+    fn diff_of_changed_and_new_objects() {
+        let dry_run = dynamic_object(
+            "apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: test
+data:
+  foo: baz",
+        );
+
+        let diff = ObjectDiff::try_from(DryRunObject {
+            object: managed_object(),
+            live: Some(dynamic_object(
+                "apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: test
+data:
+  foo: bar",
+            )),
+            dry_run: dry_run.clone(),
+        })
+        .unwrap();
+
+        assert_eq!(diff.status, ObjectDiffStatus::Changed);
+        assert!(diff.diff.contains("-  foo: bar\n+  foo: baz\n"));
+
+        let diff = ObjectDiff::try_from(DryRunObject {
+            object: managed_object(),
+            live: None,
+            dry_run,
+        })
+        .unwrap();
+
+        assert_eq!(diff.status, ObjectDiffStatus::New);
+        assert!(diff.diff.contains("+  foo: baz\n"));
+    }
+}
@@ -0,0 +1,336 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
+use k8s_openapi::{api::core::v1::ConfigMap, apimachinery::pkg::apis::meta::v1::Time, chrono::Utc};
+use kube::{api::ListParams, core::ObjectMeta, ResourceExt};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use snafu::{OptionExt, ResultExt, Snafu};
+use stackable_operator::kvp::Labels;
+use tracing::{debug, instrument, warn};
+
+use crate::{
+    platform::prune::ResourceKind,
+    utils::{
+        k8s::{self, Client},
+        params::Parameter,
+    },
+};
+
+/// Label which is attached to the ConfigMaps storing install records. The
+/// value is the kind of the record, either `demo` or `stack`.
+pub const INSTALL_RECORD_LABEL: &str = "stackable.tech/install-record";
+
+/// Key in the ConfigMap data which contains the serialized install record.
+const INSTALL_RECORD_DATA_KEY: &str = "record.yaml";
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    /// This error indicates that the install record could not be serialized
+    /// into YAML.
+    #[snafu(display("failed to serialize install record"))]
+    SerializeRecord { source: serde_yaml::Error },
+
+    /// This error indicates that the install record stored in the ConfigMap
+    /// could not be deserialized.
+    #[snafu(display("failed to deserialize install record {name:?}"))]
+    DeserializeRecord {
+        source: serde_yaml::Error,
+        name: String,
+    },
+
+    /// This error indicates that the ConfigMap doesn't contain a record.
+    #[snafu(display("ConfigMap {name:?} doesn't contain an install record"))]
+    MissingRecordData { name: String },
+
+    /// This error indicates that the kube client failed to read or write
+    /// install records.
+    #[snafu(display("failed to read or write install record ConfigMap"))]
+    KubeClient { source: k8s::Error },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InstallRecordKind {
+    Demo,
+    Stack,
+}
+
+impl Display for InstallRecordKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstallRecordKind::Demo => write!(f, "demo"),
+            InstallRecordKind::Stack => write!(f, "stack"),
+        }
+    }
+}
+
+/// An install record describes which demo or stack was installed into the
+/// product namespace. It is stored in a ConfigMap after the installation
+/// succeeded.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallRecord {
+    /// The kind of the installed object, either a demo or a stack
+    pub kind: InstallRecordKind,
+
+    /// Name of the installed demo or stack
+    pub name: String,
+
+    /// Name of the stack used by the demo. This is only set for demos.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack: Option<String>,
+
+    /// The release used by the stack, e.g. 24.7
+    pub release: String,
+
+    /// Namespace the operators were installed in
+    pub operator_namespace: String,
+
+    /// Namespace the products were installed in
+    pub product_namespace: String,
+
+    /// The resolved parameters used during the installation. Sensitive
+    /// parameters are not included.
+    #[serde(default)]
+    pub parameters: BTreeMap<String, String>,
+
+    /// The resolved parameters used to install the stack of a demo. Sensitive
+    /// parameters are not included.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub stack_parameters: BTreeMap<String, String>,
+
+    /// The demo, stack and release files used during the installation
+    #[serde(default)]
+    pub sources: Vec<SpecSource>,
+
+    /// The Helm chart and YAML manifest files installed, together with the
+    /// hash of their content
+    #[serde(default)]
+    pub manifests: Vec<SpecSource>,
+
+    /// The kinds of all objects applied from plain YAML manifests. Pruning
+    /// lists objects of these kinds when the demo or stack is installed again.
+    /// Kinds of previous installations are retained, as their objects might
+    /// not have been pruned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub kinds: Vec<ResourceKind>,
+
+    /// Time of the first installation
+    pub created_at: Time,
+
+    /// Time of the most recent installation
+    pub updated_at: Time,
+}
+
+/// A demo, stack or release file together with the hash of its content at
+/// the time of the installation.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecSource {
+    pub file: String,
+    pub sha256: String,
+}
+
+impl SpecSource {
+    /// Creates a new source for the `file` with the hash of its `content`.
+    pub fn new(file: impl Into<String>, content: &str) -> Self {
+        Self {
+            file: file.into(),
+            sha256: format!("{:x}", Sha256::digest(content.as_bytes())),
+        }
+    }
+}
+
+impl InstallRecord {
+    /// Creates a new install record with the current time as creation and
+    /// update time.
+    pub fn new(
+        kind: InstallRecordKind,
+        name: impl Into<String>,
+        release: impl Into<String>,
+        operator_namespace: impl Into<String>,
+        product_namespace: impl Into<String>,
+    ) -> Self {
+        let now = Time(Utc::now());
+
+        Self {
+            kind,
+            name: name.into(),
+            stack: None,
+            release: release.into(),
+            operator_namespace: operator_namespace.into(),
+            product_namespace: product_namespace.into(),
+            parameters: BTreeMap::new(),
+            stack_parameters: BTreeMap::new(),
+            sources: Vec::new(),
+            manifests: Vec::new(),
+            kinds: Vec::new(),
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// Returns the name of the ConfigMap storing this record.
+    pub fn config_map_name(&self) -> String {
+        format!("stackablectl-{}-{}", self.kind, self.name)
+    }
+
+    /// Writes the record into a ConfigMap in the product namespace. If a
+    /// record for the same demo or stack already exists, its creation time
+    /// and resource kinds are retained.
+    #[instrument(skip_all, fields(name = %self.config_map_name()))]
+    pub async fn write(mut self, labels: Labels, client: &Client) -> Result<(), Error> {
+        debug!("Writing install record");
+
+        if let Some(previous) = self.previous(client).await? {
+            self.created_at = previous.created_at;
+            self.kinds.extend(previous.kinds);
+            self.kinds.sort();
+            self.kinds.dedup();
+        }
+
+        let name = self.config_map_name();
+        let mut labels: BTreeMap<String, String> = labels.into();
+        labels.insert(INSTALL_RECORD_LABEL.to_string(), self.kind.to_string());
+
+        let data = serde_yaml::to_string(&self).context(SerializeRecordSnafu)?;
+
+        let config_map = ConfigMap {
+            metadata: ObjectMeta {
+                name: Some(name),
+                namespace: Some(self.product_namespace.clone()),
+                labels: Some(labels),
+                ..Default::default()
+            },
+            data: Some(BTreeMap::from([(
+                INSTALL_RECORD_DATA_KEY.to_string(),
+                data,
+            )])),
+            ..Default::default()
+        };
+
+        client
+            .apply_config_map(&self.product_namespace, config_map)
+            .await
+            .context(KubeClientSnafu)
+    }
+
+    /// Reads the record stored by a previous installation of the same demo or
+    /// stack into the same product namespace. Returns [`None`] if there is no
+    /// previous record or if it is invalid.
+    pub async fn previous(&self, client: &Client) -> Result<Option<Self>, Error> {
+        let name = self.config_map_name();
+
+        let existing = client
+            .get_config_map(&self.product_namespace, &name)
+            .await
+            .context(KubeClientSnafu)?;
+
+        Ok(
+            existing.and_then(|existing| match Self::from_config_map(&existing) {
+                Ok(record) => Some(record),
+                Err(err) => {
+                    warn!("Ignoring invalid install record {name:?}: {err}");
+                    None
+                }
+            }),
+        )
+    }
+
+    /// Lists install records of the provided `kind`. Records are listed from
+    /// all namespaces if no `namespace` is provided. ConfigMaps containing
+    /// invalid records are skipped.
+    #[instrument(skip(client))]
+    pub async fn list(
+        kind: InstallRecordKind,
+        namespace: Option<&str>,
+        client: &Client,
+    ) -> Result<Vec<Self>, Error> {
+        let list_params = ListParams::default().labels(&format!("{INSTALL_RECORD_LABEL}={kind}"));
+
+        let config_maps = client
+            .list_config_maps(namespace, &list_params)
+            .await
+            .context(KubeClientSnafu)?;
+
+        let mut records = Vec::new();
+
+        for config_map in config_maps {
+            match Self::from_config_map(&config_map) {
+                Ok(record) => records.push(record),
+                Err(err) => warn!("Skipping invalid install record: {err}"),
+            }
+        }
+
+        Ok(records)
+    }
+
+    fn from_config_map(config_map: &ConfigMap) -> Result<Self, Error> {
+        let name = config_map.name_any();
+
+        let data = config_map
+            .data
+            .as_ref()
+            .and_then(|data| data.get(INSTALL_RECORD_DATA_KEY))
+            .context(MissingRecordDataSnafu { name: name.clone() })?;
+
+        serde_yaml::from_str(data).context(DeserializeRecordSnafu { name })
+    }
+}
+
+/// Filters out sensitive parameters from the resolved `parameters`, so that
+/// they can be persisted in an install record.
+pub fn public_parameters(
+    parameters: &HashMap<String, String>,
+    valid_parameters: &[Parameter],
+) -> BTreeMap<String, String> {
+    parameters
+        .iter()
+        .filter(|(name, _)| {
+            !valid_parameters
+                .iter()
+                .any(|parameter| &parameter.name == *name && parameter.is_secret())
+        })
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parameter(name: &str, secret: bool) -> Parameter {
+        Parameter {
+            description: String::new(),
+            default: String::new(),
+            value: String::new(),
+            name: name.to_string(),
+            secret,
+        }
+    }
+
+    #[test]
+    fn public_parameters_exclude_secrets() {
+        let valid_parameters = [
+            parameter("adminUser", false),
+            parameter("adminPassword", false),
+            parameter("clientKey", true),
+        ];
+
+        let parameters = HashMap::from([
+            ("adminUser".to_string(), "admin".to_string()),
+            ("adminPassword".to_string(), "adminadmin".to_string()),
+            ("clientKey".to_string(), "abc".to_string()),
+        ]);
+
+        let public = public_parameters(&parameters, &valid_parameters);
+
+        assert_eq!(
+            public,
+            BTreeMap::from([("adminUser".to_string(), "admin".to_string())])
+        );
+    }
+}
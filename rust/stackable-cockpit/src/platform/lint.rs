@@ -0,0 +1,657 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    str::FromStr,
+};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, instrument};
+
+use crate::{
+    common::{
+        list::{List, ResolveReferences, SpecIter},
+        manifest::{self, ManifestSource, ManifestSpec},
+    },
+    helm,
+    platform::{
+        demo::{DemoSpec, DemosV2},
+        operator::OperatorSpec,
+        release::{ReleaseSpec, Releases},
+        stack::{StackSpec, StacksV2},
+    },
+    utils::{
+        params::Parameter,
+        path::{IntoPathOrUrl, PathOrUrl},
+        templating,
+    },
+    xfer::{self, processor::Text},
+};
+
+/// The result of linting demo, stack and release files. The report is
+/// serializable, so that it can be consumed by other tools.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintReport {
+    /// Number of demos, stacks and releases which were checked
+    pub checked: usize,
+
+    /// All findings in the order they were found
+    pub findings: Vec<Finding>,
+}
+
+impl LintReport {
+    /// Returns if any finding has the severity [`Severity::Error`].
+    pub fn has_errors(&self) -> bool {
+        self.findings
+            .iter()
+            .any(|finding| finding.severity == Severity::Error)
+    }
+}
+
+/// A single problem found while linting.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Finding {
+    pub severity: Severity,
+
+    /// The kind of spec this finding belongs to
+    pub kind: SpecKind,
+
+    /// Name of the demo, stack or release. This is not set if the whole file
+    /// is affected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The file the spec is defined in
+    pub file: String,
+
+    /// The affected part of the spec, e.g. a manifest or an operator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The spec can't be used
+    Error,
+
+    /// The spec can be used, but likely doesn't behave as intended
+    Warning,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpecKind {
+    Demo,
+    Stack,
+    Release,
+}
+
+impl Display for SpecKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpecKind::Demo => write!(f, "demo"),
+            SpecKind::Stack => write!(f, "stack"),
+            SpecKind::Release => write!(f, "release"),
+        }
+    }
+}
+
+/// Lints the demos, stacks and releases defined in the provided files. Each
+/// file is checked for schema conformity. Afterwards, the specs are checked
+/// for:
+///
+/// - references to stacks and releases which don't exist,
+/// - operators which are not part of the referenced release,
+/// - manifests which can't be retrieved,
+/// - manifests which don't render using the default parameter values,
+/// - parameters which are used in manifests, but not declared.
+///
+/// Manifests are rendered without access to a cluster, see
+/// [`templating::render_offline`].
+#[instrument(skip_all)]
+pub async fn lint(
+    demo_files: &[PathOrUrl],
+    stack_files: &[PathOrUrl],
+    release_files: &[PathOrUrl],
+    transfer_client: &xfer::Client,
+) -> LintReport {
+    let mut linter = Linter {
+        report: LintReport::default(),
+        transfer_client,
+    };
+
+    let releases = linter
+        .load::<Releases, ReleaseSpec>(SpecKind::Release, release_files)
+        .await;
+    let stacks = linter
+        .load::<StacksV2, StackSpec>(SpecKind::Stack, stack_files)
+        .await;
+    let demos = linter
+        .load::<DemosV2, DemoSpec>(SpecKind::Demo, demo_files)
+        .await;
+
+    let stack_specs: IndexMap<String, StackSpec> = stacks
+        .iter()
+        .map(|(name, (_, spec))| (name.clone(), spec.clone()))
+        .collect();
+
+    for (name, (file, release)) in &releases {
+        debug!("Linting release {name}");
+        let target = Target::new(SpecKind::Release, name, file);
+
+        if release.products.is_empty() {
+            linter.warning(&target, None, "release doesn't contain any products");
+        }
+    }
+
+    for (name, (file, stack)) in &stacks {
+        debug!("Linting stack {name}");
+        let target = Target::new(SpecKind::Stack, name, file);
+
+        match releases.get(&stack.release) {
+            Some((_, release)) => linter.lint_operators(&target, &stack.operators, release),
+            None => linter.error(
+                &target,
+                Some("stackableRelease"),
+                format!("release {:?} doesn't exist", stack.release),
+            ),
+        }
+
+        if let Err(err) = stack.resolve_includes(name, &stack_specs) {
+            linter.error(&target, Some("includes"), error_message(&err));
+        }
+
+        linter
+            .lint_manifests(&target, &stack.manifests, &stack.parameters)
+            .await;
+    }
+
+    for (name, (file, demo)) in &demos {
+        debug!("Linting demo {name}");
+        let target = Target::new(SpecKind::Demo, name, file);
+
+        if !stacks.contains_key(&demo.stack) {
+            linter.error(
+                &target,
+                Some("stackableStack"),
+                format!("stack {:?} doesn't exist", demo.stack),
+            );
+        }
+
+        linter
+            .lint_manifests(&target, &demo.manifests, &demo.parameters)
+            .await;
+    }
+
+    linter.report.checked = releases.len() + stacks.len() + demos.len();
+    linter.report
+}
+
+/// The spec a finding is reported for.
+struct Target<'a> {
+    kind: SpecKind,
+    name: Option<&'a str>,
+    file: String,
+}
+
+impl<'a> Target<'a> {
+    fn new(kind: SpecKind, name: &'a str, file: &PathOrUrl) -> Self {
+        Self {
+            kind,
+            name: Some(name),
+            file: file.to_string(),
+        }
+    }
+}
+
+struct Linter<'a> {
+    report: LintReport,
+    transfer_client: &'a xfer::Client,
+}
+
+impl Linter<'_> {
+    /// Loads the specs of all `files`. Files which can't be loaded are
+    /// reported and skipped. Each spec is returned together with the file it
+    /// is defined in. Specs defined in multiple files are reported, the last
+    /// definition wins.
+    async fn load<L, S>(
+        &mut self,
+        kind: SpecKind,
+        files: &[PathOrUrl],
+    ) -> IndexMap<String, (PathOrUrl, S)>
+    where
+        L: for<'a> Deserialize<'a> + Serialize + SpecIter<S>,
+        S: for<'a> Deserialize<'a> + Serialize + Clone + ResolveReferences,
+    {
+        let mut specs = IndexMap::new();
+
+        for file in files {
+            let list =
+                match List::<L, S>::build(std::slice::from_ref(file), self.transfer_client).await {
+                    Ok(list) => list,
+                    Err(err) => {
+                        self.push(
+                            Severity::Error,
+                            &Target {
+                                kind,
+                                name: None,
+                                file: file.to_string(),
+                            },
+                            None,
+                            error_message(&err),
+                        );
+                        continue;
+                    }
+                };
+
+            for (name, spec) in list.iter() {
+                if let Some((previous, _)) =
+                    specs.insert(name.clone(), (file.clone(), spec.clone()))
+                {
+                    self.warning(
+                        &Target::new(kind, name, file),
+                        None,
+                        format!("{kind} is also defined in {previous}, which is overridden"),
+                    );
+                }
+            }
+        }
+
+        specs
+    }
+
+    /// Checks that all `operators` of a stack are valid and part of the
+    /// referenced `release`.
+    fn lint_operators(&mut self, target: &Target<'_>, operators: &[String], release: &ReleaseSpec) {
+        for operator in operators {
+            let location = format!("operator {operator}");
+
+            match OperatorSpec::from_str(operator) {
+                Ok(spec) if !release.products.contains_key(&spec.name) => self.error(
+                    target,
+                    Some(&location),
+                    format!("operator {:?} is not part of the release", spec.name),
+                ),
+                Ok(_) => {}
+                Err(err) => self.error(target, Some(&location), error_message(&err)),
+            }
+        }
+    }
+
+    /// Checks that all `manifests` can be retrieved and rendered using the
+    /// default values of the declared `parameters`, and that all referenced
+    /// parameters are declared. Declared parameters which are never used are
+    /// reported as warnings.
+    async fn lint_manifests(
+        &mut self,
+        target: &Target<'_>,
+        manifests: &[ManifestSpec],
+        parameters: &[Parameter],
+    ) {
+        let defaults: HashMap<String, String> = parameters
+            .iter()
+            .map(|parameter| (parameter.name.clone(), parameter.default.clone()))
+            .collect();
+        let mut used = BTreeSet::new();
+
+        for manifest in manifests {
+            let location = manifest.source.to_string();
+            let parameters = manifest.merge_parameters(&defaults);
+
+            if let Some(condition) = &manifest.condition {
+                let location = format!("{location} (condition)");
+
+                if let Some(rendered) =
+                    self.render(target, &location, condition, &parameters, &mut used)
+                {
+                    if manifest::evaluate_condition(&rendered).is_none() {
+                        self.error(
+                            target,
+                            Some(&location),
+                            format!("condition rendered to {rendered:?}, expected true or false"),
+                        );
+                    }
+                }
+            }
+
+            let (ManifestSource::HelmChart(path) | ManifestSource::PlainYaml(path)) =
+                &manifest.source;
+
+            let path_or_url = match path.into_path_or_url() {
+                Ok(path_or_url) => path_or_url,
+                Err(err) => {
+                    self.error(target, Some(&location), error_message(&err));
+                    continue;
+                }
+            };
+
+            let content = match self.transfer_client.get(&path_or_url, &Text).await {
+                Ok(content) => content,
+                Err(err) => {
+                    self.error(
+                        target,
+                        Some(&location),
+                        format!("manifest is not reachable: {}", error_message(&err)),
+                    );
+                    continue;
+                }
+            };
+
+            let Some(rendered) = self.render(target, &location, &content, &parameters, &mut used)
+            else {
+                continue;
+            };
+
+            let result = match &manifest.source {
+                ManifestSource::HelmChart(_) => serde_yaml::from_str::<helm::Chart>(&rendered)
+                    .map(|_| ())
+                    .map_err(|err| format!("rendered Helm chart is invalid: {err}")),
+                ManifestSource::PlainYaml(_) => serde_yaml::Deserializer::from_str(&rendered)
+                    .try_for_each(|document| serde_yaml::Value::deserialize(document).map(|_| ()))
+                    .map_err(|err| format!("rendered manifest is not valid YAML: {err}")),
+            };
+
+            if let Err(message) = result {
+                self.error(target, Some(&location), message);
+            }
+        }
+
+        for parameter in parameters {
+            if !used.contains(&parameter.name) {
+                self.warning(
+                    target,
+                    Some(&format!("parameter {}", parameter.name)),
+                    "parameter is not used by any manifest",
+                );
+            }
+        }
+    }
+
+    /// Renders the template `content` using the `parameters`. Referenced
+    /// parameters are added to `used`. Returns [`None`] if referenced
+    /// parameters are not declared or rendering failed, which is reported.
+    fn render(
+        &mut self,
+        target: &Target<'_>,
+        location: &str,
+        content: &str,
+        parameters: &HashMap<String, String>,
+        used: &mut BTreeSet<String>,
+    ) -> Option<String> {
+        let referenced = match templating::referenced_parameters(content) {
+            Ok(referenced) => referenced,
+            Err(err) => {
+                self.error(
+                    target,
+                    Some(location),
+                    format!("invalid template: {}", error_message(&err)),
+                );
+                return None;
+            }
+        };
+
+        let undeclared: Vec<_> = referenced
+            .iter()
+            .filter(|name| !parameters.contains_key(*name))
+            .collect();
+        used.extend(referenced.iter().cloned());
+
+        for name in &undeclared {
+            self.error(
+                target,
+                Some(location),
+                format!("parameter {name:?} is used, but not declared"),
+            );
+        }
+
+        if !undeclared.is_empty() {
+            return None;
+        }
+
+        match templating::render_offline(content, parameters) {
+            Ok(rendered) => Some(rendered),
+            Err(err) => {
+                self.error(
+                    target,
+                    Some(location),
+                    format!(
+                        "failed to render with default parameters: {}",
+                        error_message(&err)
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    fn error(&mut self, target: &Target<'_>, location: Option<&str>, message: impl Into<String>) {
+        self.push(Severity::Error, target, location, message)
+    }
+
+    fn warning(&mut self, target: &Target<'_>, location: Option<&str>, message: impl Into<String>) {
+        self.push(Severity::Warning, target, location, message)
+    }
+
+    fn push(
+        &mut self,
+        severity: Severity,
+        target: &Target<'_>,
+        location: Option<&str>,
+        message: impl Into<String>,
+    ) {
+        self.report.findings.push(Finding {
+            severity,
+            kind: target.kind,
+            name: target.name.map(ToString::to_string),
+            file: target.file.clone(),
+            location: location.map(ToString::to_string),
+            message: message.into(),
+        })
+    }
+}
+
+/// Formats the `error` and all of its sources into a single line.
+fn error_message(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+
+    while let Some(error) = source {
+        message.push_str(&format!(": {error}"));
+        source = error.source();
+    }
+
+    message
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utils::temp_dir::TempDir;
+
+    use super::*;
+
+    fn finding(target: &Target<'_>, location: &str, message: &str) -> Finding {
+        Finding {
+            severity: Severity::Error,
+            kind: target.kind,
+            name: target.name.map(ToString::to_string),
+            file: target.file.clone(),
+            location: Some(location.to_string()),
+            message: message.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn lint_undeclared_parameters() {
+        let transfer_client = xfer::Client::new_with(
+            xfer::cache::Settings::disabled()
+                .try_into_cache()
+                .await
+                .unwrap(),
+        );
+        let mut linter = Linter {
+            report: LintReport::default(),
+            transfer_client: &transfer_client,
+        };
+        let target = Target {
+            kind: SpecKind::Stack,
+            name: Some("test"),
+            file: "stacks.yaml".into(),
+        };
+        let parameters = HashMap::from([("user".to_string(), "admin".to_string())]);
+        let mut used = BTreeSet::new();
+
+        let rendered = linter.render(
+            &target,
+            "manifest",
+            "{{ user }}:{{ password }}",
+            &parameters,
+            &mut used,
+        );
+
+        assert!(rendered.is_none());
+        assert!(linter.report.has_errors());
+        assert_eq!(
+            serde_json::to_value(&linter.report.findings).unwrap(),
+            serde_json::to_value([finding(
+                &target,
+                "manifest",
+                "parameter \"password\" is used, but not declared"
+            )])
+            .unwrap()
+        );
+        assert_eq!(used, BTreeSet::from(["password".into(), "user".into()]));
+    }
+
+    #[tokio::test]
+    async fn lint_files() {
+        let dir = TempDir::new("lint");
+        let release_file = dir.write(
+            "releases.yaml",
+            "releases:
+  \"24.11\":
+    releaseDate: 2024-11-18
+    description: Release
+    products:
+      commons:
+        operatorVersion: 24.11.0",
+        );
+        let invalid_stack_file = dir.write(
+            "invalid-stacks.yaml",
+            "stacks:
+  broken:
+    stackableRelease: \"24.11\"",
+        );
+        let stack_file = dir.write(
+            "stacks.yaml",
+            "stacks:
+  hive:
+    description: Hive
+    stackableRelease: \"24.11\"
+    stackableOperators: [commons, hive]
+    manifests:
+      - plainYaml: missing.yaml
+  trino:
+    description: Trino
+    stackableRelease: \"23.4\"
+    stackableOperators: [commons]
+    manifests: []",
+        );
+        let demo_file = dir.write(
+            "demos.yaml",
+            "demos:
+  airflow:
+    description: Airflow
+    stackableStack: airflow
+    manifests: []",
+        );
+
+        let transfer_client = xfer::Client::new_with(
+            xfer::cache::Settings::disabled()
+                .try_into_cache()
+                .await
+                .unwrap(),
+        );
+        let report = lint(
+            &[PathOrUrl::Path(demo_file)],
+            &[
+                PathOrUrl::Path(invalid_stack_file.clone()),
+                PathOrUrl::Path(stack_file),
+            ],
+            &[PathOrUrl::Path(release_file)],
+            &transfer_client,
+        )
+        .await;
+
+        let findings: Vec<_> = report
+            .findings
+            .iter()
+            .map(|finding| {
+                (
+                    finding.severity,
+                    finding.name.as_deref(),
+                    finding.location.clone(),
+                )
+            })
+            .collect();
+
+        let manifest_location = format!(
+            "YAML manifest {}",
+            dir.path().join("missing.yaml").display()
+        );
+
+        assert_eq!(report.checked, 4);
+        assert!(report.has_errors());
+        assert_eq!(
+            findings,
+            [
+                (Severity::Error, None, None),
+                (Severity::Error, Some("hive"), Some("operator hive".into())),
+                (Severity::Error, Some("hive"), Some(manifest_location)),
+                (
+                    Severity::Error,
+                    Some("trino"),
+                    Some("stackableRelease".into())
+                ),
+                (
+                    Severity::Error,
+                    Some("airflow"),
+                    Some("stackableStack".into())
+                ),
+            ]
+        );
+
+        let messages: Vec<_> = report
+            .findings
+            .iter()
+            .map(|finding| finding.message.as_str())
+            .collect();
+
+        assert_eq!(
+            report.findings[0].file,
+            invalid_stack_file.display().to_string()
+        );
+        assert!(messages[0].contains("description"), "{}", messages[0]);
+        assert_eq!(messages[1], "operator \"hive\" is not part of the release");
+        assert!(
+            messages[2].starts_with("manifest is not reachable"),
+            "{}",
+            messages[2]
+        );
+        assert_eq!(messages[3], "release \"23.4\" doesn't exist");
+        assert_eq!(messages[4], "stack \"airflow\" doesn't exist");
+    }
+}
@@ -0,0 +1,782 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use snafu::{ensure, OptionExt, ResultExt, Snafu};
+use tokio::fs;
+use tracing::{debug, info, instrument, warn};
+
+use crate::{
+    common::manifest::{ManifestSource, ManifestSpec},
+    helm,
+    platform::{
+        demo::DemoSpec,
+        install_record::{InstallRecordKind, SpecSource},
+        product::ProductSpec,
+        release::ReleaseList,
+        stack::StackSpec,
+    },
+    utils::{
+        params::Parameter,
+        path::{IntoPathOrUrl, PathOrUrlParseError},
+        templating,
+    },
+    xfer::{self, processor::Text},
+};
+
+/// The version of the lockfile format written by this version of the
+/// library. Lockfiles with a different version are rejected.
+pub const LOCKFILE_VERSION: u32 = 1;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    /// This error indicates that the lockfile could not be read.
+    #[snafu(display("failed to read lockfile {path:?}"))]
+    ReadLockfile {
+        source: std::io::Error,
+        path: PathBuf,
+    },
+
+    /// This error indicates that the lockfile could not be written.
+    #[snafu(display("failed to write lockfile {path:?}"))]
+    WriteLockfile {
+        source: std::io::Error,
+        path: PathBuf,
+    },
+
+    /// This error indicates that the lockfile is not valid YAML or doesn't
+    /// match the expected format.
+    #[snafu(display("failed to deserialize lockfile {path:?}"))]
+    DeserializeLockfile {
+        source: serde_yaml::Error,
+        path: PathBuf,
+    },
+
+    /// This error indicates that the lockfile could not be serialized into
+    /// YAML.
+    #[snafu(display("failed to serialize lockfile"))]
+    SerializeLockfile { source: serde_yaml::Error },
+
+    /// This error indicates that the lockfile was written using an
+    /// incompatible format.
+    #[snafu(display("unsupported lockfile version {version}, expected {LOCKFILE_VERSION}"))]
+    UnsupportedVersion { version: u32 },
+
+    /// This error indicates that the release used by the stack doesn't exist
+    /// in the loaded list of releases.
+    #[snafu(display("no release named {name:?}"))]
+    NoSuchRelease { name: String },
+
+    /// This error indicates that parsing a manifest reference into a path or
+    /// URL failed.
+    #[snafu(display("failed to parse {path_or_url:?} as path/url"))]
+    ParsePathOrUrl {
+        source: PathOrUrlParseError,
+        path_or_url: String,
+    },
+
+    /// This error indicates that a manifest could not be retrieved.
+    #[snafu(display("failed to retrieve manifest {file}"))]
+    FileTransfer { source: xfer::Error, file: String },
+
+    /// This error indicates that a Helm chart file could not be rendered
+    /// using the default parameter values.
+    #[snafu(display("failed to render Helm chart {file} using the default parameters"))]
+    RenderHelmChart { source: tera::Error, file: String },
+
+    /// This error indicates that a rendered Helm chart file is invalid.
+    #[snafu(display("failed to deserialize Helm chart {file}"))]
+    DeserializeHelmChart {
+        source: serde_yaml::Error,
+        file: String,
+    },
+
+    /// This error indicates that the version of a Helm chart is neither a
+    /// version nor a version range.
+    #[snafu(display("failed to parse version {version:?} of Helm chart {chart:?}"))]
+    ParseChartVersion {
+        source: semver::Error,
+        chart: String,
+        version: String,
+    },
+
+    /// This error indicates that the Helm index of a repository could not be
+    /// retrieved to resolve a chart version range.
+    #[snafu(display("failed to retrieve Helm index of {repo_url}"))]
+    FetchHelmIndex {
+        source: helm::Error,
+        repo_url: String,
+    },
+
+    /// This error indicates that no version of a Helm chart in the repository
+    /// matches the requested version range.
+    #[snafu(display("no version of Helm chart {chart:?} matches {version:?}"))]
+    NoMatchingChartVersion { chart: String, version: String },
+
+    /// This error indicates that the lockfile was created for a different
+    /// demo or stack.
+    #[snafu(display(
+        "lockfile was created for {locked_kind} {locked_name:?}, not for {kind} {name:?}"
+    ))]
+    LockedSpecMismatch {
+        kind: InstallRecordKind,
+        name: String,
+        locked_kind: InstallRecordKind,
+        locked_name: String,
+    },
+
+    /// This error indicates that a file is used which is not recorded in the
+    /// lockfile.
+    #[snafu(display("{file} is not part of the lockfile"))]
+    FileNotLocked { file: String },
+
+    /// This error indicates that the content of a file changed since the
+    /// lockfile was created.
+    #[snafu(display(
+        "content of {file} changed, expected SHA-256 digest {expected} but got {actual}"
+    ))]
+    FileChanged {
+        file: String,
+        expected: String,
+        actual: String,
+    },
+
+    /// This error indicates that the version of an operator differs from the
+    /// locked version.
+    #[snafu(display("operator {operator} has version {version}, but {locked:?} is locked"))]
+    OperatorMismatch {
+        operator: String,
+        version: String,
+        locked: Option<String>,
+    },
+
+    /// This error indicates that a Helm chart is used which is not recorded
+    /// in the lockfile.
+    #[snafu(display("Helm chart {file} is not part of the lockfile"))]
+    ChartNotLocked { file: String },
+}
+
+/// A lockfile records everything which influences the installation of a demo
+/// or stack: the content hashes of all demo, stack and release files, the
+/// versions of the operators, the resolved versions of Helm charts and the
+/// content hashes of all manifests. Installing with a lockfile fails if
+/// anything changed since the lockfile was created.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Lockfile {
+    /// The version of the lockfile format
+    pub version: u32,
+
+    /// The kind of the locked object, either a demo or a stack
+    pub kind: InstallRecordKind,
+
+    /// Name of the locked demo or stack
+    pub name: String,
+
+    /// The release used by the stack, e.g. 24.7
+    pub release: String,
+
+    /// The demo, stack and release files
+    #[serde(default)]
+    pub files: Vec<SpecSource>,
+
+    /// The operators of the stack and their versions
+    #[serde(default)]
+    pub operators: BTreeMap<String, String>,
+
+    /// The Helm charts of all manifests with their resolved version
+    #[serde(default)]
+    pub charts: Vec<LockedChart>,
+
+    /// The Helm chart and YAML manifest files
+    #[serde(default)]
+    pub manifests: Vec<SpecSource>,
+}
+
+/// A Helm chart referenced by a manifest, together with the exact version
+/// its version (range) resolved to.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedChart {
+    /// The Helm chart file referenced by the manifest
+    pub file: String,
+
+    /// Name of the chart
+    pub name: String,
+
+    /// URL of the repository containing the chart
+    pub repo: String,
+
+    /// The resolved chart version
+    pub version: String,
+}
+
+impl Lockfile {
+    fn new(kind: InstallRecordKind, name: impl Into<String>, release: impl Into<String>) -> Self {
+        Self {
+            version: LOCKFILE_VERSION,
+            kind,
+            name: name.into(),
+            release: release.into(),
+            files: Vec::new(),
+            operators: BTreeMap::new(),
+            charts: Vec::new(),
+            manifests: Vec::new(),
+        }
+    }
+
+    /// Creates the lockfile of the stack named `stack_name`. The `stack` is
+    /// expected to have its includes resolved. The `spec_sources` are the
+    /// stack and release files the stack was loaded from, see
+    /// [`List::sources`](crate::common::list::List::sources).
+    #[instrument(skip(stack, release_list, spec_sources, transfer_client))]
+    pub async fn for_stack(
+        stack_name: &str,
+        stack: &StackSpec,
+        release_list: &ReleaseList,
+        spec_sources: &[SpecSource],
+        transfer_client: &xfer::Client,
+    ) -> Result<Self> {
+        info!("Locking stack {stack_name}");
+
+        let mut lockfile = Self::new(InstallRecordKind::Stack, stack_name, &stack.release);
+        lockfile.files = spec_sources.to_vec();
+        lockfile.lock_operators(stack, release_list)?;
+        lockfile
+            .lock_manifests(&stack.manifests, &stack.parameters, transfer_client)
+            .await?;
+
+        Ok(lockfile)
+    }
+
+    /// Creates the lockfile of the demo named `demo_name`. The `stack` of
+    /// the demo is expected to have its includes resolved. The `spec_sources`
+    /// are the demo, stack and release files the demo was loaded from.
+    #[instrument(skip(demo, stack, release_list, spec_sources, transfer_client))]
+    pub async fn for_demo(
+        demo_name: &str,
+        demo: &DemoSpec,
+        stack: &StackSpec,
+        release_list: &ReleaseList,
+        spec_sources: &[SpecSource],
+        transfer_client: &xfer::Client,
+    ) -> Result<Self> {
+        info!("Locking demo {demo_name}");
+
+        let mut lockfile = Self::new(InstallRecordKind::Demo, demo_name, &stack.release);
+        lockfile.files = spec_sources.to_vec();
+        lockfile.lock_operators(stack, release_list)?;
+        lockfile
+            .lock_manifests(&stack.manifests, &stack.parameters, transfer_client)
+            .await?;
+        lockfile
+            .lock_manifests(&demo.manifests, &demo.parameters, transfer_client)
+            .await?;
+
+        Ok(lockfile)
+    }
+
+    /// Reads the lockfile located at `path`.
+    pub async fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        let content = fs::read_to_string(path)
+            .await
+            .context(ReadLockfileSnafu { path })?;
+        let lockfile: Self =
+            serde_yaml::from_str(&content).context(DeserializeLockfileSnafu { path })?;
+
+        ensure!(
+            lockfile.version == LOCKFILE_VERSION,
+            UnsupportedVersionSnafu {
+                version: lockfile.version
+            }
+        );
+
+        Ok(lockfile)
+    }
+
+    /// Writes the lockfile to `path`, replacing an existing lockfile.
+    pub async fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let content = serde_yaml::to_string(self).context(SerializeLockfileSnafu)?;
+
+        fs::write(path, content)
+            .await
+            .context(WriteLockfileSnafu { path })
+    }
+
+    /// Ensures that the lockfile was created for the demo or stack of `kind`
+    /// named `name`.
+    pub fn verify_target(&self, kind: InstallRecordKind, name: &str) -> Result<()> {
+        ensure!(
+            self.kind == kind && self.name == name,
+            LockedSpecMismatchSnafu {
+                kind,
+                name,
+                locked_kind: self.kind,
+                locked_name: &self.name,
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Ensures that all `sources` are part of the lockfile and that their
+    /// content didn't change.
+    pub fn verify_sources(&self, sources: &[SpecSource]) -> Result<()> {
+        for source in sources {
+            verify_source(&self.files, &source.file, &source.sha256)?;
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that the versions of the `products` of a release match the
+    /// locked operator versions.
+    pub fn verify_operators(&self, products: &[(String, ProductSpec)]) -> Result<()> {
+        for (operator, ProductSpec { version, .. }) in products {
+            let locked = self.operators.get(operator);
+
+            ensure!(
+                locked.is_some_and(|locked| *locked == version.to_string()),
+                OperatorMismatchSnafu {
+                    operator,
+                    version: version.to_string(),
+                    locked: locked.cloned(),
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves all `manifests` and ensures that their content didn't change
+    /// since the lockfile was created. Like locking, this includes manifests
+    /// whose condition is not met. The returned [`LockedManifests`] contain
+    /// the verified content, which is rendered instead of retrieving the
+    /// manifests again.
+    pub(crate) async fn verify_manifests(
+        &self,
+        manifests: &[ManifestSpec],
+        transfer_client: &xfer::Client,
+    ) -> Result<LockedManifests> {
+        let mut contents = HashMap::new();
+
+        for manifest in manifests {
+            let (ManifestSource::HelmChart(file) | ManifestSource::PlainYaml(file)) =
+                &manifest.source;
+
+            if contents.contains_key(file) {
+                continue;
+            }
+
+            debug!("Verifying manifest {file}");
+            let content = get_manifest(file, transfer_client).await?;
+            verify_source(&self.manifests, file, &sha256(&content))?;
+
+            contents.insert(file.clone(), content);
+        }
+
+        Ok(LockedManifests {
+            lockfile: self.clone(),
+            contents,
+        })
+    }
+
+    /// Returns the locked version of the Helm chart referenced by `file`.
+    pub(crate) fn chart_version(&self, file: &str) -> Result<&str> {
+        self.charts
+            .iter()
+            .find(|chart| chart.file == file)
+            .map(|chart| chart.version.as_str())
+            .context(ChartNotLockedSnafu { file })
+    }
+
+    fn lock_operators(&mut self, stack: &StackSpec, release_list: &ReleaseList) -> Result<()> {
+        let release = release_list
+            .get(&stack.release)
+            .context(NoSuchReleaseSnafu {
+                name: &stack.release,
+            })?;
+
+        self.operators = release
+            .filter_products(&stack.operators, &[])
+            .into_iter()
+            .map(|(name, product)| (name, product.version.to_string()))
+            .collect();
+
+        Ok(())
+    }
+
+    /// Records the content hashes of all `manifests` and resolves the
+    /// versions of Helm charts. Helm chart files are rendered using the
+    /// default values of the `parameters`. Manifests are locked regardless
+    /// of their condition, as conditions depend on the parameters used
+    /// during the installation.
+    async fn lock_manifests(
+        &mut self,
+        manifests: &[ManifestSpec],
+        parameters: &[Parameter],
+        transfer_client: &xfer::Client,
+    ) -> Result<()> {
+        let defaults: HashMap<String, String> = parameters
+            .iter()
+            .map(|parameter| (parameter.name.clone(), parameter.default.clone()))
+            .collect();
+
+        for manifest in manifests {
+            let (ManifestSource::HelmChart(file) | ManifestSource::PlainYaml(file)) =
+                &manifest.source;
+
+            // Manifests used multiple times only need to be locked once
+            if self.manifests.iter().any(|source| source.file == *file) {
+                continue;
+            }
+
+            debug!("Locking manifest {file}");
+            let content = get_manifest(file, transfer_client).await?;
+
+            if let ManifestSource::HelmChart(_) = &manifest.source {
+                let parameters = manifest.merge_parameters(&defaults);
+                let rendered = templating::render_offline(&content, &parameters)
+                    .context(RenderHelmChartSnafu { file })?;
+                let chart: helm::Chart =
+                    serde_yaml::from_str(&rendered).context(DeserializeHelmChartSnafu { file })?;
+
+                let version = resolve_chart_version(&chart, transfer_client).await?;
+
+                self.charts.push(LockedChart {
+                    file: file.clone(),
+                    name: chart.name,
+                    repo: chart.repo.url,
+                    version,
+                });
+            }
+
+            self.manifests.push(SpecSource {
+                file: file.clone(),
+                sha256: sha256(&content),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Verifies the `manifests` against the `lockfile` like
+/// [`Lockfile::verify_manifests`], if a lockfile is provided.
+pub(crate) async fn verify_manifests(
+    lockfile: Option<&Lockfile>,
+    manifests: &[ManifestSpec],
+    transfer_client: &xfer::Client,
+) -> Result<Option<LockedManifests>> {
+    match lockfile {
+        Some(lockfile) => lockfile
+            .verify_manifests(manifests, transfer_client)
+            .await
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
+/// The content of manifest files which was verified against a [`Lockfile`]
+/// using [`Lockfile::verify_manifests`].
+#[derive(Debug)]
+pub struct LockedManifests {
+    lockfile: Lockfile,
+    contents: HashMap<String, String>,
+}
+
+impl LockedManifests {
+    /// Returns the verified content of the manifest `file`.
+    pub(crate) fn content(&self, file: &str) -> Result<&str> {
+        self.contents
+            .get(file)
+            .map(String::as_str)
+            .context(FileNotLockedSnafu { file })
+    }
+
+    /// Returns the locked version of the Helm chart referenced by `file`.
+    pub(crate) fn chart_version(&self, file: &str) -> Result<&str> {
+        self.lockfile.chart_version(file)
+    }
+}
+
+/// Ensures that `file` is part of the `locked` files and that the locked
+/// digest matches the `actual` digest.
+fn verify_source(locked: &[SpecSource], file: &str, actual: &str) -> Result<()> {
+    let source = locked
+        .iter()
+        .find(|source| source.file == file)
+        .context(FileNotLockedSnafu { file })?;
+
+    ensure!(
+        source.sha256 == actual,
+        FileChangedSnafu {
+            file,
+            expected: &source.sha256,
+            actual,
+        }
+    );
+
+    Ok(())
+}
+
+/// Resolves the version of the Helm `chart` to an exact version. Versions
+/// which are version ranges, like `>0.0.0-0`, are resolved to the highest
+/// matching version listed in the index of the chart repository.
+async fn resolve_chart_version(
+    chart: &helm::Chart,
+    transfer_client: &xfer::Client,
+) -> Result<String> {
+    if Version::parse(&chart.version).is_ok() {
+        return Ok(chart.version.clone());
+    }
+
+    let version_req = VersionReq::parse(&chart.version).context(ParseChartVersionSnafu {
+        chart: &chart.name,
+        version: &chart.version,
+    })?;
+
+    // OCI registries don't provide an index, so the range is kept as is
+    if chart.repo.url.starts_with("oci://") {
+        warn!(
+            "Unable to resolve version {:?} of Helm chart {:?} from OCI registry, locking the version range",
+            chart.version, chart.name
+        );
+        return Ok(chart.version.clone());
+    }
+
+    let index = helm::get_helm_index(&chart.repo.url, transfer_client)
+        .await
+        .context(FetchHelmIndexSnafu {
+            repo_url: &chart.repo.url,
+        })?;
+
+    index
+        .entries
+        .get(&chart.name)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Version::parse(&entry.version).ok())
+        .filter(|version| version_req.matches(version))
+        .max()
+        .map(|version| version.to_string())
+        .context(NoMatchingChartVersionSnafu {
+            chart: &chart.name,
+            version: &chart.version,
+        })
+}
+
+async fn get_manifest(file: &str, transfer_client: &xfer::Client) -> Result<String> {
+    let path_or_url = file
+        .into_path_or_url()
+        .context(ParsePathOrUrlSnafu { path_or_url: file })?;
+
+    transfer_client
+        .get(&path_or_url, &Text)
+        .await
+        .context(FileTransferSnafu { file })
+}
+
+fn sha256(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+#[cfg(test)]
+mod test {
+    use url::Url;
+
+    use crate::utils::{
+        chartsource::{ChartSourceEntry, ChartSourceMetadata},
+        temp_dir::TempDir,
+    };
+
+    use super::*;
+
+    const REPO_URL: &str = "https://repo.example.com/";
+
+    fn source(file: &str, sha256: &str) -> SpecSource {
+        SpecSource {
+            file: file.into(),
+            sha256: sha256.into(),
+        }
+    }
+
+    fn manifest(source: ManifestSource) -> ManifestSpec {
+        ManifestSpec {
+            source,
+            wait_for: Vec::new(),
+            condition: None,
+            parameters: BTreeMap::new(),
+        }
+    }
+
+    fn chart(repo_url: &str, version: &str) -> helm::Chart {
+        helm::Chart {
+            release_name: "trino".into(),
+            name: "trino".into(),
+            repo: helm::ChartRepo {
+                name: "trino".into(),
+                url: repo_url.into(),
+            },
+            version: version.into(),
+            options: serde_yaml::Value::Null,
+        }
+    }
+
+    /// Returns a transfer client with a disk cache in `dir`, which already
+    /// contains the Helm index of [`REPO_URL`].
+    async fn transfer_client(dir: &TempDir) -> xfer::Client {
+        let cache = xfer::cache::Settings::disk(dir.path().join("cache"))
+            .try_into_cache()
+            .await
+            .unwrap();
+        let client = xfer::Client::new_with(cache);
+
+        let index_url = Url::parse(REPO_URL).unwrap().join("index.yaml").unwrap();
+        let entries = ["1.0.0", "1.2.0", "2.0.0"]
+            .into_iter()
+            .map(|version| ChartSourceEntry {
+                name: "trino".into(),
+                version: version.into(),
+            })
+            .collect();
+        let index = ChartSourceMetadata {
+            entries: HashMap::from([("trino".to_string(), entries)]),
+        };
+
+        client
+            .store_value(&ChartSourceMetadata::cache_key(&index_url), &index)
+            .await
+            .unwrap();
+
+        client
+    }
+
+    #[test]
+    fn verify_locked_sources() {
+        let mut lockfile = Lockfile::new(InstallRecordKind::Stack, "trino", "24.11");
+        lockfile.files = vec![source("stacks.yaml", "abc"), source("releases.yaml", "def")];
+
+        assert!(lockfile
+            .verify_sources(&[source("stacks.yaml", "abc"), source("releases.yaml", "def")])
+            .is_ok());
+        assert!(matches!(
+            lockfile.verify_sources(&[source("stacks.yaml", "123")]),
+            Err(Error::FileChanged { file, .. }) if file == "stacks.yaml"
+        ));
+        assert!(matches!(
+            lockfile.verify_sources(&[source("demos.yaml", "abc")]),
+            Err(Error::FileNotLocked { file }) if file == "demos.yaml"
+        ));
+        assert!(matches!(
+            lockfile.verify_target(InstallRecordKind::Demo, "trino"),
+            Err(Error::LockedSpecMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn resolve_chart_versions() {
+        let dir = TempDir::new("lockfile");
+        let transfer_client = transfer_client(&dir).await;
+
+        let version = resolve_chart_version(&chart(REPO_URL, "2.0.0"), &transfer_client).await;
+        assert_eq!(version.unwrap(), "2.0.0");
+
+        let version =
+            resolve_chart_version(&chart(REPO_URL, ">=1.0.0, <2.0.0"), &transfer_client).await;
+        assert_eq!(version.unwrap(), "1.2.0");
+
+        // OCI registries don't provide an index, so the range is locked
+        let version = resolve_chart_version(
+            &chart("oci://oci.example.com/helm", ">=1.0.0"),
+            &transfer_client,
+        )
+        .await;
+        assert_eq!(version.unwrap(), ">=1.0.0");
+
+        let version = resolve_chart_version(&chart(REPO_URL, ">=3.0.0"), &transfer_client).await;
+        assert!(matches!(version, Err(Error::NoMatchingChartVersion { .. })));
+    }
+
+    #[tokio::test]
+    async fn lock_and_verify_manifests() {
+        let dir = TempDir::new("lockfile");
+        let transfer_client = transfer_client(&dir).await;
+
+        let chart_file = dir.write(
+            "trino.yaml",
+            &format!(
+                "releaseName: trino
+name: trino
+repo:
+  name: trino
+  url: {REPO_URL}
+version: \"{{{{ chartVersion }}}}\"
+options: {{}}"
+            ),
+        );
+        let plain_file = dir.write("config.yaml", "apiVersion: v1\nkind: ConfigMap");
+
+        let chart_file = chart_file.display().to_string();
+        let plain_file = plain_file.display().to_string();
+        let manifests = [
+            manifest(ManifestSource::HelmChart(chart_file.clone())),
+            manifest(ManifestSource::PlainYaml(plain_file.clone())),
+            manifest(ManifestSource::PlainYaml(plain_file.clone())),
+        ];
+        let parameters = [Parameter {
+            description: "Version of the Trino chart".into(),
+            default: "^1.0.0".into(),
+            value: String::new(),
+            name: "chartVersion".into(),
+            secret: false,
+        }];
+
+        let mut lockfile = Lockfile::new(InstallRecordKind::Stack, "trino", "24.11");
+        lockfile
+            .lock_manifests(&manifests, &parameters, &transfer_client)
+            .await
+            .unwrap();
+
+        assert_eq!(lockfile.charts.len(), 1);
+        assert_eq!(lockfile.charts[0].file, chart_file);
+        assert_eq!(lockfile.charts[0].version, "1.2.0");
+
+        // Manifests used multiple times are only locked once
+        let locked: Vec<_> = lockfile
+            .manifests
+            .iter()
+            .map(|source| source.file.as_str())
+            .collect();
+        assert_eq!(locked, [chart_file.as_str(), plain_file.as_str()]);
+
+        let verified = lockfile
+            .verify_manifests(&manifests, &transfer_client)
+            .await
+            .unwrap();
+        assert_eq!(
+            verified.content(&plain_file).unwrap(),
+            "apiVersion: v1\nkind: ConfigMap"
+        );
+        assert_eq!(verified.chart_version(&chart_file).unwrap(), "1.2.0");
+
+        dir.write("config.yaml", "apiVersion: v1\nkind: Secret");
+
+        let result = lockfile
+            .verify_manifests(&manifests, &transfer_client)
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::FileChanged { file, .. }) if file == plain_file
+        ));
+    }
+}
@@ -1,19 +1,30 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
-use snafu::{ResultExt, Snafu};
+use snafu::{OptionExt, ResultExt, Snafu};
 use stackable_operator::kvp::Labels;
 use tracing::{debug, info, instrument};
+use url::Url;
 
 use crate::{
-    common::manifest::ManifestSpec,
+    common::manifest::{self, ManifestSource, ManifestSpec, WaitCondition},
+    constants::GENERATED_PASSWORDS_SECRET_NAME,
     helm,
+    platform::{
+        cluster::{ResourceEstimate, ResourceEstimateError},
+        diff::{self, ObjectDiff},
+        install_record::SpecSource,
+        lockfile::{self, LockedManifests},
+        prune::ManagedObject,
+        rollback::{Rollback, RollbackStep},
+    },
     utils::{
         k8s::{self, Client},
         path::{IntoPathOrUrl, PathOrUrlParseError},
+        templating::{ClusterFacts, GeneratedPasswords},
     },
     xfer::{
         self,
-        processor::{Processor, Template, Yaml},
+        processor::{self, Processor, Template, Text, Yaml},
     },
 };
 
@@ -38,6 +49,29 @@ pub enum Error {
         repo_name: String,
     },
 
+    /// This error indicates that the Helm repository was not added before and
+    /// can't be accessed in offline mode.
+    #[snafu(display("Helm repository {repo_name} is not available"))]
+    HelmRepositoryOffline {
+        source: xfer::Error,
+        repo_name: String,
+    },
+
+    /// This error indicates that the URL of the Helm repository is invalid.
+    #[snafu(display("failed to parse URL of Helm repository {repo_name}"))]
+    ParseHelmRepositoryUrl {
+        source: url::ParseError,
+        repo_name: String,
+    },
+
+    /// This error indicates that the Helm wrapper failed to check if the Helm
+    /// release already exists.
+    #[snafu(display("failed to check if Helm release {release_name} exists"))]
+    CheckHelmRelease {
+        release_name: String,
+        source: helm::Error,
+    },
+
     /// This error indicates that the Hlm wrapper failed to install the Helm
     /// release.
     #[snafu(display("failed to install Helm release {release_name}"))]
@@ -58,12 +92,102 @@ pub enum Error {
     /// This error indicates that the kube client failed to deloy manifests.
     #[snafu(display("failed to deploy manifests using the kube client"))]
     DeployManifest { source: k8s::Error },
+
+    /// This error indicates that the kube client failed to dry-run apply
+    /// manifests.
+    #[snafu(display("failed to dry-run manifests using the kube client"))]
+    DryRunManifest { source: k8s::Error },
+
+    /// This error indicates that the live and dry-run state of an object
+    /// could not be compared.
+    #[snafu(display("failed to diff object"))]
+    DiffObject { source: diff::Error },
+
+    /// This error indicates that the Helm wrapper failed to render the
+    /// manifests of the Helm chart.
+    #[snafu(display("failed to template Helm release {release_name}"))]
+    TemplateHelmRelease {
+        release_name: String,
+        source: helm::Error,
+    },
+
+    /// This error indicates that the resources of rendered manifests could
+    /// not be estimated.
+    #[snafu(display("failed to estimate resources of manifests"))]
+    EstimateResources { source: ResourceEstimateError },
+
+    /// This error indicates that the condition of a manifest could not be
+    /// rendered.
+    #[snafu(display("failed to render manifest condition {condition:?}"))]
+    RenderCondition {
+        source: processor::ProcessorError,
+        condition: String,
+    },
+
+    /// This error indicates that the condition of a manifest rendered to a
+    /// value other than `true` or `false`.
+    #[snafu(display(
+        "manifest condition {condition:?} rendered to {rendered:?}, expected true or false"
+    ))]
+    InvalidCondition { condition: String, rendered: String },
+
+    /// This error indicates that a manifest or Helm chart version differs
+    /// from the lockfile.
+    #[snafu(display("failed to verify manifest against the lockfile"))]
+    VerifyLockfile { source: lockfile::Error },
+
+    /// This error indicates that the verified content of a manifest could not
+    /// be rendered.
+    #[snafu(display("failed to process manifest {file}"))]
+    ProcessManifest {
+        source: processor::ProcessorError,
+        file: String,
+    },
+
+    /// This error indicates that a wait condition of a manifest was not met.
+    #[snafu(display("failed to wait for {condition}"))]
+    WaitForCondition {
+        source: k8s::Error,
+        condition: String,
+    },
+}
+
+/// The result of installing demo or stack manifests.
+#[derive(Debug, Default)]
+pub struct InstalledManifests {
+    /// All objects applied from plain YAML manifests
+    pub objects: Vec<ManagedObject>,
+
+    /// The installed Helm chart and YAML manifest files together with the
+    /// hash of their content
+    pub files: Vec<SpecSource>,
+}
+
+impl InstalledManifests {
+    /// Adds the objects and files of `other`. Files which were installed
+    /// already are only included once.
+    pub fn extend(&mut self, other: InstalledManifests) {
+        self.objects.extend(other.objects);
+
+        for file in other.files {
+            self.push_file(file);
+        }
+    }
+
+    fn push_file(&mut self, file: SpecSource) {
+        if !self.files.iter().any(|existing| existing.file == file.file) {
+            self.files.push(file);
+        }
+    }
 }
 
 pub trait InstallManifestsExt {
     // TODO (Techassi): This step shouldn't care about templating the manifests nor fetching them from remote
+    /// Installs the `manifests`. If `locked` manifests are provided, their
+    /// verified content is installed and Helm charts are installed using the
+    /// locked versions. Returns the applied objects and the installed files.
     #[instrument(skip_all)]
-    #[allow(async_fn_in_trait)]
+    #[allow(async_fn_in_trait, clippy::too_many_arguments)]
     async fn install_manifests(
         manifests: &[ManifestSpec],
         parameters: &HashMap<String, String>,
@@ -71,80 +195,544 @@ pub trait InstallManifestsExt {
         labels: Labels,
         client: &Client,
         transfer_client: &xfer::Client,
-    ) -> Result<(), Error> {
+        rollback: Option<&Rollback>,
+        locked: Option<&LockedManifests>,
+    ) -> Result<InstalledManifests, Error> {
         debug!("Installing demo / stack manifests");
 
-        for manifest in manifests {
-            match manifest {
-                ManifestSpec::HelmChart(helm_file) => {
-                    debug!("Installing manifest from Helm chart {}", helm_file);
+        let facts = ClusterFacts::gather(client, product_namespace).await;
+        let mut installed = InstalledManifests::default();
 
-                    // Read Helm chart YAML and apply templating
-                    let helm_file = helm_file.into_path_or_url().context(ParsePathOrUrlSnafu {
-                        path_or_url: helm_file.clone(),
-                    })?;
+        // Named passwords are stored in a Secret shared by all demos and
+        // stacks in the product namespace, so rollbacks only remove the
+        // passwords inserted by this installation
+        let passwords = Arc::new(GeneratedPasswords::default());
 
-                    let helm_chart: helm::Chart = transfer_client
-                        .get(&helm_file, &Template::new(parameters).then(Yaml::new()))
-                        .await
-                        .context(FileTransferSnafu)?;
-
-                    info!(
-                        "Installing Helm chart {} ({})",
-                        helm_chart.name, helm_chart.version
-                    );
-
-                    // Assumption: that all manifest helm charts refer to repos not registries
-                    helm::add_repo(&helm_chart.repo.name, &helm_chart.repo.url).context(
-                        AddHelmRepositorySnafu {
-                            repo_name: helm_chart.repo.name.clone(),
-                        },
-                    )?;
-
-                    // Serialize chart options to string
-                    let values_yaml = serde_yaml::to_string(&helm_chart.options)
-                        .context(SerializeOptionsSnafu)?;
-
-                    // Install the Helm chart using the Helm wrapper
-                    helm::install_release_from_repo_or_registry(
-                        &helm_chart.release_name,
-                        helm::ChartVersion {
-                            chart_source: &helm_chart.repo.name,
-                            chart_name: &helm_chart.name,
-                            chart_version: Some(&helm_chart.version),
-                        },
-                        Some(&values_yaml),
-                        product_namespace,
-                        true,
-                    )
-                    .context(InstallHelmReleaseSnafu {
-                        release_name: helm_chart.release_name,
-                    })?;
+        let result: Result<(), Error> = async {
+            for manifest in manifests {
+                let parameters = manifest.merge_parameters(parameters);
+                let template = Template::new(&parameters)
+                    .with_cluster(client, facts.clone())
+                    .with_labels(&labels, passwords.clone());
+
+                if !is_enabled(manifest, &template)? {
+                    continue;
                 }
-                ManifestSpec::PlainYaml(manifest_file) => {
-                    debug!("Installing YAML manifest from {}", manifest_file);
-
-                    // Read YAML manifest and apply templating
-                    let path_or_url =
-                        manifest_file
-                            .into_path_or_url()
-                            .context(ParsePathOrUrlSnafu {
-                                path_or_url: manifest_file.clone(),
+
+                match &manifest.source {
+                    ManifestSource::HelmChart(helm_file) => {
+                        debug!("Installing manifest from Helm chart {}", helm_file);
+
+                        let (helm_chart, file) =
+                            fetch_helm_chart(helm_file, &template, transfer_client, locked).await?;
+                        installed.push_file(file);
+                        add_helm_repo(&helm_chart, transfer_client)?;
+
+                        info!(
+                            "Installing Helm chart {} ({})",
+                            helm_chart.name, helm_chart.version
+                        );
+
+                        // Serialize chart options to string
+                        let values_yaml = serde_yaml::to_string(&helm_chart.options)
+                            .context(SerializeOptionsSnafu)?;
+
+                        // Record the Helm release before installing it, so that
+                        // releases which fail to install are rolled back as well
+                        if let Some(rollback) = rollback {
+                            let exists = helm::check_release_exists(
+                                &helm_chart.release_name,
+                                product_namespace,
+                            )
+                            .context(CheckHelmReleaseSnafu {
+                                release_name: helm_chart.release_name.clone(),
                             })?;
 
-                    let manifests = transfer_client
-                        .get(&path_or_url, &Template::new(parameters))
+                            if !exists {
+                                rollback.record(RollbackStep::UninstallHelmRelease {
+                                    release_name: helm_chart.release_name.clone(),
+                                    namespace: product_namespace.to_string(),
+                                });
+                            }
+                        }
+
+                        // Install the Helm chart using the Helm wrapper
+                        helm::install_release_from_repo_or_registry(
+                            &helm_chart.release_name,
+                            helm::ChartVersion {
+                                chart_source: &helm_chart.repo.name,
+                                chart_name: &helm_chart.name,
+                                chart_version: Some(&helm_chart.version),
+                            },
+                            Some(&values_yaml),
+                            product_namespace,
+                            true,
+                        )
+                        .context(InstallHelmReleaseSnafu {
+                            release_name: helm_chart.release_name,
+                        })?;
+                    }
+                    ManifestSource::PlainYaml(manifest_file) => {
+                        debug!("Installing YAML manifest from {}", manifest_file);
+
+                        let (manifests, file) =
+                            render_plain_yaml(manifest_file, &template, transfer_client, locked)
+                                .await?;
+                        installed.push_file(file);
+
+                        let objects = client
+                            .deploy_manifests(
+                                &manifests,
+                                product_namespace,
+                                labels.clone(),
+                                rollback,
+                            )
+                            .await
+                            .context(DeployManifestSnafu)?;
+
+                        installed.objects.extend(objects);
+                    }
+                }
+
+                // Block installation of the next manifest until all conditions
+                // of this manifest are met
+                for condition in &manifest.wait_for {
+                    wait_for_condition(condition, product_namespace, client).await?;
+                }
+            }
+
+            Ok(())
+        }
+        .await;
+
+        if let Some(rollback) = rollback {
+            for name in passwords.inserted() {
+                rollback.record(RollbackStep::RemoveSecretValue {
+                    namespace: product_namespace.to_string(),
+                    name: GENERATED_PASSWORDS_SECRET_NAME.to_string(),
+                    key: name,
+                });
+            }
+        }
+
+        result?;
+
+        // Keep the Secret storing named passwords when pruning, as long as
+        // the manifests use it
+        if passwords.used() {
+            installed.objects.push(ManagedObject {
+                group: String::new(),
+                kind: "Secret".to_string(),
+                namespace: Some(product_namespace.to_string()),
+                name: GENERATED_PASSWORDS_SECRET_NAME.to_string(),
+            });
+        }
+
+        Ok(installed)
+    }
+
+    /// Renders the `manifests` the same way [`InstallManifestsExt::install_manifests`]
+    /// does and compares them against the live objects in the cluster using
+    /// server-side dry-run applies. Nothing is changed in the cluster. Helm
+    /// charts are rendered client-side, using the locked chart versions if
+    /// `locked` manifests are provided.
+    #[instrument(skip_all)]
+    #[allow(async_fn_in_trait, clippy::too_many_arguments)]
+    async fn diff_manifests(
+        manifests: &[ManifestSpec],
+        parameters: &HashMap<String, String>,
+        product_namespace: &str,
+        labels: Labels,
+        client: &Client,
+        transfer_client: &xfer::Client,
+        locked: Option<&LockedManifests>,
+    ) -> Result<Vec<ObjectDiff>, Error> {
+        debug!("Diffing demo / stack manifests");
+
+        let facts = ClusterFacts::gather(client, product_namespace).await;
+        let mut diffs = Vec::new();
+
+        for manifest in manifests {
+            let parameters = manifest.merge_parameters(parameters);
+            let template = Template::new(&parameters)
+                .with_cluster(client, facts.clone())
+                .read_only();
+
+            if !is_enabled(manifest, &template)? {
+                continue;
+            }
+
+            let objects = match &manifest.source {
+                ManifestSource::HelmChart(helm_file) => {
+                    debug!("Diffing manifests of Helm chart {}", helm_file);
+
+                    let (helm_chart, _) =
+                        fetch_helm_chart(helm_file, &template, transfer_client, locked).await?;
+                    let manifests =
+                        render_helm_chart(&helm_chart, product_namespace, transfer_client)?;
+
+                    // Objects of Helm releases are owned by Helm and don't
+                    // carry our labels
+                    client
+                        .dry_run_manifests(&manifests, product_namespace, Labels::new(), true)
                         .await
-                        .context(FileTransferSnafu)?;
+                        .context(DryRunManifestSnafu)?
+                }
+                ManifestSource::PlainYaml(manifest_file) => {
+                    debug!("Diffing YAML manifest from {}", manifest_file);
+
+                    let (manifests, _) =
+                        render_plain_yaml(manifest_file, &template, transfer_client, locked)
+                            .await?;
 
                     client
-                        .deploy_manifests(&manifests, product_namespace, labels.clone())
+                        .dry_run_manifests(&manifests, product_namespace, labels.clone(), false)
                         .await
-                        .context(DeployManifestSnafu)?
+                        .context(DryRunManifestSnafu)?
                 }
+            };
+
+            for object in objects {
+                diffs.push(ObjectDiff::try_from(object).context(DiffObjectSnafu)?);
+            }
+        }
+
+        Ok(diffs)
+    }
+
+    /// Renders the `manifests` (including Helm charts) without installing
+    /// them and estimates the resources requested by the contained workloads.
+    /// Without a `client`, the manifests are rendered offline using
+    /// placeholder cluster facts and values.
+    #[instrument(skip_all)]
+    #[allow(async_fn_in_trait)]
+    async fn estimate_manifest_resources(
+        manifests: &[ManifestSpec],
+        parameters: &HashMap<String, String>,
+        product_namespace: &str,
+        client: Option<&Client>,
+        transfer_client: &xfer::Client,
+    ) -> Result<ResourceEstimate, Error> {
+        debug!("Estimating resources of demo / stack manifests");
+
+        let cluster = match client {
+            Some(client) => Some((
+                client,
+                ClusterFacts::gather(client, product_namespace).await,
+            )),
+            None => None,
+        };
+        let mut estimate = ResourceEstimate::default();
+
+        for manifest in manifests {
+            let parameters = manifest.merge_parameters(parameters);
+            let template = match &cluster {
+                Some((client, facts)) => Template::new(&parameters)
+                    .with_cluster(client, facts.clone())
+                    .read_only(),
+                None => Template::new(&parameters).offline(),
+            };
+
+            if !is_enabled(manifest, &template)? {
+                continue;
             }
+
+            let rendered = match &manifest.source {
+                ManifestSource::HelmChart(helm_file) => {
+                    debug!("Rendering manifests of Helm chart {}", helm_file);
+
+                    let (helm_chart, _) =
+                        fetch_helm_chart(helm_file, &template, transfer_client, None).await?;
+                    render_helm_chart(&helm_chart, product_namespace, transfer_client)?
+                }
+                ManifestSource::PlainYaml(manifest_file) => {
+                    debug!("Rendering YAML manifest from {}", manifest_file);
+
+                    render_plain_yaml(manifest_file, &template, transfer_client, None)
+                        .await?
+                        .0
+                }
+            };
+
+            estimate.extend(
+                ResourceEstimate::from_manifests(&rendered).context(EstimateResourcesSnafu)?,
+            );
         }
 
-        Ok(())
+        Ok(estimate)
+    }
+}
+
+/// Renders the condition of the `manifest` (if any) using the `template`
+/// processor and returns if the manifest should be installed.
+fn is_enabled(manifest: &ManifestSpec, template: &Template<'_>) -> Result<bool, Error> {
+    let Some(condition) = &manifest.condition else {
+        return Ok(true);
+    };
+
+    let rendered = template
+        .process(condition.clone())
+        .context(RenderConditionSnafu {
+            condition: condition.clone(),
+        })?;
+
+    let enabled = manifest::evaluate_condition(&rendered).context(InvalidConditionSnafu {
+        condition: condition.clone(),
+        rendered,
+    })?;
+
+    if !enabled {
+        info!(
+            "Skipping {} because its condition {:?} is not met",
+            manifest.source, condition
+        );
+    }
+
+    Ok(enabled)
+}
+
+/// Reads the Helm chart definition `helm_file` and applies templating using
+/// the provided `template` processor. If `locked` manifests are provided, the
+/// verified content and the locked chart version are used. The chart is
+/// returned together with the hash of the file content.
+async fn fetch_helm_chart(
+    helm_file: &str,
+    template: &Template<'_>,
+    transfer_client: &xfer::Client,
+    locked: Option<&LockedManifests>,
+) -> Result<(helm::Chart, SpecSource), Error> {
+    let locked_version = locked
+        .map(|locked| locked.chart_version(helm_file))
+        .transpose()
+        .context(VerifyLockfileSnafu)?;
+
+    let (mut helm_chart, file): (helm::Chart, _) = fetch_manifest(
+        helm_file,
+        &template.clone().then(Yaml::new()),
+        transfer_client,
+        locked,
+    )
+    .await?;
+
+    if let Some(version) = locked_version {
+        helm_chart.version = version.to_string();
+    }
+
+    Ok((helm_chart, file))
+}
+
+/// Adds the Helm repository of the `helm_chart`, so that the chart can be
+/// installed. In offline mode, the repository can't be added and needs to be
+/// added already.
+fn add_helm_repo(helm_chart: &helm::Chart, transfer_client: &xfer::Client) -> Result<(), Error> {
+    match transfer_client.offline {
+        // Assumption: that all manifest helm charts refer to repos not registries
+        false => helm::add_repo(&helm_chart.repo.name, &helm_chart.repo.url).context(
+            AddHelmRepositorySnafu {
+                repo_name: helm_chart.repo.name.clone(),
+            },
+        ),
+        true if helm::check_repo_exists(&helm_chart.repo.name, &helm_chart.repo.url) => Ok(()),
+        true => Err(helm_repo_offline_error(helm_chart)),
+    }
+}
+
+/// Returns the error for Helm repositories which were not added before and
+/// can't be accessed in offline mode.
+fn helm_repo_offline_error(helm_chart: &helm::Chart) -> Error {
+    let repo_name = helm_chart.repo.name.clone();
+
+    match Url::parse(&helm_chart.repo.url) {
+        Ok(url) => Error::HelmRepositoryOffline {
+            source: xfer::Error::NotCachedOffline { url },
+            repo_name,
+        },
+        Err(source) => Error::ParseHelmRepositoryUrl { source, repo_name },
+    }
+}
+
+/// Renders the manifests of the `helm_chart` client-side using the same
+/// values it is installed with. Rendering doesn't change the added Helm
+/// repositories, so charts from repositories which were not added before are
+/// looked up in the repository directly.
+fn render_helm_chart(
+    helm_chart: &helm::Chart,
+    product_namespace: &str,
+    transfer_client: &xfer::Client,
+) -> Result<String, Error> {
+    let values_yaml = serde_yaml::to_string(&helm_chart.options).context(SerializeOptionsSnafu)?;
+
+    let repo_url = match helm::check_repo_exists(&helm_chart.repo.name, &helm_chart.repo.url) {
+        true => None,
+        false if transfer_client.offline => return Err(helm_repo_offline_error(helm_chart)),
+        false => Some(helm_chart.repo.url.as_str()),
+    };
+
+    helm::template_release(
+        &helm_chart.release_name,
+        helm::ChartVersion {
+            chart_source: &helm_chart.repo.name,
+            chart_name: &helm_chart.name,
+            chart_version: Some(&helm_chart.version),
+        },
+        repo_url,
+        Some(&values_yaml),
+        product_namespace,
+    )
+    .context(TemplateHelmReleaseSnafu {
+        release_name: helm_chart.release_name.clone(),
+    })
+}
+
+/// Reads the plain YAML `manifest_file` and applies templating using the
+/// provided `template` processor. If `locked` manifests are provided, the
+/// verified content is used. The rendered manifests are returned together
+/// with the hash of the file content.
+async fn render_plain_yaml(
+    manifest_file: &str,
+    template: &Template<'_>,
+    transfer_client: &xfer::Client,
+    locked: Option<&LockedManifests>,
+) -> Result<(String, SpecSource), Error> {
+    fetch_manifest(manifest_file, template, transfer_client, locked).await
+}
+
+/// Retrieves the manifest `file` and processes it using the `processor`. If
+/// `locked` manifests are provided, the verified content is processed instead
+/// of retrieving the file again, so that exactly the verified content is
+/// installed. The processed output is returned together with the hash of the
+/// unprocessed content.
+async fn fetch_manifest<P>(
+    file: &str,
+    processor: &P,
+    transfer_client: &xfer::Client,
+    locked: Option<&LockedManifests>,
+) -> Result<(P::Output, SpecSource), Error>
+where
+    P: Processor<Input = String>,
+{
+    let content = match locked {
+        Some(locked) => locked
+            .content(file)
+            .context(VerifyLockfileSnafu)?
+            .to_owned(),
+        None => {
+            let path_or_url = file.into_path_or_url().context(ParsePathOrUrlSnafu {
+                path_or_url: file.to_string(),
+            })?;
+
+            transfer_client
+                .get(&path_or_url, &Text)
+                .await
+                .context(FileTransferSnafu)?
+        }
+    };
+
+    let source = SpecSource::new(file, &content);
+    let output = processor
+        .process(content)
+        .context(ProcessManifestSnafu { file })?;
+
+    Ok((output, source))
+}
+
+/// Waits until the `condition` is met. Objects without an explicit namespace
+/// are looked up in the `product_namespace`.
+#[instrument(skip(client))]
+async fn wait_for_condition(
+    condition: &WaitCondition,
+    product_namespace: &str,
+    client: &Client,
+) -> Result<(), Error> {
+    let target = condition.target();
+    info!(
+        "Waiting up to {} seconds for {}",
+        target.timeout_seconds, condition
+    );
+
+    let namespace = target.namespace.as_deref().unwrap_or(product_namespace);
+    let timeout = Duration::from_secs(target.timeout_seconds);
+
+    let result = match condition {
+        WaitCondition::DeploymentReady(target) => {
+            client
+                .wait_for_deployment_ready(namespace, &target.name, timeout)
+                .await
+        }
+        WaitCondition::StatefulSetReady(target) => {
+            client
+                .wait_for_stateful_set_ready(namespace, &target.name, timeout)
+                .await
+        }
+        WaitCondition::JobCompleted(target) => {
+            client
+                .wait_for_job_completed(namespace, &target.name, timeout)
+                .await
+        }
+        WaitCondition::CrdEstablished(target) => {
+            client.wait_for_crd_established(&target.name, timeout).await
+        }
+    };
+
+    result.context(WaitForConditionSnafu {
+        condition: condition.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utils::temp_dir::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn record_hash_of_unrendered_manifest() {
+        let dir = TempDir::new("manifests");
+        let content = "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: {{ name }}\n";
+        let file = dir.write("configmap.yaml", content);
+        let file = file.to_str().unwrap();
+
+        let cache = xfer::cache::Settings::disk(dir.path().join("cache"))
+            .try_into_cache()
+            .await
+            .unwrap();
+        let transfer_client = xfer::Client::new_with(cache);
+
+        let parameters = HashMap::from([("name".to_string(), "trino".to_string())]);
+        let template = Template::new(&parameters).offline();
+
+        let (rendered, source) = render_plain_yaml(file, &template, &transfer_client, None)
+            .await
+            .unwrap();
+
+        assert!(rendered.contains("name: trino"));
+        assert_eq!(source.file, file);
+        assert_eq!(source.sha256, SpecSource::new(file, content).sha256);
+        assert_ne!(source.sha256, SpecSource::new(file, &rendered).sha256);
+    }
+
+    #[test]
+    fn installed_files_are_deduplicated() {
+        let mut installed = InstalledManifests {
+            objects: Vec::new(),
+            files: vec![SpecSource::new("trino.yaml", "a")],
+        };
+
+        installed.extend(InstalledManifests {
+            objects: Vec::new(),
+            files: vec![
+                SpecSource::new("trino.yaml", "a"),
+                SpecSource::new("superset.yaml", "b"),
+            ],
+        });
+
+        assert_eq!(
+            installed
+                .files
+                .iter()
+                .map(|source| source.file.as_str())
+                .collect::<Vec<_>>(),
+            ["trino.yaml", "superset.yaml"]
+        );
     }
 }
@@ -1,11 +1,17 @@
 pub mod cluster;
 pub mod credentials;
 pub mod demo;
+pub mod diff;
+pub mod install_record;
+pub mod lint;
+pub mod lockfile;
 pub mod manifests;
 pub mod namespace;
 pub mod operator;
 pub mod product;
+pub mod prune;
 pub mod release;
+pub mod rollback;
 pub mod service;
 pub mod stack;
 pub mod stacklet;
@@ -0,0 +1,344 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fmt::Display,
+};
+
+use kube::{api::ListParams, core::DynamicObject, discovery::ApiResource, ResourceExt};
+use serde::{Deserialize, Serialize};
+use snafu::{ResultExt, Snafu};
+use stackable_operator::kvp::Labels;
+use tracing::{info, instrument};
+
+use crate::{
+    platform::install_record::{self, InstallRecord, INSTALL_RECORD_LABEL},
+    utils::k8s::{self, Client},
+};
+
+/// Label which is attached to all objects installed as part of a demo. Stack
+/// installs exclude these objects from pruning.
+const DEMO_LABEL: &str = "stackable.tech/demo";
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    /// This error indicates that the install record of the previous
+    /// installation could not be read.
+    #[snafu(display("failed to read previous install record"))]
+    ReadInstallRecord { source: install_record::Error },
+
+    /// This error indicates that the kube client failed to list objects
+    /// which are candidates for pruning.
+    #[snafu(display("failed to list objects to prune"))]
+    ListObjects { source: k8s::Error },
+
+    /// This error indicates that the kube client failed to delete an
+    /// orphaned object.
+    #[snafu(display("failed to prune {object}"))]
+    DeleteObject {
+        source: k8s::Error,
+        object: ManagedObject,
+    },
+}
+
+/// Controls if orphaned objects are deleted or only reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PruneMode {
+    /// Orphaned objects are deleted
+    Delete,
+
+    /// Orphaned objects are only reported, nothing is deleted
+    DryRun,
+}
+
+/// An object managed by stackablectl, identified by its API group, kind,
+/// namespace and name. The namespace is [`None`] for cluster-scoped objects.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct ManagedObject {
+    pub group: String,
+    pub kind: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+impl ManagedObject {
+    /// Returns the API group and kind of this object.
+    pub fn resource_kind(&self) -> ResourceKind {
+        ResourceKind {
+            group: self.group.clone(),
+            kind: self.kind.clone(),
+        }
+    }
+}
+
+impl Display for ManagedObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.group.as_str() {
+            "" => self.kind.to_lowercase(),
+            group => format!("{}.{}", self.kind.to_lowercase(), group),
+        };
+
+        match &self.namespace {
+            Some(namespace) => write!(f, "{kind}/{} (namespace {namespace})", self.name),
+            None => write!(f, "{kind}/{}", self.name),
+        }
+    }
+}
+
+/// The API group and kind of objects managed by stackablectl. Install records
+/// store the kinds of all applied objects, which limits the resource types
+/// listed when pruning.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct ResourceKind {
+    pub group: String,
+    pub kind: String,
+}
+
+/// Returns the distinct kinds of the `applied` objects.
+pub fn resource_kinds(applied: &[ManagedObject]) -> Vec<ResourceKind> {
+    applied
+        .iter()
+        .map(ManagedObject::resource_kind)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Deletes all objects carrying the `labels` of a demo or stack which were
+/// not part of the most recent installation, e.g. because they were removed
+/// from the manifests. Namespaced objects are only considered in the product
+/// namespace of the `record`. Objects installed via Helm charts don't carry
+/// these labels and are therefore never pruned.
+///
+/// Only objects of the kinds in `applied` and the kinds stored in the previous
+/// install record are considered, instead of listing every resource type
+/// known to the cluster. Records written by older versions don't contain any
+/// kinds, in which case only the kinds in `applied` are considered.
+///
+/// When `exclude_demos` is set, objects which were installed as part of a
+/// demo are left untouched. This is used when installing a stack on its own.
+/// Returns the pruned objects, or the objects which would be pruned when
+/// running in [`PruneMode::DryRun`].
+#[instrument(skip(applied, record, labels, client))]
+pub async fn prune_objects(
+    applied: &[ManagedObject],
+    record: &InstallRecord,
+    labels: &Labels,
+    exclude_demos: bool,
+    mode: PruneMode,
+    client: &Client,
+) -> Result<Vec<ManagedObject>, Error> {
+    info!("Pruning orphaned objects");
+
+    let previous = record
+        .previous(client)
+        .await
+        .context(ReadInstallRecordSnafu)?;
+
+    let kinds: HashSet<ResourceKind> = applied
+        .iter()
+        .map(ManagedObject::resource_kind)
+        .chain(previous.into_iter().flat_map(|record| record.kinds))
+        .collect();
+
+    let list_params = ListParams::default().labels(&prune_selector(labels, exclude_demos));
+
+    let objects = client
+        .list_labeled_objects(&record.product_namespace, &kinds, &list_params)
+        .await
+        .context(ListObjectsSnafu)?;
+
+    let orphaned = select_orphaned(applied, objects);
+
+    if mode == PruneMode::DryRun {
+        return Ok(orphaned.into_keys().collect());
+    }
+
+    for (object, resource) in &orphaned {
+        info!("Pruning {object}");
+
+        client
+            .delete_object(resource, object.namespace.as_deref(), &object.name)
+            .await
+            .context(DeleteObjectSnafu {
+                object: object.clone(),
+            })?;
+    }
+
+    Ok(orphaned.into_keys().collect())
+}
+
+/// Selects the listed `objects` which are not part of the `applied` objects.
+/// The orphaned objects are returned together with the API resource needed
+/// to delete them.
+fn select_orphaned(
+    applied: &[ManagedObject],
+    objects: Vec<(ApiResource, DynamicObject)>,
+) -> BTreeMap<ManagedObject, ApiResource> {
+    let applied: HashSet<&ManagedObject> = applied.iter().collect();
+    let mut orphaned = BTreeMap::new();
+
+    for (resource, object) in objects {
+        let managed_object = ManagedObject {
+            group: resource.group.clone(),
+            kind: resource.kind.clone(),
+            namespace: object.namespace(),
+            name: object.name_any(),
+        };
+
+        if !applied.contains(&managed_object) {
+            orphaned.insert(managed_object, resource);
+        }
+    }
+
+    orphaned
+}
+
+/// Builds the label selector matching all objects which are candidates for
+/// pruning. Install records are always excluded, as they are written after
+/// the installation finished.
+fn prune_selector(labels: &Labels, exclude_demos: bool) -> String {
+    let labels: BTreeMap<String, String> = labels.clone().into();
+
+    let mut selector: Vec<String> = labels
+        .into_iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect();
+
+    selector.push(format!("!{INSTALL_RECORD_LABEL}"));
+
+    if exclude_demos {
+        selector.push(format!("!{DEMO_LABEL}"));
+    }
+
+    selector.join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use kube::core::GroupVersionKind;
+
+    use super::*;
+
+    fn managed_object(
+        group: &str,
+        kind: &str,
+        namespace: Option<&str>,
+        name: &str,
+    ) -> ManagedObject {
+        ManagedObject {
+            group: group.to_string(),
+            kind: kind.to_string(),
+            namespace: namespace.map(ToString::to_string),
+            name: name.to_string(),
+        }
+    }
+
+    fn listed_object(
+        group: &str,
+        version: &str,
+        kind: &str,
+        namespace: Option<&str>,
+        name: &str,
+    ) -> (ApiResource, DynamicObject) {
+        let resource = ApiResource::from_gvk(&GroupVersionKind::gvk(group, version, kind));
+        let mut object = DynamicObject::new(name, &resource);
+        object.metadata.namespace = namespace.map(ToString::to_string);
+
+        (resource, object)
+    }
+
+    #[test]
+    fn select_orphaned_objects() {
+        let applied = [
+            managed_object("apps", "Deployment", Some("default"), "grafana"),
+            managed_object("", "ConfigMap", Some("default"), "grafana-dashboards"),
+            managed_object("rbac.authorization.k8s.io", "ClusterRole", None, "grafana"),
+        ];
+
+        let objects = vec![
+            listed_object("apps", "v1", "Deployment", Some("default"), "grafana"),
+            listed_object("apps", "v1", "Deployment", Some("default"), "prometheus"),
+            listed_object("", "v1", "ConfigMap", Some("default"), "grafana-dashboards"),
+            listed_object("", "v1", "Secret", Some("default"), "grafana-dashboards"),
+            listed_object(
+                "rbac.authorization.k8s.io",
+                "v1",
+                "ClusterRole",
+                None,
+                "grafana",
+            ),
+            listed_object(
+                "rbac.authorization.k8s.io",
+                "v1",
+                "ClusterRole",
+                None,
+                "prometheus",
+            ),
+        ];
+
+        let orphaned = select_orphaned(&applied, objects);
+
+        assert_eq!(
+            orphaned.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                managed_object("", "Secret", Some("default"), "grafana-dashboards"),
+                managed_object("apps", "Deployment", Some("default"), "prometheus"),
+                managed_object(
+                    "rbac.authorization.k8s.io",
+                    "ClusterRole",
+                    None,
+                    "prometheus"
+                ),
+            ]
+        );
+        assert_eq!(
+            orphaned[&managed_object("apps", "Deployment", Some("default"), "prometheus")]
+                .api_version,
+            "apps/v1"
+        );
+    }
+
+    #[test]
+    fn resource_kinds_are_distinct() {
+        let applied = [
+            managed_object("apps", "Deployment", Some("default"), "grafana"),
+            managed_object("apps", "Deployment", Some("default"), "prometheus"),
+            managed_object("", "ConfigMap", Some("default"), "grafana-dashboards"),
+        ];
+
+        assert_eq!(
+            resource_kinds(&applied),
+            vec![
+                ResourceKind {
+                    group: String::new(),
+                    kind: "ConfigMap".to_string(),
+                },
+                ResourceKind {
+                    group: "apps".to_string(),
+                    kind: "Deployment".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn prune_selector_for_stack() {
+        let labels = Labels::try_from([
+            ("stackable.tech/managed-by", "stackablectl"),
+            ("stackable.tech/stack", "monitoring"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            prune_selector(&labels, true),
+            "stackable.tech/managed-by=stackablectl,stackable.tech/stack=monitoring,\
+             !stackable.tech/install-record,!stackable.tech/demo"
+        );
+        assert_eq!(
+            prune_selector(&labels, false),
+            "stackable.tech/managed-by=stackablectl,stackable.tech/stack=monitoring,\
+             !stackable.tech/install-record"
+        );
+    }
+}
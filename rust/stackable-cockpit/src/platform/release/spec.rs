@@ -9,11 +9,13 @@ use tracing::{info, instrument};
 use utoipa::ToSchema;
 
 use crate::{
+    common::list::{FilterableSpec, ResolveReferences},
     helm,
     platform::{
         operator::{self, ChartSourceType, OperatorSpec},
         product,
     },
+    utils::path::PathOrUrl,
 };
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -48,6 +50,26 @@ pub struct ReleaseSpec {
     pub products: IndexMap<String, product::ProductSpec>,
 }
 
+impl FilterableSpec for ReleaseSpec {
+    // Releases don't have any labels
+    fn labels(&self) -> &[String] {
+        &[]
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn release<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        Some(name)
+    }
+}
+
+// Releases don't reference any files
+impl ResolveReferences for ReleaseSpec {
+    fn resolve_references(&mut self, _origin: &PathOrUrl) {}
+}
+
 impl ReleaseSpec {
     /// Installs a release by installing individual operators.
     #[instrument(skip_all)]
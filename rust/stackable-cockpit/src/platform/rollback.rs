@@ -0,0 +1,113 @@
+use std::sync::Mutex;
+
+use kube::discovery::ApiResource;
+use tracing::{info, instrument, warn};
+
+use crate::{helm, utils::k8s::Client};
+
+/// A single change to the cluster which can be undone during a rollback.
+#[derive(Debug, Clone)]
+pub enum RollbackStep {
+    /// An object which didn't exist before and was created while applying
+    /// manifests.
+    DeleteObject {
+        resource: ApiResource,
+        namespace: Option<String>,
+        name: String,
+    },
+
+    /// A key which didn't exist before and was inserted into a shared Secret,
+    /// like the one storing named passwords. Other keys are left untouched.
+    RemoveSecretValue {
+        namespace: String,
+        name: String,
+        key: String,
+    },
+
+    /// A Helm release which didn't exist before and was installed while
+    /// installing manifests.
+    UninstallHelmRelease {
+        release_name: String,
+        namespace: String,
+    },
+}
+
+/// Tracks changes made to the cluster while installing a demo or stack. If a
+/// later installation step fails, [`Rollback::run`] undoes all recorded
+/// changes in reverse order.
+///
+/// Objects and Helm releases which already existed before the installation
+/// are not recorded, as they cannot be restored to their previous state.
+#[derive(Debug, Default)]
+pub struct Rollback {
+    steps: Mutex<Vec<RollbackStep>>,
+}
+
+impl Rollback {
+    /// Records a change which gets undone when the rollback is run.
+    pub fn record(&self, step: RollbackStep) {
+        self.steps
+            .lock()
+            .expect("rollback steps lock must not be poisoned")
+            .push(step);
+    }
+
+    /// Undoes all recorded changes in reverse order. Failing steps are logged
+    /// and skipped, so that as many changes as possible are undone. Returns
+    /// the number of steps which failed.
+    #[instrument(skip_all)]
+    pub async fn run(self, client: &Client) -> usize {
+        let steps = self
+            .steps
+            .into_inner()
+            .expect("rollback steps lock must not be poisoned");
+
+        info!("Rolling back {} change(s)", steps.len());
+        let mut failed = 0;
+
+        for step in steps.into_iter().rev() {
+            match step {
+                RollbackStep::DeleteObject {
+                    resource,
+                    namespace,
+                    name,
+                } => {
+                    info!("Deleting {} {:?}", resource.kind, name);
+
+                    if let Err(err) = client
+                        .delete_object(&resource, namespace.as_deref(), &name)
+                        .await
+                    {
+                        warn!("Failed to delete {} {:?}: {}", resource.kind, name, err);
+                        failed += 1;
+                    }
+                }
+                RollbackStep::RemoveSecretValue {
+                    namespace,
+                    name,
+                    key,
+                } => {
+                    info!("Removing {:?} from Secret {:?}", key, name);
+
+                    if let Err(err) = client.remove_secret_value(&namespace, &name, &key).await {
+                        warn!("Failed to remove {:?} from Secret {:?}: {}", key, name, err);
+                        failed += 1;
+                    }
+                }
+                RollbackStep::UninstallHelmRelease {
+                    release_name,
+                    namespace,
+                } => {
+                    info!("Uninstalling Helm release {}", release_name);
+
+                    if let Err(err) = helm::uninstall_release(&release_name, &namespace, true) {
+                        warn!("Failed to uninstall Helm release {}: {}", release_name, err);
+                        failed += 1;
+                    }
+                }
+            }
+        }
+
+        failed
+    }
+}
@@ -1,6 +1,8 @@
 use stackable_operator::kvp::Labels;
 
-use crate::platform::operator::ChartSourceType;
+use crate::platform::{
+    install_record::SpecSource, lockfile::Lockfile, operator::ChartSourceType, prune::PruneMode,
+};
 
 #[derive(Debug)]
 pub struct StackInstallParameters {
@@ -12,6 +14,35 @@ pub struct StackInstallParameters {
 
     pub parameters: Vec<String>,
     pub skip_release: bool,
+    pub rollback_on_failure: bool,
+
+    /// Skips estimating the resources of the stack from its rendered
+    /// manifests if the spec doesn't define resource requests. The resource
+    /// validation is skipped in that case.
+    pub skip_resource_estimate: bool,
+
+    /// Deletes (or only reports in dry-run mode) objects of previous
+    /// installations which are not part of the stack anymore.
+    pub prune: Option<PruneMode>,
     pub labels: Labels,
     pub chart_source: ChartSourceType,
+
+    /// Stack and release files used to install the stack, together with the
+    /// hash of their content. These are persisted in the install record.
+    pub spec_sources: Vec<SpecSource>,
+
+    /// Installs exactly the files and versions recorded in the lockfile.
+    /// The installation fails if anything changed.
+    pub lockfile: Option<Lockfile>,
+}
+
+#[derive(Debug)]
+pub struct StackDiffParameters {
+    pub stack_name: String,
+    pub product_namespace: String,
+    pub parameters: Vec<String>,
+    pub labels: Labels,
+
+    /// Renders Helm charts using the versions recorded in the lockfile.
+    pub lockfile: Option<Lockfile>,
 }
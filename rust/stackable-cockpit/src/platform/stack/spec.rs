@@ -1,25 +1,37 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use snafu::{OptionExt, ResultExt, Snafu};
+use snafu::{ensure, OptionExt, ResultExt, Snafu};
 use tracing::{debug, info, instrument, log::warn};
 
 #[cfg(feature = "openapi")]
 use utoipa::ToSchema;
 
 use crate::{
-    common::manifest::ManifestSpec,
+    common::{
+        list::{FilterableSpec, ResolveReferences},
+        manifest::ManifestSpec,
+    },
     platform::{
-        cluster::{ResourceRequests, ResourceRequestsError},
-        manifests::{self, InstallManifestsExt},
+        cluster::{ResourceEstimate, ResourceRequests, ResourceRequestsError},
+        diff::ObjectDiff,
+        install_record::{self, InstallRecord, InstallRecordKind},
+        lockfile::{self, LockedManifests, Lockfile},
+        manifests::{self, InstallManifestsExt, InstalledManifests},
         namespace,
         operator::ChartSourceType,
+        prune::{self, ManagedObject},
         release,
-        stack::StackInstallParameters,
+        rollback::Rollback,
+        stack::{StackDiffParameters, StackInstallParameters},
     },
     utils::{
         k8s::Client,
         params::{
             IntoParameters, IntoParametersError, Parameter, RawParameter, RawParameterParseError,
         },
+        path::PathOrUrl,
     },
     xfer,
 };
@@ -63,6 +75,51 @@ pub enum Error {
 
     #[snafu(display("failed to install stack manifests"))]
     InstallManifests { source: manifests::Error },
+
+    #[snafu(display("failed to diff stack manifests"))]
+    DiffManifests { source: manifests::Error },
+
+    #[snafu(display("failed to estimate resources of stack manifests"))]
+    EstimateResources { source: manifests::Error },
+
+    #[snafu(display("failed to write install record"))]
+    WriteInstallRecord { source: install_record::Error },
+
+    #[snafu(display("failed to prune orphaned objects"))]
+    PruneObjects { source: prune::Error },
+
+    /// This error indicates that the stack, release or operators differ from
+    /// the lockfile.
+    #[snafu(display("failed to verify stack against the lockfile"))]
+    VerifyLockfile { source: lockfile::Error },
+
+    /// This error indicates that a stack includes a stack which doesn't exist
+    /// in the loaded list of stacks.
+    #[snafu(display("stack {included_by:?} includes unknown stack {name:?}"))]
+    NoSuchIncludedStack { name: String, included_by: String },
+
+    /// This error indicates that stacks include each other.
+    #[snafu(display("stacks include each other: {}", cycle.join(" -> ")))]
+    IncludeCycle { cycle: Vec<String> },
+
+    /// This error indicates that an included stack uses a different release
+    /// than the stack including it.
+    #[snafu(display(
+        "included stack {name:?} uses release {release:?}, but {stack_name:?} uses release {expected:?}"
+    ))]
+    IncludedReleaseMismatch {
+        name: String,
+        release: String,
+        stack_name: String,
+        expected: String,
+    },
+
+    /// This error indicates that a stack and the stacks it includes don't
+    /// support a common namespace.
+    #[snafu(display(
+        "stack {stack_name:?} and its included stacks don't support a common namespace"
+    ))]
+    NoCommonNamespace { stack_name: String },
 }
 
 /// This struct describes a stack with the v2 spec
@@ -100,22 +157,198 @@ pub struct StackSpec {
     /// A variable number of supported parameters
     #[serde(default)]
     pub parameters: Vec<StackParameter>,
+
+    /// A variable number of stacks which are installed before this stack.
+    /// They are merged into this stack using [`StackSpec::resolve_includes`]
+    /// when installing, diffing or estimating the stack.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub includes: Vec<String>,
 }
 
 impl InstallManifestsExt for StackSpec {}
 
+impl ResolveReferences for StackSpec {
+    fn resolve_references(&mut self, origin: &PathOrUrl) {
+        for manifest in &mut self.manifests {
+            manifest.source.resolve(origin);
+        }
+    }
+}
+
+impl FilterableSpec for StackSpec {
+    fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn release<'a>(&'a self, _name: &'a str) -> Option<&'a str> {
+        Some(&self.release)
+    }
+}
+
 impl StackSpec {
+    /// Merges the (transitively) included stacks into this stack, which is
+    /// named `stack_name` in the list of `stacks`. The manifests of included
+    /// stacks are placed in front of the manifests of this stack, so that they
+    /// are installed first. Each stack is included only once. Operators are
+    /// deduplicated and the parameters of all stacks are passed through, with
+    /// parameters of including stacks taking precedence. The resource requests
+    /// are added up if all stacks specify them, otherwise they are left empty
+    /// to be estimated. Only namespaces supported by all stacks are supported.
+    /// All other fields are taken from this stack. All included stacks need to
+    /// use the same release as this stack.
+    pub fn resolve_includes(
+        &self,
+        stack_name: &str,
+        stacks: &IndexMap<String, StackSpec>,
+    ) -> Result<StackSpec, Error> {
+        let mut included = Vec::new();
+        self.collect_includes(
+            stacks,
+            &mut vec![stack_name.to_string()],
+            &mut HashSet::new(),
+            &mut included,
+        )?;
+
+        if included.is_empty() {
+            return Ok(self.clone());
+        }
+
+        for (name, stack) in &included {
+            ensure!(
+                stack.release == self.release,
+                IncludedReleaseMismatchSnafu {
+                    name: name.clone(),
+                    release: stack.release.clone(),
+                    stack_name,
+                    expected: self.release.clone(),
+                }
+            );
+        }
+
+        let mut resolved = StackSpec {
+            manifests: Vec::new(),
+            operators: Vec::new(),
+            parameters: Vec::new(),
+            includes: Vec::new(),
+            supported_namespaces: Vec::new(),
+            ..self.clone()
+        };
+        let mut parameters = IndexMap::new();
+        let stacks: Vec<_> = included
+            .into_iter()
+            .map(|(_, stack)| stack)
+            .chain([self])
+            .collect();
+
+        // The resources of stacks without resource requests are unknown, so
+        // the requests of all stacks need to be estimated instead
+        resolved.resource_requests = stacks
+            .iter()
+            .map(|stack| stack.resource_requests.as_ref())
+            .collect::<Option<Vec<_>>>()
+            .map(ResourceRequests::sum)
+            .transpose()?;
+
+        // An empty list of supported namespaces supports all namespaces
+        for stack in &stacks {
+            if stack.supported_namespaces.is_empty() {
+                continue;
+            }
+
+            if resolved.supported_namespaces.is_empty() {
+                resolved
+                    .supported_namespaces
+                    .clone_from(&stack.supported_namespaces);
+            } else {
+                resolved
+                    .supported_namespaces
+                    .retain(|namespace| stack.supported_namespaces.contains(namespace));
+                ensure!(
+                    !resolved.supported_namespaces.is_empty(),
+                    NoCommonNamespaceSnafu { stack_name }
+                );
+            }
+        }
+
+        for stack in stacks {
+            resolved.manifests.extend(stack.manifests.iter().cloned());
+
+            for operator in &stack.operators {
+                if !resolved.operators.contains(operator) {
+                    resolved.operators.push(operator.clone());
+                }
+            }
+
+            for parameter in &stack.parameters {
+                parameters.insert(parameter.name.clone(), parameter.clone());
+            }
+        }
+
+        resolved.parameters = parameters.into_values().collect();
+        Ok(resolved)
+    }
+
+    /// Collects the stacks included by this stack depth-first into `included`,
+    /// so that included stacks are listed before the stacks including them.
+    /// The `path` contains the chain of stacks leading to this stack and is
+    /// used to detect cycles.
+    fn collect_includes<'a>(
+        &self,
+        stacks: &'a IndexMap<String, StackSpec>,
+        path: &mut Vec<String>,
+        visited: &mut HashSet<String>,
+        included: &mut Vec<(String, &'a StackSpec)>,
+    ) -> Result<(), Error> {
+        for name in &self.includes {
+            if path.contains(name) {
+                let mut cycle = path.clone();
+                cycle.push(name.clone());
+
+                return IncludeCycleSnafu { cycle }.fail();
+            }
+
+            // Stacks which are included multiple times are installed once
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let stack = stacks.get(name).context(NoSuchIncludedStackSnafu {
+                name: name.clone(),
+                included_by: path.last().cloned().unwrap_or_default(),
+            })?;
+
+            path.push(name.clone());
+            stack.collect_includes(stacks, path, visited, included)?;
+            path.pop();
+
+            included.push((name.clone(), stack));
+        }
+
+        Ok(())
+    }
+
     /// Checks if the prerequisites to run this stack are met. These checks
     /// include:
     ///
     /// - Does the stack support to be installed in the requested namespace?
     /// - Does the cluster have enough resources available to run this stack?
+    ///
+    /// If the stack doesn't specify resource requests, they are estimated from
+    /// the rendered manifests, unless the estimate is skipped in the
+    /// `install_parameters`. In that case, the resource validation is skipped.
     #[instrument(skip_all)]
-    pub async fn check_prerequisites(
+    async fn check_prerequisites(
         &self,
         client: &Client,
-        product_namespace: &str,
+        transfer_client: &xfer::Client,
+        install_parameters: &StackInstallParameters,
     ) -> Result<(), Error> {
+        let product_namespace = install_parameters.product_namespace.as_str();
+
         debug!("Checking prerequisites before installing stack");
 
         // Returns an error if the stack doesn't support to be installed in the
@@ -129,11 +362,34 @@ impl StackSpec {
             });
         }
 
+        let resource_requests = match &self.resource_requests {
+            Some(resource_requests) => Some(resource_requests.clone()),
+            None if install_parameters.skip_resource_estimate => {
+                debug!("Stack doesn't specify resource requests, skipping validation");
+                None
+            }
+            None => match self
+                .estimate_resolved_resources(
+                    &install_parameters.parameters,
+                    product_namespace,
+                    Some(client),
+                    transfer_client,
+                )
+                .await
+            {
+                Ok(estimate) => Some(estimate.to_resource_requests()),
+                Err(err) => {
+                    warn!("Failed to estimate resource requests of stack ({err}), skipping validation");
+                    None
+                }
+            },
+        };
+
         // Checks if the available cluster resources are sufficient to deploy
-        // the demo.
-        if let Some(resource_requests) = &self.resource_requests {
+        // the stack.
+        if let Some(resource_requests) = &resource_requests {
             if let Err(err) = resource_requests
-                .validate_cluster_size(client, "stack")
+                .validate_cluster_size(client, "stack", &install_parameters.labels)
                 .await
             {
                 match err {
@@ -150,17 +406,118 @@ impl StackSpec {
         Ok(())
     }
 
+    /// Installs the stack, including the stacks it includes from the list of
+    /// `stacks`. If pruning is enabled, objects of previous installations
+    /// which are not part of the stack anymore are deleted afterwards. Returns
+    /// the pruned objects, or the objects which would be pruned in dry-run
+    /// mode.
     // TODO (Techassi): Can we get rid of the release list and just use the release spec instead
-    #[instrument(skip(self, release_list, client, transfer_client))]
+    #[instrument(skip(self, stacks, release_list, client, transfer_client))]
     pub async fn install(
         &self,
+        stacks: &IndexMap<String, StackSpec>,
         release_list: release::ReleaseList,
         install_parameters: StackInstallParameters,
         client: &Client,
         transfer_client: &xfer::Client,
-    ) -> Result<(), Error> {
+    ) -> Result<Vec<ManagedObject>, Error> {
+        let stack = self.resolve_includes(&install_parameters.stack_name, stacks)?;
+
+        let mut record = stack.install_record(&install_parameters)?;
+        let labels = install_parameters.labels.clone();
+        let prune_mode = install_parameters.prune;
+
+        let rollback = install_parameters
+            .rollback_on_failure
+            .then(Rollback::default);
+
+        let result = stack
+            .install_resolved(
+                release_list,
+                install_parameters,
+                client,
+                transfer_client,
+                rollback.as_ref(),
+            )
+            .await;
+
+        if let (Err(err), Some(rollback)) = (&result, rollback) {
+            warn!("Failed to install stack ({err}), rolling back");
+
+            let failed = rollback.run(client).await;
+            if failed > 0 {
+                warn!("Failed to roll back {failed} change(s), manual cleanup required");
+            }
+        }
+        let installed = result?;
+
+        let pruned = match prune_mode {
+            Some(mode) => {
+                prune::prune_objects(&installed.objects, &record, &labels, true, mode, client)
+                    .await
+                    .context(PruneObjectsSnafu)?
+            }
+            None => Vec::new(),
+        };
+
+        record.manifests = installed.files;
+        record.kinds = prune::resource_kinds(&installed.objects);
+
+        record
+            .write(labels, client)
+            .await
+            .context(WriteInstallRecordSnafu)?;
+
+        Ok(pruned)
+    }
+
+    /// Installs the stack like [`StackSpec::install`], but records all
+    /// changes to the cluster in the provided `rollback`. The caller is
+    /// responsible for running the rollback when the installation fails.
+    /// Returns all objects applied from plain YAML manifests and the
+    /// installed manifest files.
+    #[instrument(skip_all)]
+    pub(crate) async fn install_with_rollback(
+        &self,
+        stacks: &IndexMap<String, StackSpec>,
+        release_list: release::ReleaseList,
+        install_parameters: StackInstallParameters,
+        client: &Client,
+        transfer_client: &xfer::Client,
+        rollback: Option<&Rollback>,
+    ) -> Result<InstalledManifests, Error> {
+        self.resolve_includes(&install_parameters.stack_name, stacks)?
+            .install_resolved(
+                release_list,
+                install_parameters,
+                client,
+                transfer_client,
+                rollback,
+            )
+            .await
+    }
+
+    /// Installs this stack, which needs to have its includes resolved already.
+    async fn install_resolved(
+        &self,
+        release_list: release::ReleaseList,
+        install_parameters: StackInstallParameters,
+        client: &Client,
+        transfer_client: &xfer::Client,
+        rollback: Option<&Rollback>,
+    ) -> Result<InstalledManifests, Error> {
+        // Verify the manifests against the lockfile before anything is
+        // installed. The verified content is installed afterwards.
+        let locked = lockfile::verify_manifests(
+            install_parameters.lockfile.as_ref(),
+            &self.manifests,
+            transfer_client,
+        )
+        .await
+        .context(VerifyLockfileSnafu)?;
+
         // First, we check if the prerequisites are met
-        self.check_prerequisites(client, &install_parameters.product_namespace)
+        self.check_prerequisites(client, transfer_client, &install_parameters)
             .await?;
 
         // Second, we install the release if not opted out
@@ -176,6 +533,7 @@ impl StackSpec {
                 &install_parameters.operator_namespace,
                 &install_parameters.product_namespace,
                 &install_parameters.chart_source,
+                install_parameters.lockfile.as_ref(),
             )
             .await?;
         }
@@ -188,17 +546,26 @@ impl StackSpec {
             })?;
 
         // Finally install the stack manifests
-        self.prepare_manifests(install_parameters, client, transfer_client)
-            .await
+        self.prepare_manifests(
+            install_parameters,
+            client,
+            transfer_client,
+            rollback,
+            locked.as_ref(),
+        )
+        .await
     }
 
-    #[instrument(skip(self, release_list))]
+    /// Installs the operators of the release used by the stack. If a
+    /// `lockfile` is provided, the operator versions are verified against it.
+    #[instrument(skip(self, release_list, lockfile))]
     pub async fn install_release(
         &self,
         release_list: release::ReleaseList,
         operator_namespace: &str,
         product_namespace: &str,
         chart_source: &ChartSourceType,
+        lockfile: Option<&Lockfile>,
     ) -> Result<(), Error> {
         info!("Trying to install release {}", self.release);
 
@@ -209,6 +576,12 @@ impl StackSpec {
                 name: self.release.clone(),
             })?;
 
+        if let Some(lockfile) = lockfile {
+            lockfile
+                .verify_operators(&release.filter_products(&self.operators, &[]))
+                .context(VerifyLockfileSnafu)?;
+        }
+
         // Install the release
         release
             .install(&self.operators, &[], operator_namespace, chart_source)
@@ -222,7 +595,9 @@ impl StackSpec {
         install_params: StackInstallParameters,
         client: &Client,
         transfer_client: &xfer::Client,
-    ) -> Result<(), Error> {
+        rollback: Option<&Rollback>,
+        locked: Option<&LockedManifests>,
+    ) -> Result<InstalledManifests, Error> {
         info!("Installing stack manifests");
 
         let parameters = install_params
@@ -238,13 +613,311 @@ impl StackSpec {
             install_params.labels,
             client,
             transfer_client,
+            rollback,
+            locked,
         )
         .await
         .context(InstallManifestsSnafu)
     }
 
+    /// Compares the stack manifests, including the manifests of the stacks it
+    /// includes from the list of `stacks`, against the live objects in the
+    /// cluster without changing anything. The release is not taken into
+    /// account.
+    #[instrument(skip_all)]
+    pub async fn diff(
+        &self,
+        stacks: &IndexMap<String, StackSpec>,
+        diff_params: StackDiffParameters,
+        client: &Client,
+        transfer_client: &xfer::Client,
+    ) -> Result<Vec<ObjectDiff>, Error> {
+        info!("Diffing stack manifests");
+
+        let stack = self.resolve_includes(&diff_params.stack_name, stacks)?;
+        let locked = lockfile::verify_manifests(
+            diff_params.lockfile.as_ref(),
+            &stack.manifests,
+            transfer_client,
+        )
+        .await
+        .context(VerifyLockfileSnafu)?;
+
+        let parameters = diff_params
+            .parameters
+            .into_params(&stack.parameters)
+            .context(ParseParametersSnafu)?;
+
+        Self::diff_manifests(
+            &stack.manifests,
+            &parameters,
+            &diff_params.product_namespace,
+            diff_params.labels,
+            client,
+            transfer_client,
+            locked.as_ref(),
+        )
+        .await
+        .context(DiffManifestsSnafu)
+    }
+
+    /// Renders the stack manifests (including Helm charts), as well as the
+    /// manifests of the stacks it includes from the list of `stacks`, and
+    /// estimates the resources requested by the contained workloads. Nothing
+    /// is installed. Without a `client`, the manifests are rendered offline.
+    #[instrument(skip_all)]
+    pub async fn estimate_resources(
+        &self,
+        stack_name: &str,
+        stacks: &IndexMap<String, StackSpec>,
+        parameters: &[String],
+        product_namespace: &str,
+        client: Option<&Client>,
+        transfer_client: &xfer::Client,
+    ) -> Result<ResourceEstimate, Error> {
+        self.resolve_includes(stack_name, stacks)?
+            .estimate_resolved_resources(parameters, product_namespace, client, transfer_client)
+            .await
+    }
+
+    /// Estimates the resources of this stack, which needs to have its
+    /// includes resolved already.
+    async fn estimate_resolved_resources(
+        &self,
+        parameters: &[String],
+        product_namespace: &str,
+        client: Option<&Client>,
+        transfer_client: &xfer::Client,
+    ) -> Result<ResourceEstimate, Error> {
+        let parameters = parameters
+            .to_owned()
+            .into_params(&self.parameters)
+            .context(ParseParametersSnafu)?;
+
+        Self::estimate_manifest_resources(
+            &self.manifests,
+            &parameters,
+            product_namespace,
+            client,
+            transfer_client,
+        )
+        .await
+        .context(EstimateResourcesSnafu)
+    }
+
+    /// Builds the install record which is persisted after the stack was
+    /// installed successfully.
+    fn install_record(
+        &self,
+        install_params: &StackInstallParameters,
+    ) -> Result<InstallRecord, Error> {
+        let parameters = install_params
+            .parameters
+            .to_owned()
+            .into_params(&self.parameters)
+            .context(ParseParametersSnafu)?;
+
+        let mut record = InstallRecord::new(
+            InstallRecordKind::Stack,
+            &install_params.stack_name,
+            &self.release,
+            &install_params.operator_namespace,
+            &install_params.product_namespace,
+        );
+
+        record.parameters = install_record::public_parameters(&parameters, &self.parameters);
+        record.sources = install_params.spec_sources.clone();
+
+        if let Some(lockfile) = &install_params.lockfile {
+            lockfile
+                .verify_target(InstallRecordKind::Stack, &install_params.stack_name)
+                .and_then(|_| lockfile.verify_sources(&record.sources))
+                .context(VerifyLockfileSnafu)?;
+        }
+
+        Ok(record)
+    }
+
     fn supports_namespace(&self, namespace: impl Into<String>) -> bool {
         self.supported_namespaces.is_empty()
             || self.supported_namespaces.contains(&namespace.into())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use stackable_operator::cpu::CpuQuantity;
+
+    use super::*;
+    use crate::platform::cluster::parse_bytes;
+
+    const GIBI: f64 = 1024.0 * 1024.0 * 1024.0;
+
+    /// Deserializes the stacks the same way stack lists are deserialized.
+    fn stacks(input: &str) -> IndexMap<String, StackSpec> {
+        serde_yaml::with::singleton_map_recursive::deserialize(serde_yaml::Deserializer::from_str(
+            input,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn resolve_includes() {
+        let stacks = stacks(
+            "monitoring:
+  description: Monitoring
+  stackableRelease: \"24.11\"
+  stackableOperators: [commons]
+  manifests:
+    - plainYaml: prometheus.yaml
+logging:
+  description: Logging
+  stackableRelease: \"24.11\"
+  stackableOperators: [commons]
+  includes: [monitoring]
+  manifests:
+    - plainYaml: vector.yaml
+trino:
+  description: Trino
+  stackableRelease: \"24.11\"
+  stackableOperators: [commons, trino]
+  includes: [logging, monitoring]
+  manifests:
+    - plainYaml: trino.yaml",
+        );
+
+        let resolved = stacks["trino"].resolve_includes("trino", &stacks).unwrap();
+        let manifests: Vec<_> = resolved
+            .manifests
+            .iter()
+            .map(|manifest| manifest.source.to_string())
+            .collect();
+
+        assert_eq!(
+            manifests,
+            [
+                "YAML manifest prometheus.yaml",
+                "YAML manifest vector.yaml",
+                "YAML manifest trino.yaml"
+            ]
+        );
+        assert_eq!(resolved.operators, ["commons", "trino"]);
+        assert!(resolved.includes.is_empty());
+    }
+
+    #[test]
+    fn resolve_included_requests_and_namespaces() {
+        let stacks = stacks(
+            "monitoring:
+  description: Monitoring
+  stackableRelease: \"24.11\"
+  stackableOperators: []
+  supportedNamespaces: [default, monitoring]
+  resourceRequests:
+    cpu: 500m
+    memory: 1Gi
+    pvc: 10Gi
+    largestPod:
+      cpu: 500m
+      memory: 1Gi
+logging:
+  description: Logging
+  stackableRelease: \"24.11\"
+  stackableOperators: []
+  manifests: []
+trino:
+  description: Trino
+  stackableRelease: \"24.11\"
+  stackableOperators: []
+  includes: [monitoring]
+  supportedNamespaces: [default]
+  resourceRequests:
+    cpu: '2'
+    memory: 4Gi
+    pvc: 5Gi
+    storageClass: fast
+    largestPod:
+      cpu: '1'
+      memory: 2Gi
+trino-logging:
+  description: Trino with logging
+  stackableRelease: \"24.11\"
+  stackableOperators: []
+  includes: [trino, logging]
+airflow:
+  description: Airflow
+  stackableRelease: \"24.11\"
+  stackableOperators: []
+  includes: [monitoring]
+  supportedNamespaces: [airflow]",
+        );
+
+        let resolved = stacks["trino"].resolve_includes("trino", &stacks).unwrap();
+        let requests = resolved.resource_requests.unwrap();
+        assert_eq!(
+            CpuQuantity::try_from(&requests.cpu)
+                .unwrap()
+                .as_milli_cpus(),
+            2500
+        );
+        assert_eq!(parse_bytes(&requests.memory), Some(5.0 * GIBI));
+        assert_eq!(parse_bytes(&requests.pvc), Some(15.0 * GIBI));
+        assert_eq!(requests.storage_class.as_deref(), Some("fast"));
+        assert_eq!(requests.largest_pod.unwrap().memory.0, "2Gi");
+        assert_eq!(resolved.supported_namespaces, ["default"]);
+
+        // The resources of the logging stack are unknown, so they need to be
+        // estimated
+        let resolved = stacks["trino-logging"]
+            .resolve_includes("trino-logging", &stacks)
+            .unwrap();
+        assert!(resolved.resource_requests.is_none());
+        assert_eq!(resolved.supported_namespaces, ["default"]);
+
+        assert!(matches!(
+            stacks["airflow"].resolve_includes("airflow", &stacks),
+            Err(Error::NoCommonNamespace { stack_name }) if stack_name == "airflow"
+        ));
+    }
+
+    #[test]
+    fn resolve_include_errors() {
+        let stacks = stacks(
+            "a:
+  description: A
+  stackableRelease: \"24.11\"
+  stackableOperators: []
+  includes: [b]
+b:
+  description: B
+  stackableRelease: \"24.11\"
+  stackableOperators: []
+  includes: [a]
+c:
+  description: C
+  stackableRelease: \"24.11\"
+  stackableOperators: []
+  includes: [d]
+e:
+  description: E
+  stackableRelease: \"24.7\"
+  stackableOperators: []
+  includes: [c]",
+        );
+
+        assert!(matches!(
+            stacks["a"].resolve_includes("a", &stacks),
+            Err(Error::IncludeCycle { cycle }) if cycle == ["a", "b", "a"]
+        ));
+        assert!(matches!(
+            stacks["c"].resolve_includes("c", &stacks),
+            Err(Error::NoSuchIncludedStack { name, .. }) if name == "d"
+        ));
+        assert!(matches!(
+            stacks["e"].resolve_includes("e", &IndexMap::from([
+                ("c".to_string(), StackSpec { includes: Vec::new(), ..stacks["c"].clone() }),
+            ])),
+            Err(Error::IncludedReleaseMismatch { name, .. }) if name == "c"
+        ));
+    }
+}
@@ -1,14 +1,27 @@
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use url::Url;
 
-#[derive(Clone, Debug, Deserialize)]
+use crate::constants::CACHE_CHART_SOURCE_FRAGMENT;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChartSourceMetadata {
     pub entries: HashMap<String, Vec<ChartSourceEntry>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChartSourceEntry {
     pub name: String,
     pub version: String,
 }
+
+impl ChartSourceMetadata {
+    /// Returns the key used to cache the metadata assembled from the index
+    /// located at `index_url`.
+    pub(crate) fn cache_key(index_url: &Url) -> Url {
+        let mut key = index_url.clone();
+        key.set_fragment(Some(CACHE_CHART_SOURCE_FRAGMENT));
+        key
+    }
+}
@@ -1,23 +1,43 @@
-use std::{collections::BTreeMap, string::FromUtf8Error};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Debug,
+    string::FromUtf8Error,
+    sync::Arc,
+    time::Duration,
+};
 
-use k8s_openapi::api::{
-    apps::v1::{Deployment, StatefulSet},
-    core::v1::{Endpoints, Namespace, Node, Secret, Service},
+use k8s_openapi::{
+    api::{
+        apps::v1::{Deployment, StatefulSet},
+        batch::v1::Job,
+        core::v1::{ConfigMap, Endpoints, Namespace, Node, Pod, Secret, Service},
+        storage::v1::{CSIStorageCapacity, StorageClass},
+    },
+    apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition,
+    apimachinery::pkg::version::Info,
+    ByteString,
 };
 use kube::{
-    api::{ListParams, Patch, PatchParams, PostParams},
+    api::{DeleteParams, ListParams, Patch, PatchParams, PostParams, Preconditions},
     core::{DynamicObject, GroupVersionKind, ObjectList, ObjectMeta, TypeMeta},
-    discovery::{ApiCapabilities, ApiResource, Scope},
-    Api, Discovery, ResourceExt,
+    discovery::{verbs, ApiCapabilities, ApiResource, Scope},
+    runtime::wait::{await_condition, conditions, Condition},
+    Api, Discovery, Resource, ResourceExt,
 };
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 use snafu::{OptionExt, ResultExt, Snafu};
 use stackable_operator::{commons::listener::Listener, kvp::Labels};
-use tokio::sync::RwLock;
-use tracing::info;
+use tokio::{sync::RwLock, time::error::Elapsed};
+use tracing::{info, warn};
 
 use crate::{
-    platform::{cluster, credentials::Credentials},
+    platform::{
+        cluster,
+        credentials::Credentials,
+        diff::DryRunObject,
+        prune::{ManagedObject, ResourceKind},
+        rollback::{Rollback, RollbackStep},
+    },
     utils::k8s::ByteStringExt,
 };
 
@@ -38,6 +58,9 @@ pub enum Error {
     #[snafu(display("failed to patch/create Kubernetes object"))]
     KubeClientPatch { source: kube::error::Error },
 
+    #[snafu(display("failed to delete Kubernetes object"))]
+    KubeClientDelete { source: kube::error::Error },
+
     #[snafu(display("failed to deserialize YAML data"))]
     DeserializeYaml { source: serde_yaml::Error },
 
@@ -60,6 +83,9 @@ pub enum Error {
     #[snafu(display("failed to convert byte string into UTF-8 string"))]
     ByteStringConvert { source: FromUtf8Error },
 
+    #[snafu(display("failed to build request to Kubernetes API"))]
+    BuildRequest { source: http::Error },
+
     #[snafu(display("missing namespace for service '{service}'"))]
     MissingServiceNamespace { service: String },
 
@@ -74,13 +100,40 @@ pub enum Error {
 
     #[snafu(display("no password key in credentials secret '{secret_name}'"))]
     NoPasswordKey { secret_name: String },
+
+    #[snafu(display("failed to wait for condition of object {name:?}"))]
+    WaitForCondition {
+        source: kube::runtime::wait::Error,
+        name: String,
+    },
+
+    #[snafu(display("timed out after {timeout:?} waiting for condition of object {name:?}"))]
+    WaitTimeout {
+        source: Elapsed,
+        timeout: Duration,
+        name: String,
+    },
+}
+
+impl Error {
+    /// Returns if the request was rejected, because the user lacks the
+    /// required permissions.
+    pub fn is_forbidden(&self) -> bool {
+        matches!(
+            self,
+            Error::KubeClientFetch {
+                source: kube::Error::Api(err),
+            } if err.code == 403
+        )
+    }
 }
 
+#[derive(Clone)]
 pub struct Client {
     client: kube::Client,
 
     // Choosing an [`RwLock`] here, as their can be many reads in parallel, but running a discovery is very rare
-    discovery: RwLock<Discovery>,
+    discovery: Arc<RwLock<Discovery>>,
 }
 
 impl Client {
@@ -91,62 +144,268 @@ impl Client {
             .await
             .context(KubeClientCreateSnafu)?;
 
-        let discovery = RwLock::new(Self::run_discovery(client.clone()).await?);
+        let discovery = Arc::new(RwLock::new(Self::run_discovery(client.clone()).await?));
 
         Ok(Self { client, discovery })
     }
 
     /// Deploys manifests defined the in raw `manifests` YAML string. This
     /// method will fail if it is unable to parse the manifests, unable to
-    /// resolve GVKs or unable to patch the dynamic objects.
+    /// resolve GVKs or unable to patch the dynamic objects. If a `rollback`
+    /// is provided, every object created by this method is recorded, so that
+    /// it can be deleted again later on. Returns all applied objects.
     pub async fn deploy_manifests(
         &self,
         manifests: &str,
         namespace: &str,
         labels: Labels,
-    ) -> Result<()> {
+        rollback: Option<&Rollback>,
+    ) -> Result<Vec<ManagedObject>> {
         // TODO (Techassi): Impl IntoIterator for Labels
         let labels: BTreeMap<String, String> = labels.into();
+        let mut applied = Vec::new();
 
         for manifest in serde_yaml::Deserializer::from_str(manifests) {
             let mut object = DynamicObject::deserialize(manifest).context(DeserializeYamlSnafu)?;
+            let (api, resource, object_namespace) =
+                self.prepare_object(&mut object, namespace, &labels).await?;
+
+            let name = object.name_any();
+
+            // Only objects which don't exist yet are recorded, as updates to
+            // already existing objects cannot be undone.
+            let created = match rollback {
+                Some(_) => api
+                    .get_opt(&name)
+                    .await
+                    .context(KubeClientFetchSnafu)?
+                    .is_none(),
+                None => false,
+            };
 
-            // Add our own labels to the object
-            object.labels_mut().extend(labels.clone());
+            api.patch(
+                &name,
+                &PatchParams::apply("stackablectl"),
+                &Patch::Apply(object),
+            )
+            .await
+            .context(KubeClientPatchSnafu)?;
 
-            let object_type = object.types.as_ref().ok_or(
-                ObjectTypeSnafu {
-                    object: object.clone(),
-                }
-                .build(),
-            )?;
+            applied.push(ManagedObject {
+                group: resource.group.clone(),
+                kind: resource.kind.clone(),
+                namespace: object_namespace.clone(),
+                name: name.clone(),
+            });
+
+            if let (Some(rollback), true) = (rollback, created) {
+                rollback.record(RollbackStep::DeleteObject {
+                    namespace: object_namespace,
+                    resource,
+                    name,
+                });
+            }
+        }
+
+        Ok(applied)
+    }
+
+    /// Runs a server-side dry-run apply of the manifests defined in the raw
+    /// `manifests` YAML string, without changing anything in the cluster.
+    /// Returns the current state of each object, if it exists, together with
+    /// the state it would have after applying the manifests. If `force` is
+    /// set, fields managed by others (e.g. Helm) are taken over instead of
+    /// failing with a conflict.
+    pub async fn dry_run_manifests(
+        &self,
+        manifests: &str,
+        namespace: &str,
+        labels: Labels,
+        force: bool,
+    ) -> Result<Vec<DryRunObject>> {
+        let labels: BTreeMap<String, String> = labels.into();
+        let mut patch_params = PatchParams::apply("stackablectl").dry_run();
+        if force {
+            patch_params = patch_params.force();
+        }
 
-            let gvk = Self::gvk_of_typemeta(object_type);
-            let (resource, capabilities) = self
-                .resolve_gvk(&gvk)
-                .await?
-                .context(GVKUnkownSnafu { gvk })?;
+        let mut objects = Vec::new();
 
+        for manifest in serde_yaml::Deserializer::from_str(manifests) {
+            // Rendered Helm charts can contain empty documents
+            let Some(mut object) =
+                Option::<DynamicObject>::deserialize(manifest).context(DeserializeYamlSnafu)?
+            else {
+                continue;
+            };
+            let (api, resource, object_namespace) =
+                self.prepare_object(&mut object, namespace, &labels).await?;
+
+            let name = object.name_any();
+            let live = api.get_opt(&name).await.context(KubeClientFetchSnafu)?;
+
+            let dry_run = match api
+                .patch(&name, &patch_params, &Patch::Apply(&object))
+                .await
+            {
+                Ok(dry_run) => dry_run,
+                // New objects in a namespace which doesn't exist yet cannot be
+                // applied, not even in dry-run mode. Use the local object
+                // instead.
+                Err(kube::Error::Api(err)) if err.code == 404 && live.is_none() => object,
+                Err(err) => return Err(err).context(KubeClientPatchSnafu),
+            };
+
+            objects.push(DryRunObject {
+                object: ManagedObject {
+                    group: resource.group,
+                    kind: resource.kind,
+                    namespace: object_namespace,
+                    name,
+                },
+                live,
+                dry_run,
+            });
+        }
+
+        Ok(objects)
+    }
+
+    /// Prepares an `object` parsed from a manifest before applying it. Our
+    /// own `labels` are added and the API of the object is resolved. Returns
+    /// the API, the API resource and the namespace of the object, which is
+    /// [`None`] for cluster-scoped objects.
+    async fn prepare_object(
+        &self,
+        object: &mut DynamicObject,
+        namespace: &str,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<(Api<DynamicObject>, ApiResource, Option<String>)> {
+        // Add our own labels to the object
+        object.labels_mut().extend(labels.clone());
+
+        let object_type = object.types.as_ref().ok_or(
+            ObjectTypeSnafu {
+                object: object.clone(),
+            }
+            .build(),
+        )?;
+
+        let gvk = Self::gvk_of_typemeta(object_type);
+        let (resource, capabilities) = self
+            .resolve_gvk(&gvk)
+            .await?
+            .context(GVKUnkownSnafu { gvk })?;
+
+        Ok(match capabilities.scope {
+            Scope::Cluster => {
+                object.metadata.namespace = None;
+                (
+                    Api::all_with(self.client.clone(), &resource),
+                    resource,
+                    None,
+                )
+            }
+            Scope::Namespaced => (
+                Api::namespaced_with(self.client.clone(), namespace, &resource),
+                resource,
+                Some(namespace.to_string()),
+            ),
+        })
+    }
+
+    /// Deletes the object identified by `name` using the provided API
+    /// `resource`. Cluster-scoped objects are deleted when no `namespace` is
+    /// provided. Objects which are already gone are silently skipped.
+    pub async fn delete_object(
+        &self,
+        resource: &ApiResource,
+        namespace: Option<&str>,
+        name: &str,
+    ) -> Result<()> {
+        let api: Api<DynamicObject> = match namespace {
+            Some(namespace) => Api::namespaced_with(self.client.clone(), namespace, resource),
+            None => Api::all_with(self.client.clone(), resource),
+        };
+
+        match api.delete(name, &DeleteParams::background()).await {
+            Ok(_) => Ok(()),
+            Err(kube::Error::Api(err)) if err.code == 404 => Ok(()),
+            Err(err) => Err(err).context(KubeClientDeleteSnafu),
+        }
+    }
+
+    /// Lists objects of the provided `kinds` which match the `list_params`.
+    /// Only resource types known to the discovery which support being listed
+    /// and deleted are considered. Namespaced objects are only listed in the
+    /// provided `namespace`. Objects are returned together with the API
+    /// resource they were listed with. Resource types which the user is not
+    /// permitted to list are skipped.
+    pub async fn list_labeled_objects(
+        &self,
+        namespace: &str,
+        kinds: &HashSet<ResourceKind>,
+        list_params: &ListParams,
+    ) -> Result<Vec<(ApiResource, DynamicObject)>> {
+        // Collect the resources first to not hold the discovery lock while
+        // listing objects
+        let resources: Vec<(ApiResource, ApiCapabilities)> = self
+            .discovery
+            .read()
+            .await
+            .groups()
+            .flat_map(|group| group.recommended_resources())
+            .filter(|(resource, capabilities)| {
+                kinds.contains(&ResourceKind {
+                    group: resource.group.clone(),
+                    kind: resource.kind.clone(),
+                }) && capabilities.supports_operation(verbs::LIST)
+                    && capabilities.supports_operation(verbs::DELETE)
+            })
+            .collect();
+
+        let mut seen_uids = HashSet::new();
+        let mut objects = Vec::new();
+
+        for (resource, capabilities) in resources {
             let api: Api<DynamicObject> = match capabilities.scope {
-                Scope::Cluster => {
-                    object.metadata.namespace = None;
-                    Api::all_with(self.client.clone(), &resource)
-                }
+                Scope::Cluster => Api::all_with(self.client.clone(), &resource),
                 Scope::Namespaced => {
                     Api::namespaced_with(self.client.clone(), namespace, &resource)
                 }
             };
 
-            api.patch(
-                &object.name_any(),
-                &PatchParams::apply("stackablectl"),
-                &Patch::Apply(object),
-            )
-            .await
-            .context(KubeClientPatchSnafu)?;
+            let list = match api.list(list_params).await {
+                Ok(list) => list,
+                // The resource type might be gone since the discovery ran
+                Err(kube::Error::Api(err)) if err.code == 404 => continue,
+                // Users with limited permissions are usually not allowed to
+                // list every resource type, which must not fail the whole
+                // operation. Objects of these types are left untouched.
+                Err(kube::Error::Api(err)) if err.code == 403 || err.code == 405 => {
+                    warn!(
+                        "Skipping {} objects, listing them is not permitted ({})",
+                        resource.kind, err.message
+                    );
+                    continue;
+                }
+                Err(err) => return Err(err).context(KubeClientFetchSnafu),
+            };
+
+            // The same object can be served by multiple API groups, e.g.
+            // Events, so duplicates are skipped based on their UID
+            for object in list {
+                if let Some(uid) = object.uid() {
+                    if !seen_uids.insert(uid) {
+                        continue;
+                    }
+                }
+
+                objects.push((resource.clone(), object));
+            }
         }
 
-        Ok(())
+        Ok(objects)
     }
 
     /// Lists objects by looking up a GVK via the discovery. It returns an
@@ -243,6 +502,27 @@ impl Client {
         Ok(listeners)
     }
 
+    /// Lists all [`StorageClass`]es of the cluster.
+    pub async fn list_storage_classes(&self) -> ListResult<StorageClass> {
+        let storage_class_api: Api<StorageClass> = Api::all(self.client.clone());
+
+        storage_class_api
+            .list(&ListParams::default())
+            .await
+            .context(KubeClientFetchSnafu)
+    }
+
+    /// Lists all [`CSIStorageCapacity`] objects in all namespaces. CSI
+    /// drivers use them to publish the capacity of their storage.
+    pub async fn list_csi_storage_capacities(&self) -> ListResult<CSIStorageCapacity> {
+        let capacity_api: Api<CSIStorageCapacity> = Api::all(self.client.clone());
+
+        capacity_api
+            .list(&ListParams::default())
+            .await
+            .context(KubeClientFetchSnafu)
+    }
+
     /// Retrieves user credentials consisting of username and password from a
     /// secret identified by `secret_name` inside the `secret_namespace`. If
     /// either one of the values is missing, [`Ok(None)`] is returned. An error
@@ -283,6 +563,146 @@ impl Client {
         Ok(Credentials { username, password })
     }
 
+    /// Retrieves the value of `key` from the secret identified by `name`
+    /// inside the `namespace`. If either the secret or the key doesn't exist,
+    /// [`Ok(None)`] is returned.
+    pub async fn get_secret_value(
+        &self,
+        namespace: &str,
+        name: &str,
+        key: &str,
+    ) -> Result<Option<String>> {
+        let secret_api: Api<Secret> = Api::namespaced(self.client.clone(), namespace);
+
+        let secret = secret_api
+            .get_opt(name)
+            .await
+            .context(KubeClientFetchSnafu)?;
+
+        secret
+            .and_then(|secret| secret.data)
+            .and_then(|mut data| data.remove(key))
+            .map(|value| value.try_to_string().context(ByteStringConvertSnafu))
+            .transpose()
+    }
+
+    /// Stores the `value` of `key` in the secret identified by `name` inside
+    /// the `namespace`, unless the key already exists. The secret is created
+    /// with the `labels` if it doesn't exist yet. Other keys of an existing
+    /// secret are left untouched. Concurrent changes to the secret are
+    /// detected, so that a value stored in the meantime is never overwritten.
+    /// Returns the value which is stored in the secret afterwards and whether
+    /// it was inserted by this call.
+    pub async fn insert_secret_value(
+        &self,
+        namespace: &str,
+        name: &str,
+        key: &str,
+        value: &str,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<(String, bool)> {
+        let secret_api: Api<Secret> = Api::namespaced(self.client.clone(), namespace);
+
+        loop {
+            let result = match secret_api
+                .get_opt(name)
+                .await
+                .context(KubeClientFetchSnafu)?
+            {
+                Some(mut secret) => {
+                    let data = secret.data.get_or_insert_with(BTreeMap::new);
+                    if let Some(existing) = data.get(key) {
+                        let existing = existing.try_to_string().context(ByteStringConvertSnafu)?;
+                        return Ok((existing, false));
+                    }
+
+                    data.insert(key.to_string(), ByteString(value.as_bytes().to_vec()));
+
+                    // The replace fails with a conflict if the secret changed
+                    // since it was retrieved, as it contains the resource
+                    // version
+                    secret_api
+                        .replace(name, &PostParams::default(), &secret)
+                        .await
+                }
+                None => {
+                    let secret = Secret {
+                        metadata: ObjectMeta {
+                            name: Some(name.to_string()),
+                            namespace: Some(namespace.to_string()),
+                            labels: Some(labels.clone()),
+                            ..Default::default()
+                        },
+                        data: Some(BTreeMap::from([(
+                            key.to_string(),
+                            ByteString(value.as_bytes().to_vec()),
+                        )])),
+                        ..Default::default()
+                    };
+
+                    secret_api.create(&PostParams::default(), &secret).await
+                }
+            };
+
+            match result {
+                Ok(_) => return Ok((value.to_string(), true)),
+                // The secret was created or changed in the meantime, so the
+                // value stored by someone else needs to be looked up
+                Err(kube::Error::Api(err)) if err.code == 409 => continue,
+                Err(err) => return Err(err).context(KubeClientPatchSnafu),
+            }
+        }
+    }
+
+    /// Removes `key` from the secret identified by `name` inside the
+    /// `namespace`. The secret is deleted if no other keys are left. Like
+    /// [`Client::insert_secret_value`], concurrent changes to the secret are
+    /// detected, so that values stored in the meantime are never removed.
+    pub async fn remove_secret_value(&self, namespace: &str, name: &str, key: &str) -> Result<()> {
+        let secret_api: Api<Secret> = Api::namespaced(self.client.clone(), namespace);
+
+        loop {
+            let Some(mut secret) = secret_api
+                .get_opt(name)
+                .await
+                .context(KubeClientFetchSnafu)?
+            else {
+                return Ok(());
+            };
+
+            let data = secret.data.get_or_insert_with(BTreeMap::new);
+            if data.remove(key).is_none() {
+                return Ok(());
+            }
+
+            let result = if data.is_empty() {
+                // The delete fails with a conflict if the secret changed since
+                // it was retrieved
+                let delete_params = DeleteParams {
+                    preconditions: Some(Preconditions {
+                        resource_version: secret.metadata.resource_version.clone(),
+                        uid: secret.metadata.uid.clone(),
+                    }),
+                    ..DeleteParams::default()
+                };
+
+                secret_api.delete(name, &delete_params).await.map(|_| ())
+            } else {
+                secret_api
+                    .replace(name, &PostParams::default(), &secret)
+                    .await
+                    .map(|_| ())
+            };
+
+            match result {
+                Ok(()) => return Ok(()),
+                Err(kube::Error::Api(err)) if err.code == 404 => return Ok(()),
+                Err(kube::Error::Api(err)) if err.code == 409 => continue,
+                Err(err) => return Err(err).context(KubeClientPatchSnafu),
+            }
+        }
+    }
+
     /// Lists [`Deployment`]s by matching labels. The services can be matched
     /// by the app labels. [`ListParamsExt`] provides a utility function to
     /// create [`ListParams`] based on a app name and other labels.
@@ -380,13 +800,139 @@ impl Client {
         Ok(())
     }
 
+    /// Lists all [`Pod`]s in all namespaces which are not terminated, i.e.
+    /// which are still requesting resources on their nodes.
+    pub async fn list_active_pods(&self) -> ListResult<Pod> {
+        let pod_api: Api<Pod> = Api::all(self.client.clone());
+        let list_params =
+            ListParams::default().fields("status.phase!=Succeeded,status.phase!=Failed");
+
+        pod_api
+            .list(&list_params)
+            .await
+            .context(KubeClientFetchSnafu)
+    }
+
     /// Retrieves [`ClusterInfo`][cluster::ClusterInfo] which contains resource
-    /// information for the current cluster. It should be noted that
-    /// [`ClusterInfo`][cluster::ClusterInfo] contains data about allocatable
-    /// resources. These values don't reflect currently available resources.
-    pub async fn get_cluster_info(&self) -> Result<cluster::ClusterInfo> {
+    /// information for the current cluster. Besides the allocatable resources,
+    /// [`ClusterInfo`][cluster::ClusterInfo] contains the free resources,
+    /// which take the requests of all active pods into account. Pods carrying
+    /// all `excluded_labels` are ignored. If listing the pods of all
+    /// namespaces is not permitted, the free resources equal the allocatable
+    /// resources.
+    pub async fn get_cluster_info(&self, excluded_labels: &Labels) -> Result<cluster::ClusterInfo> {
         let nodes = self.list_nodes().await?;
-        cluster::ClusterInfo::from_nodes(nodes).context(ClusterInformationSnafu)
+        let pods = match self.list_active_pods().await {
+            Ok(pods) => pods,
+            Err(err) if err.is_forbidden() => {
+                warn!(
+                    "Ignoring the resources requested by running pods, listing them is not permitted"
+                );
+                ObjectList {
+                    metadata: Default::default(),
+                    items: Vec::new(),
+                    types: Default::default(),
+                }
+            }
+            Err(err) => return Err(err),
+        };
+
+        let excluded_labels: BTreeMap<String, String> = excluded_labels.clone().into();
+        cluster::ClusterInfo::from_nodes_and_pods(nodes, pods, &excluded_labels)
+            .context(ClusterInformationSnafu)
+    }
+
+    /// Returns the version information of the Kubernetes API server.
+    pub async fn get_server_version(&self) -> Result<Info> {
+        self.client
+            .apiserver_version()
+            .await
+            .context(KubeClientFetchSnafu)
+    }
+
+    /// Returns if the API `group` is served by the cluster. This uses the
+    /// results of the last discovery run.
+    pub async fn has_api_group(&self, group: &str) -> bool {
+        self.discovery.read().await.has_group(group)
+    }
+
+    /// Returns the cluster domain configured in the kubelet of the first
+    /// node. The kubelet configuration is retrieved using the node proxy of
+    /// the API server, which requires the `nodes/proxy` permission. Returns
+    /// [`None`] if the cluster has no nodes.
+    pub async fn get_cluster_domain(&self) -> Result<Option<String>> {
+        #[derive(Deserialize)]
+        struct KubeletConfigz {
+            kubeletconfig: KubeletConfig,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct KubeletConfig {
+            cluster_domain: Option<String>,
+        }
+
+        let nodes = self.list_nodes().await?;
+        let Some(node) = nodes.items.first() else {
+            return Ok(None);
+        };
+
+        let request =
+            http::Request::get(format!("/api/v1/nodes/{}/proxy/configz", node.name_any()))
+                .body(Vec::new())
+                .context(BuildRequestSnafu)?;
+
+        let configz: KubeletConfigz = self
+            .client
+            .request(request)
+            .await
+            .context(KubeClientFetchSnafu)?;
+
+        Ok(configz.kubeletconfig.cluster_domain)
+    }
+
+    /// Returns a [`ConfigMap`] identified by `name` in `namespace`. If the
+    /// ConfigMap doesn't exist, this method returns [`None`].
+    pub async fn get_config_map(&self, namespace: &str, name: &str) -> Result<Option<ConfigMap>> {
+        let config_map_api: Api<ConfigMap> = Api::namespaced(self.client.clone(), namespace);
+        config_map_api
+            .get_opt(name)
+            .await
+            .context(KubeClientFetchSnafu)
+    }
+
+    /// Lists [`ConfigMap`]s by matching labels.
+    pub async fn list_config_maps(
+        &self,
+        namespace: Option<&str>,
+        list_params: &ListParams,
+    ) -> ListResult<ConfigMap> {
+        let config_map_api: Api<ConfigMap> = match namespace {
+            Some(namespace) => Api::namespaced(self.client.clone(), namespace),
+            None => Api::all(self.client.clone()),
+        };
+
+        config_map_api
+            .list(list_params)
+            .await
+            .context(KubeClientFetchSnafu)
+    }
+
+    /// Creates or updates the [`ConfigMap`] in `namespace` using server-side
+    /// apply.
+    pub async fn apply_config_map(&self, namespace: &str, config_map: ConfigMap) -> Result<()> {
+        let config_map_api: Api<ConfigMap> = Api::namespaced(self.client.clone(), namespace);
+
+        config_map_api
+            .patch(
+                &config_map.name_any(),
+                &PatchParams::apply("stackablectl"),
+                &Patch::Apply(config_map),
+            )
+            .await
+            .context(KubeClientPatchSnafu)?;
+
+        Ok(())
     }
 
     pub async fn get_endpoints(&self, namespace: &str, name: &str) -> Result<Endpoints> {
@@ -394,6 +940,71 @@ impl Client {
         endpoints_api.get(name).await.context(KubeClientFetchSnafu)
     }
 
+    /// Waits until all replicas of the [`Deployment`] identified by `name` in
+    /// `namespace` are ready. An error is returned if the Deployment doesn't
+    /// become ready within `timeout`.
+    pub async fn wait_for_deployment_ready(
+        &self,
+        namespace: &str,
+        name: &str,
+        timeout: Duration,
+    ) -> Result<()> {
+        let deployment_api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+        Self::await_object_condition(deployment_api, name, is_deployment_ready(), timeout).await
+    }
+
+    /// Waits until all replicas of the [`StatefulSet`] identified by `name` in
+    /// `namespace` are ready. An error is returned if the StatefulSet doesn't
+    /// become ready within `timeout`.
+    pub async fn wait_for_stateful_set_ready(
+        &self,
+        namespace: &str,
+        name: &str,
+        timeout: Duration,
+    ) -> Result<()> {
+        let stateful_set_api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
+        Self::await_object_condition(stateful_set_api, name, is_stateful_set_ready(), timeout).await
+    }
+
+    /// Waits until the [`Job`] identified by `name` in `namespace` completed.
+    /// An error is returned if the Job doesn't complete within `timeout`.
+    pub async fn wait_for_job_completed(
+        &self,
+        namespace: &str,
+        name: &str,
+        timeout: Duration,
+    ) -> Result<()> {
+        let job_api: Api<Job> = Api::namespaced(self.client.clone(), namespace);
+        Self::await_object_condition(job_api, name, conditions::is_job_completed(), timeout).await
+    }
+
+    /// Waits until the [`CustomResourceDefinition`] identified by `name` is
+    /// established. An error is returned if the CRD isn't established within
+    /// `timeout`.
+    pub async fn wait_for_crd_established(&self, name: &str, timeout: Duration) -> Result<()> {
+        let crd_api: Api<CustomResourceDefinition> = Api::all(self.client.clone());
+        Self::await_object_condition(crd_api, name, conditions::is_crd_established(), timeout).await
+    }
+
+    /// Waits until the object identified by `name` matches the `condition`.
+    /// The object doesn't need to exist yet when this method is called.
+    async fn await_object_condition<K>(
+        api: Api<K>,
+        name: &str,
+        condition: impl Condition<K>,
+        timeout: Duration,
+    ) -> Result<()>
+    where
+        K: Clone + Debug + Send + DeserializeOwned + Resource + 'static,
+    {
+        tokio::time::timeout(timeout, await_condition(api, name, condition))
+            .await
+            .context(WaitTimeoutSnafu { timeout, name })?
+            .context(WaitForConditionSnafu { name })?;
+
+        Ok(())
+    }
+
     /// Try to resolve the given [`GroupVersionKind`]. In case the resolution fails a discovery is run to pull in new
     /// GVKs that are not present in the [`Discovery`] cache. Afterwards a normal resolution is issued.
     async fn resolve_gvk(
@@ -439,3 +1050,47 @@ impl Client {
         }
     }
 }
+
+/// Returns a [`Condition`] which is met as soon as all requested replicas of
+/// a [`Deployment`] are ready.
+fn is_deployment_ready() -> impl Condition<Deployment> {
+    |deployment: Option<&Deployment>| {
+        deployment.is_some_and(|deployment| {
+            let replicas = deployment
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.replicas)
+                .unwrap_or(1);
+
+            let ready_replicas = deployment
+                .status
+                .as_ref()
+                .and_then(|status| status.ready_replicas)
+                .unwrap_or_default();
+
+            ready_replicas >= replicas
+        })
+    }
+}
+
+/// Returns a [`Condition`] which is met as soon as all requested replicas of
+/// a [`StatefulSet`] are ready.
+fn is_stateful_set_ready() -> impl Condition<StatefulSet> {
+    |stateful_set: Option<&StatefulSet>| {
+        stateful_set.is_some_and(|stateful_set| {
+            let replicas = stateful_set
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.replicas)
+                .unwrap_or(1);
+
+            let ready_replicas = stateful_set
+                .status
+                .as_ref()
+                .and_then(|status| status.ready_replicas)
+                .unwrap_or_default();
+
+            ready_replicas >= replicas
+        })
+    }
+}
@@ -6,6 +6,9 @@ pub mod path;
 pub mod string;
 pub mod templating;
 
+#[cfg(test)]
+pub(crate) mod temp_dir;
+
 /// Returns the name of the operator used in the Helm repository.
 pub fn operator_chart_name(name: &str) -> String {
     format!("{}-operator", name)
@@ -28,6 +28,26 @@ pub struct Parameter {
 
     /// Parameter name
     pub name: String,
+
+    /// Marks the parameter as sensitive, e.g. a password. The values of
+    /// sensitive parameters are never persisted in install records.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub secret: bool,
+}
+
+impl Parameter {
+    /// Returns if the parameter is sensitive. Parameters are considered
+    /// sensitive if they are explicitly marked as `secret` or if their name
+    /// suggests they contain credentials, as older demo and stack files don't
+    /// mark parameters explicitly.
+    pub fn is_secret(&self) -> bool {
+        let name = self.name.to_lowercase();
+
+        self.secret
+            || ["password", "secret", "token"]
+                .iter()
+                .any(|pattern| name.contains(pattern))
+    }
 }
 
 #[derive(Debug, Snafu, PartialEq)]
@@ -348,6 +368,7 @@ mod test {
             default: "Default value 1".into(),
             name: "param1".into(),
             value: "".into(),
+            secret: false,
         }];
 
         let input = "param1=value1";
@@ -374,6 +395,7 @@ mod test {
             default: "Default value 1".into(),
             name: "param1".into(),
             value: "".into(),
+            secret: false,
         }];
 
         let input = "param2=value2";
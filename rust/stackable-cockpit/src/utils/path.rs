@@ -1,18 +1,195 @@
-use std::{path::PathBuf, str::FromStr};
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
-use snafu::{ResultExt, Snafu};
+use snafu::{ensure, OptionExt, ResultExt, Snafu};
 use url::{ParseError, Url};
 
+/// The prefix of the URL fragment which pins the SHA-256 digest of a remote
+/// file, e.g. `https://example.com/stacks.yaml#sha256=<hex>`.
+const INTEGRITY_FRAGMENT_PREFIX: &str = "sha256=";
+
+/// The integrity suffix as it appears in references, which is only supported
+/// for HTTP(S) URLs.
+const INTEGRITY_SUFFIX: &str = "#sha256=";
+
+/// The scheme prefix of files stored in a Git repository, e.g.
+/// `git+https://github.com/stackabletech/demos.git#main:stacks/stacks-v2.yaml`.
+const GIT_SCHEME_PREFIX: &str = "git+";
+
+/// The tag of OCI artifacts which don't specify a tag or digest.
+const OCI_DEFAULT_TAG: &str = "latest";
+
 #[derive(Debug, Clone)]
 pub enum PathOrUrl {
     Path(PathBuf),
     Url(Url),
+    Git(GitFile),
+    Oci(OciArtifact),
+}
+
+/// A single file stored in a Git repository at a specific reference (branch,
+/// tag or commit), parsed from `git+<repository URL>#<reference>:<path>`.
+#[derive(Debug, Clone)]
+pub struct GitFile {
+    /// The original URL, which is also used as the cache key
+    pub url: Url,
+
+    /// The URL of the repository, without the `git+` prefix and fragment
+    pub repository: Url,
+
+    /// The branch, tag or commit to retrieve the file from
+    pub reference: String,
+
+    /// The path of the file in the repository
+    pub path: String,
+}
+
+/// A file stored as an artifact in an OCI registry, parsed from
+/// `oci://<registry>/<repository>[:<tag>|@<digest>][#<file>]`. The optional
+/// fragment selects the layer by its `org.opencontainers.image.title`
+/// annotation, which is required if the artifact contains multiple layers.
+#[derive(Debug, Clone)]
+pub struct OciArtifact {
+    /// The original URL, which is also used as the cache key
+    pub url: Url,
+
+    /// The registry host, including the port if specified
+    pub registry: String,
+
+    /// The repository in the registry, e.g. `sdp/stacks`
+    pub repository: String,
+
+    /// The tag or digest of the artifact
+    pub reference: String,
+
+    /// The title of the layer to retrieve
+    pub file: Option<String>,
+}
+
+impl PathOrUrl {
+    /// Returns the expected SHA-256 digest of the file as lowercase hex, if
+    /// it is pinned using the `#sha256=<hex>` suffix of a URL. The suffix is
+    /// rejected when parsing any other reference, see [`PathOrUrl::from_str`].
+    pub fn integrity(&self) -> Option<String> {
+        match self {
+            PathOrUrl::Url(url) => integrity(url),
+            PathOrUrl::Path(_) | PathOrUrl::Git(_) | PathOrUrl::Oci(_) => None,
+        }
+    }
+
+    /// Resolves the file `reference` relative to this file. URLs and
+    /// absolute paths are returned unchanged. Relative references are
+    /// resolved against the directory of this file, which is a directory
+    /// of the repository for Git files and a layer title for OCI artifacts.
+    pub fn resolve(&self, reference: &str) -> String {
+        if reference.contains("://") || Path::new(reference).is_absolute() {
+            return reference.to_string();
+        }
+
+        match self {
+            PathOrUrl::Path(path) => path
+                .parent()
+                .unwrap_or(Path::new(""))
+                .join(reference)
+                .display()
+                .to_string(),
+            PathOrUrl::Url(url) => url
+                .join(reference)
+                .map_or_else(|_| reference.to_string(), String::from),
+            PathOrUrl::Git(file) => {
+                let mut url = file.url.clone();
+                url.set_fragment(Some(&format!(
+                    "{}:{}",
+                    file.reference,
+                    join_relative(&file.path, reference)
+                )));
+                url.to_string()
+            }
+            PathOrUrl::Oci(artifact) => {
+                let mut url = artifact.url.clone();
+                url.set_fragment(Some(&join_relative(
+                    artifact.file.as_deref().unwrap_or_default(),
+                    reference,
+                )));
+                url.to_string()
+            }
+        }
+    }
+}
+
+/// Joins the relative `reference` to the directory of the `/` separated
+/// `file` path, resolving `.` and `..` segments.
+fn join_relative(file: &str, reference: &str) -> String {
+    let mut segments: Vec<&str> = file.split('/').collect();
+    segments.pop();
+
+    for segment in reference.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    segments
+        .into_iter()
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Returns the SHA-256 digest pinned by the fragment of `url` as lowercase
+/// hex.
+pub(crate) fn integrity(url: &Url) -> Option<String> {
+    url.fragment()
+        .and_then(|fragment| fragment.strip_prefix(INTEGRITY_FRAGMENT_PREFIX))
+        .map(str::to_ascii_lowercase)
+}
+
+impl Display for PathOrUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathOrUrl::Path(path) => write!(f, "{}", path.display()),
+            PathOrUrl::Url(url) => write!(f, "{url}"),
+            PathOrUrl::Git(file) => write!(f, "{}", file.url),
+            PathOrUrl::Oci(artifact) => write!(f, "{}", artifact.url),
+        }
+    }
 }
 
 #[derive(Debug, Snafu)]
 pub enum PathOrUrlParseError {
     #[snafu(display("failed to parse URL"))]
     UrlParse { source: ParseError },
+
+    #[snafu(display("invalid integrity {integrity:?}, expected sha256=<64 hex characters>"))]
+    InvalidIntegrity { integrity: String },
+
+    #[snafu(display(
+        "{file} pins a SHA-256 digest, which is only supported for https and http URLs"
+    ))]
+    UnsupportedIntegrity { file: String },
+
+    #[snafu(display("{url} is not a valid local file URL"))]
+    InvalidFileUrl { url: Url },
+
+    #[snafu(display("invalid Git file {url}, expected git+<repository>#<reference>:<path>"))]
+    InvalidGitFile { url: String },
+
+    #[snafu(display(
+        "unsupported protocol {protocol:?} of Git file {url}, only https, http and file are supported"
+    ))]
+    UnsupportedGitProtocol { url: String, protocol: String },
+
+    #[snafu(display(
+        "invalid OCI artifact {url}, expected oci://<registry>/<repository>[:<tag>|@<digest>][#<file>]"
+    ))]
+    InvalidOciArtifact { url: String },
 }
 
 pub trait IntoPathOrUrl: Sized {
@@ -90,10 +267,331 @@ impl FromStr for PathOrUrl {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.starts_with("https://") || s.starts_with("http://") {
             let url = Url::parse(s).context(UrlParseSnafu)?;
+
+            if let Some(digest) = integrity(&url) {
+                ensure!(
+                    digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit()),
+                    InvalidIntegritySnafu {
+                        integrity: url.fragment().unwrap_or_default()
+                    }
+                );
+            }
+
             return Ok(Self::Url(url));
         }
 
+        // The integrity of local files, Git files and OCI artifacts is not
+        // verified, so a pinned digest must not be ignored silently
+        ensure!(
+            !s.contains(INTEGRITY_SUFFIX),
+            UnsupportedIntegritySnafu { file: s }
+        );
+
+        if s.starts_with("file://") {
+            let url = Url::parse(s).context(UrlParseSnafu)?;
+            let path = url
+                .to_file_path()
+                .ok()
+                .context(InvalidFileUrlSnafu { url: url.clone() })?;
+
+            return Ok(Self::Path(path));
+        }
+
+        if s.starts_with(GIT_SCHEME_PREFIX) {
+            return GitFile::from_str(s).map(Self::Git);
+        }
+
+        if s.starts_with("oci://") {
+            return OciArtifact::from_str(s).map(Self::Oci);
+        }
+
         let path = PathBuf::from(s);
         Ok(Self::Path(path))
     }
 }
+
+impl FromStr for GitFile {
+    type Err = PathOrUrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = Url::parse(s).context(UrlParseSnafu)?;
+
+        // References starting with a dash would be interpreted as options by
+        // git
+        let (reference, path) = url
+            .fragment()
+            .and_then(|fragment| fragment.split_once(':'))
+            .filter(|(reference, path)| {
+                !reference.is_empty() && !reference.starts_with('-') && !path.is_empty()
+            })
+            .context(InvalidGitFileSnafu { url: s })?;
+
+        let mut repository = s
+            .strip_prefix(GIT_SCHEME_PREFIX)
+            .map(Url::parse)
+            .context(InvalidGitFileSnafu { url: s })?
+            .context(UrlParseSnafu)?;
+        repository.set_fragment(None);
+
+        // Other protocols, like ext::, can run arbitrary commands
+        ensure!(
+            matches!(repository.scheme(), "https" | "http" | "file"),
+            UnsupportedGitProtocolSnafu {
+                url: s,
+                protocol: repository.scheme(),
+            }
+        );
+
+        Ok(Self {
+            reference: reference.to_string(),
+            path: path.trim_start_matches('/').to_string(),
+            repository,
+            url,
+        })
+    }
+}
+
+impl FromStr for OciArtifact {
+    type Err = PathOrUrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = Url::parse(s).context(UrlParseSnafu)?;
+
+        let registry = match (url.host_str().filter(|host| !host.is_empty()), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return InvalidOciArtifactSnafu { url: s }.fail(),
+        };
+
+        let path = url.path().trim_start_matches('/');
+
+        // A digest takes precedence over the tag, e.g. repo:tag@sha256:<hex>
+        let (repository, reference) = match path.split_once('@') {
+            Some((repository, digest)) => {
+                let repository = repository
+                    .rsplit_once(':')
+                    .filter(|(_, tag)| !tag.contains('/'))
+                    .map_or(repository, |(repository, _)| repository);
+                (repository, digest)
+            }
+            None => match path.rsplit_once(':') {
+                Some((repository, tag)) if !tag.contains('/') => (repository, tag),
+                _ => (path, OCI_DEFAULT_TAG),
+            },
+        };
+
+        ensure!(
+            !repository.is_empty() && !reference.is_empty(),
+            InvalidOciArtifactSnafu { url: s }
+        );
+
+        Ok(Self {
+            registry,
+            repository: repository.to_string(),
+            reference: reference.to_string(),
+            file: url
+                .fragment()
+                .filter(|fragment| !fragment.is_empty())
+                .map(ToString::to_string),
+            url,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("https://example.com/stacks.yaml", None)]
+    #[case("https://example.com/stacks.yaml#main", None)]
+    #[case(
+        "https://example.com/stacks.yaml#sha256=E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855",
+        Some("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+    )]
+    #[case("stacks.yaml", None)]
+    fn parse_integrity(#[case] input: &str, #[case] expected: Option<&str>) {
+        let path_or_url = PathOrUrl::from_str(input).unwrap();
+        assert_eq!(path_or_url.integrity().as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case("https://example.com/stacks.yaml#sha256=")]
+    #[case("https://example.com/stacks.yaml#sha256=abc")]
+    #[case(
+        "https://example.com/stacks.yaml#sha256=x3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    )]
+    fn parse_invalid_integrity(#[case] input: &str) {
+        assert!(matches!(
+            PathOrUrl::from_str(input),
+            Err(PathOrUrlParseError::InvalidIntegrity { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case("stacks.yaml#sha256=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")]
+    #[case(
+        "file:///tmp/stacks.yaml#sha256=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    )]
+    #[case(
+        "git+https://github.com/stackabletech/demos.git#main:stacks.yaml#sha256=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    )]
+    #[case(
+        "oci://oci.example.com/sdp/stacks:1.0.0#sha256=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    )]
+    fn parse_unsupported_integrity(#[case] input: &str) {
+        assert!(matches!(
+            PathOrUrl::from_str(input),
+            Err(PathOrUrlParseError::UnsupportedIntegrity { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_file_url() {
+        let path_or_url = PathOrUrl::from_str("file:///tmp/stacks.yaml").unwrap();
+        assert!(
+            matches!(path_or_url, PathOrUrl::Path(path) if path == Path::new("/tmp/stacks.yaml"))
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "git+https://github.com/stackabletech/demos.git#main:stacks/stacks-v2.yaml",
+        "https://github.com/stackabletech/demos.git",
+        "main",
+        "stacks/stacks-v2.yaml"
+    )]
+    #[case(
+        "git+file:///srv/demos.git#release-24.11:/demos/demos-v2.yaml",
+        "file:///srv/demos.git",
+        "release-24.11",
+        "demos/demos-v2.yaml"
+    )]
+    fn parse_git_file(
+        #[case] input: &str,
+        #[case] repository: &str,
+        #[case] reference: &str,
+        #[case] path: &str,
+    ) {
+        let PathOrUrl::Git(file) = PathOrUrl::from_str(input).unwrap() else {
+            panic!("{input} is not parsed as Git file");
+        };
+
+        assert_eq!(file.repository.as_str(), repository);
+        assert_eq!(file.reference, reference);
+        assert_eq!(file.path, path);
+    }
+
+    #[rstest]
+    #[case("oci://oci.stackable.tech/sdp/stacks", "sdp/stacks", "latest", None)]
+    #[case(
+        "oci://localhost:5000/sdp/stacks:24.11#stacks-v2.yaml",
+        "sdp/stacks",
+        "24.11",
+        Some("stacks-v2.yaml")
+    )]
+    #[case(
+        "oci://oci.stackable.tech/sdp/stacks:24.11@sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        "sdp/stacks",
+        "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        None
+    )]
+    fn parse_oci_artifact(
+        #[case] input: &str,
+        #[case] repository: &str,
+        #[case] reference: &str,
+        #[case] file: Option<&str>,
+    ) {
+        let PathOrUrl::Oci(artifact) = PathOrUrl::from_str(input).unwrap() else {
+            panic!("{input} is not parsed as OCI artifact");
+        };
+
+        assert_eq!(artifact.repository, repository);
+        assert_eq!(artifact.reference, reference);
+        assert_eq!(artifact.file.as_deref(), file);
+    }
+
+    #[rstest]
+    #[case("git+https://github.com/stackabletech/demos.git")]
+    #[case("git+https://github.com/stackabletech/demos.git#main")]
+    #[case("git+https://github.com/stackabletech/demos.git#:stacks.yaml")]
+    #[case("git+https://github.com/stackabletech/demos.git#--upload-pack=sh:stacks.yaml")]
+    #[case("git+ext::sh -c touch% /tmp/pwned#main:stacks.yaml")]
+    #[case("git+ssh://git@github.com/stackabletech/demos.git#main:stacks.yaml")]
+    #[case("oci:///sdp/stacks:24.11")]
+    fn parse_invalid_git_or_oci(#[case] input: &str) {
+        assert!(matches!(
+            PathOrUrl::from_str(input),
+            Err(PathOrUrlParseError::InvalidGitFile { .. }
+                | PathOrUrlParseError::UnsupportedGitProtocol { .. }
+                | PathOrUrlParseError::InvalidOciArtifact { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case(
+        "stacks/stacks.yaml",
+        "manifests/trino.yaml",
+        "stacks/manifests/trino.yaml"
+    )]
+    #[case("stacks.yaml", "./trino.yaml", "./trino.yaml")]
+    #[case("stacks/stacks.yaml", "/srv/trino.yaml", "/srv/trino.yaml")]
+    #[case(
+        "https://example.com/stacks/stacks.yaml#sha256=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        "../manifests/trino.yaml",
+        "https://example.com/manifests/trino.yaml"
+    )]
+    #[case(
+        "https://example.com/stacks/stacks.yaml",
+        "https://example.org/trino.yaml",
+        "https://example.org/trino.yaml"
+    )]
+    #[case(
+        "git+https://github.com/mycorp/stacks.git#main:stacks/stacks.yaml",
+        "../manifests/./trino.yaml",
+        "git+https://github.com/mycorp/stacks.git#main:manifests/trino.yaml"
+    )]
+    #[case(
+        "oci://oci.example.com/mycorp/stacks:1.0.0#stacks.yaml",
+        "manifests/trino.yaml",
+        "oci://oci.example.com/mycorp/stacks:1.0.0#manifests/trino.yaml"
+    )]
+    fn resolve_relative_reference(
+        #[case] origin: &str,
+        #[case] reference: &str,
+        #[case] expected: &str,
+    ) {
+        let origin = PathOrUrl::from_str(origin).unwrap();
+        assert_eq!(origin.resolve(reference), expected);
+    }
+
+    #[rstest]
+    #[case("https://example.com/stacks/stacks.yaml", true)]
+    #[case("stacks/stacks.yaml", false)]
+    #[case(
+        "git+https://github.com/mycorp/stacks.git#main:stacks/stacks.yaml",
+        false
+    )]
+    fn resolve_pinned_reference(#[case] origin: &str, #[case] supported: bool) {
+        let origin = PathOrUrl::from_str(origin).unwrap();
+        let resolved = origin.resolve(
+            "./trino.yaml#sha256=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        );
+
+        match PathOrUrl::from_str(&resolved) {
+            Ok(path_or_url) => {
+                assert!(supported);
+                assert!(path_or_url.integrity().is_some());
+            }
+            Err(err) => {
+                assert!(!supported);
+                assert!(matches!(
+                    err,
+                    PathOrUrlParseError::UnsupportedIntegrity { .. }
+                ));
+            }
+        }
+    }
+}
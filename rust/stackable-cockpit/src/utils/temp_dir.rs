@@ -0,0 +1,41 @@
+//! This module contains helpers used by tests which operate on files.
+
+use std::path::{Path, PathBuf};
+
+/// A uniquely named directory below [`std::env::temp_dir`], which is removed
+/// including its content when dropped.
+pub(crate) struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates a new temporary directory. The `prefix` is used to identify
+    /// which test created the directory.
+    pub(crate) fn new(prefix: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("stackable-{prefix}-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&path).expect("failed to create temporary directory");
+
+        Self { path }
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes `content` to the file `name` in this directory and returns its
+    /// path.
+    pub(crate) fn write(&self, name: &str, content: &str) -> PathBuf {
+        let path = self.path.join(name);
+        std::fs::write(&path, content).expect("failed to write temporary file");
+
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        // Failing to clean up doesn't affect the outcome of the test
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
@@ -1,32 +1,494 @@
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
 
-use bcrypt::DEFAULT_COST;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use bcrypt::{Version, DEFAULT_COST};
 use rand::distributions::{Alphanumeric, DistString};
-use serde::de::DeserializeOwned;
-use tera::{Context, Function, Tera, Value};
+use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
+use tera::{
+    ast::{Expr, ExprVal, Node},
+    Context, Filter, Function, Tera, Value,
+};
+use tokio::{
+    runtime::{Handle, RuntimeFlavor},
+    task::block_in_place,
+};
+use tracing::{debug, warn};
 
-use crate::constants::PASSWORD_LENGTH;
+use crate::{
+    constants::{
+        DEFAULT_CLUSTER_DOMAIN, DEFAULT_PRODUCT_NAMESPACE, GENERATED_PASSWORDS_SECRET_NAME,
+        PASSWORD_LENGTH,
+    },
+    platform::cluster,
+    utils::k8s::{self, Client},
+};
+
+/// Provides templates access to the cluster the rendered manifests are
+/// installed into. This is required by functions like `lookup_secret`.
+#[derive(Clone)]
+pub struct ClusterAccess {
+    pub client: Client,
+
+    /// Facts about the cluster, which are available as the `cluster` object
+    /// in templates.
+    pub facts: ClusterFacts,
+
+    /// If set, generated values are neither stored in the cluster nor
+    /// invented. Placeholders are used instead, so that renders are stable.
+    /// This is used when manifests are only rendered, but not installed.
+    pub read_only: bool,
+
+    /// Labels of the Secret storing named passwords, so that it is pruned
+    /// like the other installed objects.
+    pub labels: BTreeMap<String, String>,
+
+    /// Tracks the named passwords used and stored while rendering.
+    pub passwords: Arc<GeneratedPasswords>,
+}
+
+/// Tracks the named passwords of an installation. The Secret storing them is
+/// shared by all demos and stacks in the product namespace, so rollbacks only
+/// remove the passwords this installation inserted.
+#[derive(Debug, Default)]
+pub struct GeneratedPasswords {
+    used: AtomicBool,
+    inserted: Mutex<Vec<String>>,
+}
+
+impl GeneratedPasswords {
+    /// Returns if a named password was used, which makes the Secret storing
+    /// them part of the installation.
+    pub fn used(&self) -> bool {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// Returns the names of the passwords which were inserted into the
+    /// Secret, as opposed to the ones which were already stored.
+    pub fn inserted(&self) -> Vec<String> {
+        self.inserted
+            .lock()
+            .expect("lock of inserted passwords is poisoned")
+            .clone()
+    }
+}
+
+/// Facts about the cluster the manifests are installed into. Templates can
+/// use these to adapt to the target cluster, e.g. using
+/// `{% if cluster.openshift %}`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ClusterFacts {
+    /// The product namespace the manifests are installed into
+    pub namespace: String,
+
+    /// The Kubernetes version in the format `<major>.<minor>`, e.g. `1.30`
+    pub kubernetes_version: Option<String>,
+
+    /// Number of nodes regardless of their type
+    pub node_count: Option<usize>,
+
+    /// The CPU architecture of the nodes, e.g. `amd64` or `arm64`. This is
+    /// only set if all nodes share the same architecture.
+    pub architecture: Option<String>,
+
+    /// Distinct CPU architectures of all nodes
+    pub architectures: Vec<String>,
+
+    /// Whether the OpenShift APIs are available
+    pub openshift: bool,
+
+    /// Name of the default StorageClass, if there is one
+    pub default_storage_class: Option<String>,
+
+    /// The cluster domain used for DNS names of Services, e.g. `cluster.local`
+    pub domain: String,
+}
+
+impl ClusterFacts {
+    /// Collects facts about the cluster using the `client`. This is
+    /// best-effort, as users might not be permitted to list cluster-wide
+    /// resources like nodes. Facts which can't be retrieved are unknown
+    /// ([`None`]) and a warning is logged. If the cluster domain can't be
+    /// determined, it defaults to `cluster.local`.
+    pub async fn gather(client: &Client, namespace: &str) -> Self {
+        let kubernetes_version = match client.get_server_version().await {
+            Ok(version) => Some(kubernetes_version(&version.major, &version.minor)),
+            Err(err) => {
+                warn!(
+                    "Failed to retrieve the Kubernetes version, it is unknown in templates: {err}"
+                );
+                None
+            }
+        };
+
+        let (node_count, architectures) = match client.list_nodes().await {
+            Ok(nodes) => {
+                let architectures: BTreeSet<_> = nodes
+                    .iter()
+                    .filter_map(|node| node.status.as_ref()?.node_info.as_ref())
+                    .map(|node_info| node_info.architecture.clone())
+                    .collect();
+
+                (Some(nodes.items.len()), architectures.into_iter().collect())
+            }
+            Err(err) => {
+                warn!("Failed to list nodes, the nodes are unknown in templates: {err}");
+                (None, Vec::new())
+            }
+        };
+
+        let default_storage_class = match client.list_storage_classes().await {
+            Ok(storage_classes) => cluster::default_storage_class(&storage_classes.items)
+                .and_then(|storage_class| storage_class.metadata.name.clone()),
+            Err(err) => {
+                warn!("Failed to list StorageClasses, the default StorageClass is unknown in templates: {err}");
+                None
+            }
+        };
+
+        let domain = match client.get_cluster_domain().await {
+            Ok(Some(domain)) => domain,
+            Ok(None) => DEFAULT_CLUSTER_DOMAIN.to_string(),
+            Err(err) => {
+                debug!("Failed to retrieve cluster domain ({err}), using {DEFAULT_CLUSTER_DOMAIN}");
+                DEFAULT_CLUSTER_DOMAIN.to_string()
+            }
+        };
+
+        Self {
+            namespace: namespace.to_string(),
+            kubernetes_version,
+            node_count,
+            architecture: match architectures.as_slice() {
+                [architecture] => Some(architecture.clone()),
+                _ => None,
+            },
+            architectures,
+            openshift: client.has_api_group(OPENSHIFT_API_GROUP).await,
+            default_storage_class,
+            domain,
+        }
+    }
+}
+
+/// API group which is only served by OpenShift clusters.
+const OPENSHIFT_API_GROUP: &str = "config.openshift.io";
+
+/// Formats the Kubernetes version as `<major>.<minor>`. Some distributions,
+/// like EKS, append a `+` to the minor version, which is removed.
+fn kubernetes_version(major: &str, minor: &str) -> String {
+    format!("{major}.{minor}", minor = minor.trim_end_matches('+'))
+}
+
+/// Describes a function or filter which can be used in templates.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateFunction {
+    pub name: &'static str,
+    pub kind: TemplateFunctionKind,
+    pub usage: &'static str,
+    pub description: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateFunctionKind {
+    /// Called like `{{ name(arg=value) }}`
+    Function,
+
+    /// Applied to a value like `{{ value | name(arg=value) }}`
+    Filter,
+}
+
+/// All custom functions and filters available in templates, in addition to
+/// the [built-in ones](https://keats.github.io/tera/docs/#built-ins) of
+/// [`tera`].
+pub const TEMPLATE_FUNCTIONS: &[TemplateFunction] = &[
+    TemplateFunction {
+        name: "random_password",
+        kind: TemplateFunctionKind::Function,
+        usage: "random_password([name=<NAME>])",
+        description: "Returns a random alphanumeric password. Passwords with a name are stored in the product namespace and reused by subsequent installs",
+    },
+    TemplateFunction {
+        name: "random_uuid",
+        kind: TemplateFunctionKind::Function,
+        usage: "random_uuid()",
+        description: "Returns a random (version 4) UUID",
+    },
+    TemplateFunction {
+        name: "bcrypt",
+        kind: TemplateFunctionKind::Function,
+        usage: "bcrypt(password=<PASSWORD>)",
+        description: "Returns the bcrypt hash of the password. The salt is derived from the password, so the hash is stable across renders",
+    },
+    TemplateFunction {
+        name: "htpasswd",
+        kind: TemplateFunctionKind::Function,
+        usage: "htpasswd(username=<USERNAME>, password=<PASSWORD>)",
+        description: "Returns an htpasswd entry using a bcrypt hash of the password. The salt is derived from the password, so the entry is stable across renders",
+    },
+    TemplateFunction {
+        name: "sha256",
+        kind: TemplateFunctionKind::Function,
+        usage: "sha256(value=<VALUE>)",
+        description: "Returns the hex encoded SHA-256 digest of the value",
+    },
+    TemplateFunction {
+        name: "base64_encode",
+        kind: TemplateFunctionKind::Function,
+        usage: "base64_encode(value=<VALUE>)",
+        description: "Returns the Base64 encoded value",
+    },
+    TemplateFunction {
+        name: "base64_decode",
+        kind: TemplateFunctionKind::Function,
+        usage: "base64_decode(value=<VALUE>)",
+        description: "Returns the Base64 decoded value, which needs to be valid UTF-8",
+    },
+    TemplateFunction {
+        name: "lookup_secret",
+        kind: TemplateFunctionKind::Function,
+        usage: "lookup_secret(namespace=<NAMESPACE>, name=<NAME>, key=<KEY>, [default=<DEFAULT>])",
+        description: "Returns the value of the key of an existing Secret in the cluster, or the default if the Secret or key doesn't exist",
+    },
+    TemplateFunction {
+        name: "to_yaml",
+        kind: TemplateFunctionKind::Filter,
+        usage: "to_yaml",
+        description: "Serializes the value to YAML",
+    },
+    TemplateFunction {
+        name: "indent",
+        kind: TemplateFunctionKind::Filter,
+        usage: "indent([width=<WIDTH>], [prefix=<PREFIX>], [first=<BOOL>], [blank=<BOOL>])",
+        description: "Indents all lines except the first (unless first=true) and blank ones (unless blank=true) by width spaces or the prefix, which defaults to 4 spaces",
+    },
+];
 
 /// Renders the templated `content` by replacing template strings with the
 /// appropiate `parameters`. Internally this uses [`tera`] to render the final
-/// output. Available helper functions are:
-///
-/// - `random_password`: Returns a random password with a relatively secure RNG.
-///   See [`rand::thread_rng`] for more information.
-/// - `bcrypt`: Returns the bcyrpt hash of the provided `password` parameter.
-pub fn render(content: &str, parameters: &HashMap<String, String>) -> Result<String, tera::Error> {
+/// output. Available helper functions and filters are listed in
+/// [`TEMPLATE_FUNCTIONS`]. The `parameters` are available both directly and
+/// as the `parameters` object. If `cluster` access is provided, the
+/// [`ClusterFacts`] are available as the `cluster` object. Functions which
+/// access the cluster, like `lookup_secret`, fail if no `cluster` access is
+/// provided.
+pub fn render(
+    content: &str,
+    parameters: &HashMap<String, String>,
+    cluster: Option<&ClusterAccess>,
+) -> Result<String, tera::Error> {
     // Create templating context
-    let context = Context::from_serialize(parameters)?;
+    let mut context = Context::from_serialize(parameters)?;
+    context.insert("parameters", parameters);
+    if let Some(cluster) = cluster {
+        context.insert("cluster", &cluster.facts);
+    }
 
     // Create render engine
-    let mut tera = Tera::default();
-    tera.register_function("random_password", random_password());
-    tera.register_function("bcrypt", bcrypt());
+    let mut tera = create_engine();
+    tera.register_function("random_password", random_password(cluster.cloned()));
+    tera.register_function("lookup_secret", lookup_secret(cluster.cloned()));
 
     // Render template
     tera.render_str(content, &context)
 }
 
+/// Renders `content` like [`render`], but without access to a cluster. The
+/// `cluster` object contains placeholder facts, `random_password` returns a
+/// placeholder and `lookup_secret` returns its `default` or a placeholder
+/// value.
+/// This is used to validate manifests without installing them.
+pub fn render_offline(
+    content: &str,
+    parameters: &HashMap<String, String>,
+) -> Result<String, tera::Error> {
+    let facts = ClusterFacts {
+        namespace: DEFAULT_PRODUCT_NAMESPACE.into(),
+        kubernetes_version: Some("1.30".into()),
+        node_count: Some(1),
+        architecture: Some("amd64".into()),
+        architectures: vec!["amd64".into()],
+        openshift: false,
+        default_storage_class: Some("standard".into()),
+        domain: DEFAULT_CLUSTER_DOMAIN.into(),
+    };
+
+    let mut context = Context::from_serialize(parameters)?;
+    context.insert("parameters", parameters);
+    context.insert("cluster", &facts);
+
+    let mut tera = create_engine();
+    tera.register_function("random_password", offline_random_password());
+    tera.register_function("lookup_secret", offline_lookup_secret());
+
+    tera.render_str(content, &context)
+}
+
+/// Returns the names of all parameters the template `content` references,
+/// either directly or via the `parameters` object. Variables which are
+/// defined in the template itself (using `set`, `for` or macro arguments),
+/// the `cluster` object and variables which are guarded by the `default`
+/// filter or the `defined` test are not included.
+pub fn referenced_parameters(content: &str) -> Result<BTreeSet<String>, tera::Error> {
+    let template = tera::Template::new("manifest", None, content)?;
+
+    let mut collector = VariableCollector::default();
+    collector.visit_nodes(&template.ast);
+
+    Ok(collector
+        .referenced
+        .into_iter()
+        .filter(|name| !collector.defined.contains(name))
+        .collect())
+}
+
+/// Creates the render engine with all functions and filters which don't
+/// require access to a cluster.
+fn create_engine() -> Tera {
+    let mut tera = Tera::default();
+    tera.register_function("random_uuid", random_uuid());
+    tera.register_function("bcrypt", bcrypt());
+    tera.register_function("htpasswd", htpasswd());
+    tera.register_function("sha256", sha256());
+    tera.register_function("base64_encode", base64_encode());
+    tera.register_function("base64_decode", base64_decode());
+    tera.register_filter("to_yaml", to_yaml());
+    tera.register_filter("indent", indent());
+
+    tera
+}
+
+/// Variables which are always available in templates.
+const BUILTIN_VARIABLES: &[&str] = &["cluster", "loop", "parameters", "__tera_context"];
+
+/// Walks the template AST and collects the referenced and locally defined
+/// variable names.
+#[derive(Default)]
+struct VariableCollector {
+    referenced: BTreeSet<String>,
+    defined: BTreeSet<String>,
+}
+
+impl VariableCollector {
+    fn visit_nodes(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            match node {
+                Node::VariableBlock(_, expr) => self.visit_expr(expr),
+                Node::MacroDefinition(_, definition, _) => {
+                    self.defined.extend(definition.args.keys().cloned());
+                    definition
+                        .args
+                        .values()
+                        .flatten()
+                        .for_each(|e| self.visit_expr(e));
+                    self.visit_nodes(&definition.body);
+                }
+                Node::Set(_, set) => {
+                    self.defined.insert(set.key.clone());
+                    self.visit_expr(&set.value);
+                }
+                Node::FilterSection(_, section, _) => {
+                    section
+                        .filter
+                        .args
+                        .values()
+                        .for_each(|e| self.visit_expr(e));
+                    self.visit_nodes(&section.body);
+                }
+                Node::Block(_, block, _) => self.visit_nodes(&block.body),
+                Node::Forloop(_, forloop, _) => {
+                    self.defined.extend(forloop.key.clone());
+                    self.defined.insert(forloop.value.clone());
+                    self.visit_expr(&forloop.container);
+                    self.visit_nodes(&forloop.body);
+                    if let Some(body) = &forloop.empty_body {
+                        self.visit_nodes(body);
+                    }
+                }
+                Node::If(condition, _) => {
+                    for (_, expr, body) in &condition.conditions {
+                        self.visit_expr(expr);
+                        self.visit_nodes(body);
+                    }
+                    if let Some((_, body)) = &condition.otherwise {
+                        self.visit_nodes(body);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        for filter in &expr.filters {
+            filter.args.values().for_each(|e| self.visit_expr(e));
+        }
+
+        // Variables with a default value don't need to be declared
+        if matches!(expr.val, ExprVal::Ident(_)) && expr.has_default_filter() {
+            return;
+        }
+
+        self.visit_expr_val(&expr.val);
+    }
+
+    fn visit_expr_val(&mut self, val: &ExprVal) {
+        match val {
+            ExprVal::Ident(ident) => self.visit_ident(ident),
+            ExprVal::Math(math) => {
+                self.visit_expr(&math.lhs);
+                self.visit_expr(&math.rhs);
+            }
+            ExprVal::Logic(logic) => {
+                self.visit_expr(&logic.lhs);
+                self.visit_expr(&logic.rhs);
+            }
+            ExprVal::Test(test) => {
+                if !matches!(test.name.as_str(), "defined" | "undefined") {
+                    self.visit_ident(&test.ident);
+                }
+                test.args.iter().for_each(|e| self.visit_expr(e));
+            }
+            ExprVal::MacroCall(call) => call.args.values().for_each(|e| self.visit_expr(e)),
+            ExprVal::FunctionCall(call) => call.args.values().for_each(|e| self.visit_expr(e)),
+            ExprVal::Array(values) => values.iter().for_each(|e| self.visit_expr(e)),
+            ExprVal::StringConcat(concat) => {
+                concat.values.iter().for_each(|v| self.visit_expr_val(v))
+            }
+            ExprVal::In(contains) => {
+                self.visit_expr(&contains.lhs);
+                self.visit_expr(&contains.rhs);
+            }
+            ExprVal::String(_) | ExprVal::Int(_) | ExprVal::Float(_) | ExprVal::Bool(_) => {}
+        }
+    }
+
+    /// Records the root of the (possibly dotted) identifier. Accesses of the
+    /// `parameters` object are recorded by the accessed parameter name.
+    fn visit_ident(&mut self, ident: &str) {
+        let mut segments = ident.split(['.', '[']);
+        let root = segments.next().unwrap_or_default();
+
+        let name = match (root, segments.next()) {
+            ("parameters", Some(name)) => name.trim_matches(|c| matches!(c, '"' | '\'' | ']')),
+            _ => root,
+        };
+
+        if !name.is_empty() && (root == "parameters" || !BUILTIN_VARIABLES.contains(&name)) {
+            self.referenced.insert(name.to_string());
+        }
+    }
+}
+
 /// Internal helper function to retrieve value of type `T` from the `map` by
 /// `key`. If the `key` is not present in the `map`, it returns an error.
 fn get_from_map<T>(map: &HashMap<String, Value>, key: &str) -> tera::Result<T>
@@ -42,19 +504,420 @@ where
     }
 }
 
-fn random_password() -> impl Function {
-    |_args: &HashMap<String, Value>| -> tera::Result<Value> {
-        let password = Alphanumeric.sample_string(&mut rand::thread_rng(), PASSWORD_LENGTH);
+/// Internal helper function to retrieve an optional value of type `T` from
+/// the `map` by `key`.
+fn get_optional_from_map<T>(map: &HashMap<String, Value>, key: &str) -> tera::Result<Option<T>>
+where
+    T: DeserializeOwned,
+{
+    match map.contains_key(key) {
+        true => get_from_map(map, key).map(Some),
+        false => Ok(None),
+    }
+}
+
+/// Returns a random password. If a `name` is provided, the password is looked
+/// up in (and stored to) a Secret in the product namespace first, so that
+/// repeated installs render identical manifests. Read-only renders return a
+/// placeholder instead of generating a password.
+fn random_password(cluster: Option<ClusterAccess>) -> impl Function {
+    // Passwords generated during this render, so that the same name always
+    // results in the same password, even if it is not stored in the cluster
+    let generated = Mutex::new(HashMap::<String, String>::new());
+
+    move |args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let generate = || Alphanumeric.sample_string(&mut rand::thread_rng(), PASSWORD_LENGTH);
+
+        let name = get_optional_from_map::<String>(args, "name")?;
+        let read_only = cluster.as_ref().is_some_and(|cluster| cluster.read_only);
+
+        let Some(name) = name else {
+            return Ok(match read_only {
+                true => password_placeholder(None),
+                false => generate(),
+            }
+            .into());
+        };
+
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(format!(
+                "Invalid password name {name:?}, only alphanumeric characters, '-', '_' and '.' are allowed"
+            )
+            .into());
+        }
+
+        let mut generated = generated
+            .lock()
+            .expect("lock of generated passwords is poisoned");
+        if let Some(password) = generated.get(&name) {
+            return Ok(password.clone().into());
+        }
+
+        let cluster = cluster
+            .as_ref()
+            .ok_or("Generating named passwords requires access to a Kubernetes cluster")?;
+        let namespace = &cluster.facts.namespace;
+
+        if !cluster.read_only {
+            cluster.passwords.used.store(true, Ordering::Relaxed);
+        }
+
+        // Tera functions are synchronous, so we need to block on the requests
+        let password = block_on(async {
+            let stored = cluster
+                .client
+                .get_secret_value(namespace, GENERATED_PASSWORDS_SECRET_NAME, &name)
+                .await?;
+
+            match stored {
+                Some(password) => Ok(password),
+                None if cluster.read_only => Ok(password_placeholder(Some(&name))),
+                // Another installation might store the password at the
+                // same time, in which case its password is returned
+                None => {
+                    let (password, inserted) = cluster
+                        .client
+                        .insert_secret_value(
+                            namespace,
+                            GENERATED_PASSWORDS_SECRET_NAME,
+                            &name,
+                            &generate(),
+                            &cluster.labels,
+                        )
+                        .await?;
+
+                    if inserted {
+                        cluster
+                            .passwords
+                            .inserted
+                            .lock()
+                            .expect("lock of inserted passwords is poisoned")
+                            .push(name.clone());
+                    }
+
+                    Ok(password)
+                }
+            }
+        })?
+        .map_err(|err: k8s::Error| {
+            format!("Failed to look up or store password {name:?} in namespace {namespace}: {err}")
+        })?;
+
+        generated.insert(name, password.clone());
         Ok(password.into())
     }
 }
 
+fn random_uuid() -> impl Function {
+    |_args: &HashMap<String, Value>| -> tera::Result<Value> {
+        Ok(uuid::Uuid::new_v4().to_string().into())
+    }
+}
+
 fn bcrypt() -> impl Function {
     |args: &HashMap<String, Value>| -> tera::Result<Value> {
         let password: String = get_from_map(args, "password")?;
-        let hash = bcrypt::hash(password, DEFAULT_COST)
+        let hash = bcrypt::hash_with_salt(&password, DEFAULT_COST, bcrypt_salt(&password))
             .map_err(|err| format!("Failed to create bcrypt hash: {err}"))?;
 
-        Ok(hash.into())
+        Ok(hash.to_string().into())
+    }
+}
+
+fn htpasswd() -> impl Function {
+    |args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let username: String = get_from_map(args, "username")?;
+        let password: String = get_from_map(args, "password")?;
+
+        // htpasswd uses the $2y$ prefix for bcrypt hashes
+        let hash = bcrypt::hash_with_salt(&password, DEFAULT_COST, bcrypt_salt(&password))
+            .map_err(|err| format!("Failed to create bcrypt hash: {err}"))?
+            .format_for_version(Version::TwoY);
+
+        Ok(format!("{username}:{hash}").into())
+    }
+}
+
+/// Derives the bcrypt salt from the `password`, so that hashing the same
+/// (e.g. named) password results in the same hash on every render. A random
+/// salt would change the rendered manifests on every install.
+fn bcrypt_salt(password: &str) -> [u8; 16] {
+    let digest = Sha256::digest(password.as_bytes());
+    digest[..16]
+        .try_into()
+        .expect("SHA-256 digests are longer than 16 bytes")
+}
+
+fn sha256() -> impl Function {
+    |args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let value: String = get_from_map(args, "value")?;
+        Ok(format!("{:x}", Sha256::digest(value.as_bytes())).into())
+    }
+}
+
+fn base64_encode() -> impl Function {
+    |args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let value: String = get_from_map(args, "value")?;
+        Ok(BASE64.encode(value).into())
+    }
+}
+
+fn base64_decode() -> impl Function {
+    |args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let value: String = get_from_map(args, "value")?;
+
+        let decoded = BASE64
+            .decode(value)
+            .map_err(|err| format!("Failed to decode Base64 value: {err}"))?;
+        let decoded = String::from_utf8(decoded)
+            .map_err(|err| format!("Base64 decoded value is not valid UTF-8: {err}"))?;
+
+        Ok(decoded.into())
+    }
+}
+
+fn lookup_secret(cluster: Option<ClusterAccess>) -> impl Function {
+    move |args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let namespace: String = get_from_map(args, "namespace")?;
+        let name: String = get_from_map(args, "name")?;
+        let key: String = get_from_map(args, "key")?;
+
+        let cluster = cluster
+            .as_ref()
+            .ok_or("Looking up Secrets requires access to a Kubernetes cluster")?;
+
+        // Tera functions are synchronous, so we need to block on the request
+        let value = block_on(cluster.client.get_secret_value(&namespace, &name, &key))?
+            .map_err(|err| format!("Failed to look up Secret {namespace}/{name}: {err}"))?;
+
+        match (value, args.get("default")) {
+            (Some(value), _) => Ok(value.into()),
+            (None, Some(default)) => Ok(default.clone()),
+            (None, None) => {
+                Err(format!("Secret {namespace}/{name} doesn't exist or has no key {key:?}").into())
+            }
+        }
+    }
+}
+
+/// Runs the `future` to completion from within a (synchronous) Tera function.
+/// Blocking requires a multi-threaded runtime, [`block_in_place`] panics when
+/// used on a current-thread runtime.
+fn block_on<F: Future>(future: F) -> tera::Result<F::Output> {
+    let handle = Handle::try_current()
+        .map_err(|err| format!("Accessing the cluster requires a Tokio runtime: {err}"))?;
+
+    if handle.runtime_flavor() != RuntimeFlavor::MultiThread {
+        return Err("Accessing the cluster requires a multi-threaded Tokio runtime".into());
+    }
+
+    Ok(block_in_place(|| handle.block_on(future)))
+}
+
+/// Like [`random_password`], but returns a placeholder instead of a password.
+fn offline_random_password() -> impl Function {
+    |args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let name = get_optional_from_map::<String>(args, "name")?;
+        Ok(password_placeholder(name.as_deref()).into())
+    }
+}
+
+/// Returns the placeholder used instead of generating a password in
+/// read-only renders. Named passwords use the same placeholder every time.
+fn password_placeholder(name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("<{GENERATED_PASSWORDS_SECRET_NAME}/{name}>"),
+        None => "<random-password>".to_string(),
+    }
+}
+
+/// Like [`lookup_secret`], but returns the `default` or a placeholder value
+/// instead of looking up the Secret.
+fn offline_lookup_secret() -> impl Function {
+    |args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let namespace: String = get_from_map(args, "namespace")?;
+        let name: String = get_from_map(args, "name")?;
+        let key: String = get_from_map(args, "key")?;
+
+        Ok(args
+            .get("default")
+            .cloned()
+            .unwrap_or_else(|| format!("<{namespace}/{name}/{key}>").into()))
+    }
+}
+
+fn to_yaml() -> impl Filter {
+    |value: &Value, _args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let yaml = serde_yaml::to_string(value)
+            .map_err(|err| format!("Failed to serialize value to YAML: {err}"))?;
+
+        // Strip the trailing newline, so that the filter can be used inline
+        Ok(yaml.trim_end_matches('\n').into())
+    }
+}
+
+/// Extends the built-in `indent` filter of [`tera`] with the `width` argument
+/// to indent by a number of spaces.
+fn indent() -> impl Filter {
+    |value: &Value, args: &HashMap<String, Value>| -> tera::Result<Value> {
+        let value: String = tera::from_value(value.clone())
+            .map_err(|_| "Filter indent can only be applied to strings")?;
+
+        let prefix = match get_optional_from_map::<usize>(args, "width")? {
+            Some(width) => " ".repeat(width),
+            None => get_optional_from_map(args, "prefix")?.unwrap_or_else(|| " ".repeat(4)),
+        };
+        let first = get_optional_from_map(args, "first")?.unwrap_or(false);
+        let blank = get_optional_from_map(args, "blank")?.unwrap_or(false);
+
+        let indented = value
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                let skip = (index == 0 && !first) || (line.trim().is_empty() && !blank);
+
+                match skip {
+                    true => line.to_string(),
+                    false => format!("{prefix}{line}"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(indented.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("{{ base64_encode(value='admin:admin') }}", "YWRtaW46YWRtaW4=")]
+    #[case("{{ base64_decode(value='YWRtaW46YWRtaW4=') }}", "admin:admin")]
+    #[case(
+        "{{ sha256(value='stackable') }}",
+        "a9d6588562ee0b848327e28eae94f872cd1b01d46377038078165b7be9bf5f5c"
+    )]
+    #[case("{{ user | to_yaml }}", "admin")]
+    #[case(
+        "config:\n  {{ config | indent(width=2) }}",
+        "config:\n  a: 1\n\n  b: 2"
+    )]
+    fn render_functions(#[case] template: &str, #[case] expected: &str) {
+        let parameters = HashMap::from([
+            ("user".to_string(), "admin".to_string()),
+            ("config".to_string(), "a: 1\n\nb: 2".to_string()),
+        ]);
+
+        assert_eq!(render(template, &parameters, None).unwrap(), expected);
+    }
+
+    #[test]
+    fn render_random_and_hash_functions() {
+        let rendered = render(
+            "{{ random_uuid() }} {{ htpasswd(username='admin', password='admin') }}",
+            &HashMap::new(),
+            None,
+        )
+        .unwrap();
+
+        let (uuid, htpasswd) = rendered.split_once(' ').unwrap();
+        assert!(uuid::Uuid::parse_str(uuid).is_ok());
+
+        let (username, hash) = htpasswd.split_once(':').unwrap();
+        assert_eq!(username, "admin");
+        assert!(hash.starts_with("$2y$"));
+        assert!(bcrypt::verify("admin", hash).unwrap());
+    }
+
+    #[test]
+    fn hashes_are_stable() {
+        let template =
+            "{{ bcrypt(password='admin') }} {{ htpasswd(username='admin', password='admin') }}";
+
+        let rendered = render(template, &HashMap::new(), None).unwrap();
+        assert_eq!(render(template, &HashMap::new(), None).unwrap(), rendered);
+
+        let (hash, _) = rendered.split_once(' ').unwrap();
+        assert!(bcrypt::verify("admin", hash).unwrap());
+        assert_ne!(
+            hash,
+            render("{{ bcrypt(password='other') }}", &HashMap::new(), None).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("{{ random_password(name='admin') }}")]
+    #[case("{{ random_password(name='invalid/name') }}")]
+    fn named_random_password_requires_cluster(#[case] template: &str) {
+        assert!(render(template, &HashMap::new(), None).is_err());
+    }
+
+    #[rstest]
+    #[case("1", "30", "1.30")]
+    #[case("1", "29+", "1.29")]
+    fn format_kubernetes_version(#[case] major: &str, #[case] minor: &str, #[case] expected: &str) {
+        assert_eq!(kubernetes_version(major, minor), expected);
+    }
+
+    #[test]
+    fn lookup_secret_requires_client() {
+        let result = render(
+            "{{ lookup_secret(namespace='default', name='admin', key='password') }}",
+            &HashMap::new(),
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn render_offline_without_cluster() {
+        let rendered = render_offline(
+            "{{ cluster.domain }} {{ lookup_secret(namespace='default', name='admin', key='password', default='admin') }}",
+            &HashMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(rendered, "cluster.local admin");
+    }
+
+    #[test]
+    fn render_offline_is_stable() {
+        let template = "{{ random_password() }} {{ random_password(name='admin') }}";
+
+        let rendered = render_offline(template, &HashMap::new()).unwrap();
+        assert_eq!(
+            rendered,
+            "<random-password> <stackablectl-generated-passwords/admin>"
+        );
+        assert_eq!(render_offline(template, &HashMap::new()).unwrap(), rendered);
+    }
+
+    #[rstest]
+    #[case("{{ user }} {{ parameters.password }}", &["password", "user"])]
+    #[case("{{ missing | default(value='x') }}{% if other is defined %}{% endif %}", &[])]
+    #[case("{% set a = b %}{% for x in items %}{{ x.name }}{% endfor %}{{ a }}", &["b", "items"])]
+    #[case("{{ cluster.domain }} {{ random_password(name=prefix ~ '-admin') }}", &["prefix"])]
+    fn referenced_parameters_in_template(#[case] template: &str, #[case] expected: &[&str]) {
+        let referenced = referenced_parameters(template).unwrap();
+        assert_eq!(referenced.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn block_on_multi_thread_runtime() {
+        assert_eq!(block_on(async { 42 }).unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn block_on_current_thread_runtime() {
+        assert!(block_on(async { 42 }).is_err());
     }
 }
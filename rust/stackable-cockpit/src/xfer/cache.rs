@@ -1,19 +1,24 @@
 use std::{
+    collections::HashMap,
     ffi::OsString,
     num::ParseIntError,
     path::{Path, PathBuf},
+    sync::Mutex,
     time::{Duration, SystemTime, SystemTimeError, UNIX_EPOCH},
 };
 
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use snafu::{ResultExt, Snafu};
 use tokio::{fs, io};
 use tracing::debug;
 use url::Url;
+use uuid::Uuid;
 
 use crate::constants::{
-    CACHE_LAST_AUTO_PURGE_FILEPATH, CACHE_PROTECTED_FILES, DEFAULT_AUTO_PURGE_INTERVAL,
-    DEFAULT_CACHE_MAX_AGE,
+    CACHE_LAST_AUTO_PURGE_FILEPATH, CACHE_METADATA_SUFFIX, CACHE_PROTECTED_FILES,
+    CACHE_TEMPORARY_SUFFIX, DEFAULT_AUTO_PURGE_INTERVAL, DEFAULT_CACHE_MAX_AGE,
+    DEFAULT_CACHE_MAX_SIZE, DEFAULT_CACHE_STALE_MAX_AGE,
 };
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -40,6 +45,9 @@ pub enum Error {
 
     #[snafu(display("tried to write file with disabled cache"))]
     WriteDisabled,
+
+    #[snafu(display("failed to serialize cache metadata"))]
+    SerializeMetadata { source: serde_json::Error },
 }
 
 #[derive(Debug)]
@@ -47,13 +55,23 @@ pub struct Cache {
     pub(crate) auto_purge_interval: Duration,
     pub(crate) backend: Backend,
     pub(crate) max_age: Duration,
+    pub(crate) max_size: Option<u64>,
+    pub(crate) stale_max_age: Duration,
+
+    /// Total size of all cached files in bytes. It is determined when the
+    /// first file is stored and tracked afterwards, so that the cache doesn't
+    /// need to be listed on every store.
+    size: Mutex<Option<u64>>,
+
+    /// Entries of the in-memory backend, keyed by their file name
+    memory: Mutex<HashMap<String, MemoryEntry>>,
 }
 
 impl Cache {
     /// Returns wether the cache is enabled.
     pub fn is_enabled(&self) -> bool {
         match self.backend {
-            Backend::Disk { .. } => true,
+            Backend::Disk { .. } | Backend::Memory => true,
             Backend::Disabled => false,
         }
     }
@@ -62,9 +80,10 @@ impl Cache {
     /// the `file_name` should only contain the file name and extension without
     /// any path segments prefixed. The cache internally makes sure the file is
     /// read from within the cache base path. The status is indicated by
-    /// [`Status`]. An error is returned when the cache was unable to read
-    /// data from disk.
-    pub async fn retrieve(&self, file_url: &Url) -> Result<Status<String>> {
+    /// [`Status`]. Expired content is returned together with its [`Metadata`],
+    /// so that it can be revalidated. An error is returned when the cache was
+    /// unable to read data from disk.
+    pub async fn retrieve(&self, file_url: &Url) -> Result<Status<Vec<u8>>> {
         match &self.backend {
             Backend::Disk { base_path } => {
                 let file_path = Self::file_path(base_path, file_url);
@@ -81,34 +100,130 @@ impl Cache {
 
                 let elapsed = modified.elapsed().context(SystemTimeSnafu {})?;
 
+                let content = Self::read(&file_path).await?;
+                let mut sidecar = Self::read_sidecar(&file_path).await;
+
                 if elapsed > self.max_age {
-                    return Ok(Status::Expired);
+                    return Ok(Status::Expired(content, sidecar.metadata));
+                }
+
+                // Failing to record the access only affects statistics and
+                // eviction, so the cached content is used nonetheless
+                sidecar.record_hit();
+                if let Err(err) = Self::write_sidecar(&file_path, &sidecar).await {
+                    debug!("Failed to record access of cached file {file_path:?}: {err}");
                 }
 
-                let content = Self::read(file_path).await?;
                 Ok(Status::Hit(content))
             }
+            Backend::Memory => {
+                let mut memory = self.memory();
+
+                let Some(entry) = memory.get_mut(&Self::file_name(file_url)) else {
+                    return Ok(Status::Miss);
+                };
+
+                let elapsed = entry.stored_at.elapsed().context(SystemTimeSnafu)?;
+
+                if elapsed > self.max_age {
+                    return Ok(Status::Expired(
+                        entry.content.clone(),
+                        entry.sidecar.metadata.clone(),
+                    ));
+                }
+
+                entry.sidecar.record_hit();
+                Ok(Status::Hit(entry.content.clone()))
+            }
             Backend::Disabled => Ok(Status::Miss),
         }
     }
 
     /// Stores `file_content` at the cache base path in a file named `file_name`.
-    /// The method returns an error if the cache fails to write the data to disk
-    /// or the cache is disabled.
-    pub async fn store(&self, file_url: &Url, file_content: &str) -> Result<()> {
-        match &self.backend {
+    /// The `metadata` and the `file_url` are stored alongside the content.
+    /// Storing content again resets its age. If the cache grew larger than its
+    /// maximum size, the least recently used files are evicted. The method
+    /// returns an error if the cache fails to write the data to disk.
+    pub async fn store(
+        &self,
+        file_url: &Url,
+        file_content: &[u8],
+        metadata: &Metadata,
+    ) -> Result<()> {
+        let file_name = Self::file_name(file_url);
+
+        let replaced_size = match &self.backend {
             Backend::Disk { base_path } => {
-                let file_path = Self::file_path(base_path, file_url);
-                Self::write(file_path, file_content).await
+                let file_path = base_path.join(&file_name);
+                let replaced_size = file_path.metadata().map_or(0, |metadata| metadata.len());
+
+                let mut sidecar = Self::read_sidecar(&file_path).await;
+                sidecar.record_miss(file_url, metadata);
+
+                Self::write_sidecar(&file_path, &sidecar).await?;
+                Self::write(&file_path, file_content).await?;
+
+                replaced_size
             }
-            Backend::Disabled => Ok(()),
+            Backend::Memory => {
+                let mut memory = self.memory();
+
+                let (replaced_size, mut sidecar) = memory
+                    .remove(&file_name)
+                    .map(|entry| (entry.content.len() as u64, entry.sidecar))
+                    .unwrap_or_default();
+                sidecar.record_miss(file_url, metadata);
+
+                memory.insert(
+                    file_name.clone(),
+                    MemoryEntry {
+                        content: file_content.to_vec(),
+                        sidecar,
+                        stored_at: SystemTime::now(),
+                    },
+                );
+
+                replaced_size
+            }
+            Backend::Disabled => return Ok(()),
+        };
+
+        let Some(max_size) = self.max_size else {
+            return Ok(());
+        };
+
+        if self
+            .track_size(file_content.len() as u64, replaced_size)
+            .await?
+            > max_size
+        {
+            self.evict(&file_name, max_size).await?;
         }
+
+        Ok(())
+    }
+
+    /// Updates the tracked size of the cache after a file of `stored_size`
+    /// bytes replaced a file of `replaced_size` bytes and returns the new
+    /// size. The size is determined by listing all files if it is not tracked
+    /// yet.
+    async fn track_size(&self, stored_size: u64, replaced_size: u64) -> Result<u64> {
+        if let Some(size) = self.size().as_mut() {
+            *size = (*size + stored_size).saturating_sub(replaced_size);
+            return Ok(*size);
+        }
+
+        // The listed files already include the stored file
+        let size = self.list().await?.iter().map(|entry| entry.size).sum();
+        *self.size() = Some(size);
+
+        Ok(size)
     }
 
     /// Returns a list of currently cached files. This method makes no assumptions
     /// if the cached files are expired. It simply returns a list of files known
     /// by the cache.
-    pub async fn list(&self) -> Result<Vec<(PathBuf, SystemTime)>> {
+    pub async fn list(&self) -> Result<Vec<Entry>> {
         match &self.backend {
             Backend::Disk { base_path } => {
                 let mut files = Vec::new();
@@ -118,23 +233,99 @@ impl Cache {
                 while let Some(entry) = entries.next_entry().await.context(IoReadSnafu)? {
                     let metadata = entry.metadata().await.context(IoMetadataSnafu)?;
 
-                    // Skip protected files
-                    if is_protected_file(entry.file_name()) {
+                    // Skip protected files, metadata of cached files and
+                    // files which are currently written
+                    if is_protected_file(entry.file_name())
+                        || is_metadata_file(&entry.path())
+                        || is_temporary_file(&entry.path())
+                    {
                         continue;
                     }
 
-                    files.push((entry.path(), metadata.modified().context(IoMetadataSnafu)?))
+                    let stored_at = metadata.modified().context(IoMetadataSnafu)?;
+                    let sidecar = Self::read_sidecar(&entry.path()).await;
+
+                    files.push(Entry {
+                        file_name: entry.file_name().to_string_lossy().into_owned(),
+                        path: Some(entry.path()),
+                        url: sidecar.url,
+                        size: metadata.len(),
+                        stored_at,
+                        accessed_at: sidecar
+                            .accessed_at
+                            .map_or(stored_at, |ts| UNIX_EPOCH + Duration::from_millis(ts)),
+                        hits: sidecar.hits,
+                        misses: sidecar.misses,
+                    })
                 }
 
                 Ok(files)
             }
+            Backend::Memory => Ok(self
+                .memory()
+                .iter()
+                .map(|(file_name, entry)| Entry {
+                    file_name: file_name.clone(),
+                    path: None,
+                    url: entry.sidecar.url.clone(),
+                    size: entry.content.len() as u64,
+                    stored_at: entry.stored_at,
+                    accessed_at: entry
+                        .sidecar
+                        .accessed_at
+                        .map_or(entry.stored_at, |ts| UNIX_EPOCH + Duration::from_millis(ts)),
+                    hits: entry.sidecar.hits,
+                    misses: entry.sidecar.misses,
+                })
+                .collect()),
             Backend::Disabled => Ok(vec![]),
         }
     }
 
+    /// Evicts the least recently used files until the cache is no longer
+    /// larger than `max_size`. The file named `keep` is never evicted, so that
+    /// a file which was just stored can be used, even if it is larger than the
+    /// maximum size on its own.
+    async fn evict(&self, keep: &str, max_size: u64) -> Result<()> {
+        let mut entries = self.list().await?;
+        let mut size: u64 = entries.iter().map(|entry| entry.size).sum();
+
+        entries.sort_by_key(|entry| entry.accessed_at);
+
+        for entry in entries {
+            if size <= max_size {
+                break;
+            }
+
+            if entry.file_name == keep {
+                continue;
+            }
+
+            debug!(
+                "Evicting cached file {} to reduce the cache size",
+                entry.url.as_deref().unwrap_or(&entry.file_name)
+            );
+
+            match &entry.path {
+                Some(path) => Self::delete(path).await?,
+                None => {
+                    self.memory().remove(&entry.file_name);
+                }
+            }
+
+            size -= entry.size;
+        }
+
+        *self.size() = Some(size);
+        Ok(())
+    }
+
     /// Removes all cached files by deleting the base cache folder and then
     /// recreating it.
     pub async fn purge(&self, delete_filter: DeleteFilter) -> Result<()> {
+        // The size is determined again on the next store
+        *self.size() = None;
+
         match &self.backend {
             Backend::Disk { base_path } => {
                 let mut entries = fs::read_dir(base_path).await.context(IoReadSnafu)?;
@@ -143,33 +334,52 @@ impl Cache {
                     let metadata = entry.metadata().await.context(IoMetadataSnafu)?;
 
                     let should_delete_file = match delete_filter {
-                        // Skip protected files
+                        // Skip protected files and metadata, which is deleted
+                        // together with the cached file
                         _ if is_protected_file(entry.file_name()) => false,
+                        _ if is_metadata_file(&entry.path()) => false,
 
                         // Without --old / --outdated
                         DeleteFilter::All => true,
                         // with --old/--outdated
-                        DeleteFilter::OnlyExpired => {
+                        DeleteFilter::OnlyExpired | DeleteFilter::OnlyStale => {
                             metadata
                                 .modified()
                                 .context(IoMetadataSnafu)?
                                 .elapsed()
                                 .context(SystemTimeSnafu)?
-                                > self.max_age
+                                > self.filter_max_age(&delete_filter)
                         }
                     };
 
                     if should_delete_file {
-                        fs::remove_file(entry.path()).await.context(IoDeleteSnafu)?;
+                        Self::delete(&entry.path()).await?;
                     }
                 }
 
                 Ok(())
             }
+            Backend::Memory => {
+                let max_age = self.filter_max_age(&delete_filter);
+
+                self.memory().retain(|_, entry| match delete_filter {
+                    DeleteFilter::All => false,
+                    DeleteFilter::OnlyExpired | DeleteFilter::OnlyStale => {
+                        !matches!(entry.stored_at.elapsed(), Ok(elapsed) if elapsed > max_age)
+                    }
+                });
+
+                Ok(())
+            }
             Backend::Disabled => Ok(()),
         }
     }
 
+    /// Deletes stale files, which are older than the stale maximum age, if
+    /// the auto-purge interval elapsed since the last auto-purge. Expired files
+    /// are kept until they are stale, so that they can still be used in
+    /// offline mode or when the remote can't be reached. Size-based eviction
+    /// applies to them nonetheless.
     pub async fn auto_purge(&self) -> Result<()> {
         match &self.backend {
             Backend::Disk { base_path } => {
@@ -191,37 +401,118 @@ impl Cache {
                     .and_then(|ts| ts.elapsed().ok())
                     .map_or(true, |elapsed| elapsed >= self.auto_purge_interval)
                 {
-                    debug!("Auto-purging outdated cache files");
+                    debug!("Auto-purging stale cache files");
 
-                    self.purge(DeleteFilter::OnlyExpired).await?;
+                    self.purge(DeleteFilter::OnlyStale).await?;
                     write_cache_auto_purge_file(&cache_auto_purge_filepath).await?;
                 }
 
                 Ok(())
             }
+            Backend::Memory => self.purge(DeleteFilter::OnlyStale).await,
             Backend::Disabled => Ok(()),
         }
     }
 
-    fn new(backend: Backend, max_age: Duration, auto_purge_interval: Duration) -> Self {
+    /// Returns the age after which files are deleted by the `delete_filter`.
+    fn filter_max_age(&self, delete_filter: &DeleteFilter) -> Duration {
+        match delete_filter {
+            DeleteFilter::All => Duration::ZERO,
+            DeleteFilter::OnlyExpired => self.max_age,
+            DeleteFilter::OnlyStale => self.stale_max_age,
+        }
+    }
+
+    fn new(settings: Settings) -> Self {
         Self {
-            auto_purge_interval,
-            backend,
-            max_age,
+            auto_purge_interval: settings.auto_purge_interval,
+            backend: settings.backend,
+            max_age: settings.max_age,
+            max_size: settings.max_size,
+            stale_max_age: settings.stale_max_age,
+            size: Mutex::default(),
+            memory: Mutex::default(),
         }
     }
 
-    async fn read(file_path: PathBuf) -> Result<String> {
-        fs::read_to_string(file_path).await.context(IoReadSnafu)
+    fn size(&self) -> std::sync::MutexGuard<'_, Option<u64>> {
+        self.size
+            .lock()
+            .expect("cache size lock must not be poisoned")
     }
 
-    async fn write(file_path: PathBuf, file_content: &str) -> Result<()> {
-        fs::write(file_path, file_content)
+    fn memory(&self) -> std::sync::MutexGuard<'_, HashMap<String, MemoryEntry>> {
+        self.memory
+            .lock()
+            .expect("cache memory lock must not be poisoned")
+    }
+
+    async fn read(file_path: &Path) -> Result<Vec<u8>> {
+        fs::read(file_path).await.context(IoReadSnafu)
+    }
+
+    /// Writes `file_content` to a temporary file, which is then renamed to
+    /// `file_path`. Renaming is atomic, so that concurrent processes never
+    /// read partially written files.
+    async fn write(file_path: &Path, file_content: &[u8]) -> Result<()> {
+        let mut temporary_path = file_path.as_os_str().to_owned();
+        temporary_path.push(format!(".{}{CACHE_TEMPORARY_SUFFIX}", Uuid::new_v4()));
+
+        fs::write(&temporary_path, file_content)
             .await
-            .context(IoWriteSnafu {})
+            .context(IoWriteSnafu)?;
+
+        if let Err(err) = fs::rename(&temporary_path, file_path).await {
+            // Best effort, the temporary file is removed by the next purge
+            // otherwise
+            let _ = fs::remove_file(&temporary_path).await;
+            return Err(err).context(IoWriteSnafu);
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the file at `file_path` together with its metadata.
+    async fn delete(file_path: &Path) -> Result<()> {
+        fs::remove_file(file_path).await.context(IoDeleteSnafu)?;
+
+        match fs::remove_file(Self::metadata_path(file_path)).await {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                Err(err).context(IoDeleteSnafu)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reads the metadata of the file at `file_path`. Missing or unreadable
+    /// metadata is treated as empty, which means the file can't be
+    /// revalidated and is downloaded again.
+    async fn read_sidecar(file_path: &Path) -> Sidecar {
+        match fs::read(Self::metadata_path(file_path)).await {
+            Ok(sidecar) => serde_json::from_slice(&sidecar).unwrap_or_else(|err| {
+                debug!("Ignoring invalid cache metadata of {file_path:?}: {err}");
+                Sidecar::default()
+            }),
+            Err(_) => Sidecar::default(),
+        }
+    }
+
+    async fn write_sidecar(file_path: &Path, sidecar: &Sidecar) -> Result<()> {
+        let sidecar = serde_json::to_vec(sidecar).context(SerializeMetadataSnafu)?;
+        Self::write(&Self::metadata_path(file_path), &sidecar).await
+    }
+
+    fn metadata_path(file_path: &Path) -> PathBuf {
+        let mut metadata_path = file_path.as_os_str().to_owned();
+        metadata_path.push(CACHE_METADATA_SUFFIX);
+        metadata_path.into()
     }
 
     fn file_path(base_path: &Path, file_url: &Url) -> PathBuf {
+        base_path.join(Self::file_name(file_url))
+    }
+
+    fn file_name(file_url: &Url) -> String {
         let mut hasher = Sha256::new();
 
         let sanitized_file_name = file_url
@@ -231,21 +522,122 @@ impl Cache {
         hasher.update(file_url.as_str().as_bytes());
         let file_url_hash = hasher.finalize();
 
-        base_path.join(format!("{sanitized_file_name}-{file_url_hash:x}"))
+        format!("{sanitized_file_name}-{file_url_hash:x}")
+    }
+}
+
+/// A cached file stored by the in-memory backend.
+#[derive(Debug)]
+struct MemoryEntry {
+    content: Vec<u8>,
+    sidecar: Sidecar,
+    stored_at: SystemTime,
+}
+
+/// A cached file as returned by [`Cache::list`].
+#[derive(Debug, Clone)]
+pub struct Entry {
+    /// The name of the file, which is derived from the URL
+    pub file_name: String,
+
+    /// The location of the file, which is not set for the in-memory backend
+    pub path: Option<PathBuf>,
+
+    /// The URL the file was retrieved from. This is not known for files
+    /// cached by previous versions.
+    pub url: Option<String>,
+
+    /// The size of the file in bytes
+    pub size: u64,
+
+    /// When the file was last stored, which determines if it is expired
+    pub stored_at: SystemTime,
+
+    /// When the file was last used, which determines the eviction order
+    pub accessed_at: SystemTime,
+
+    /// How often the file was served from the cache
+    pub hits: u64,
+
+    /// How often the file had to be retrieved from the remote
+    pub misses: u64,
+}
+
+/// Data stored alongside each cached file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Sidecar {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+
+    #[serde(default)]
+    hits: u64,
+
+    #[serde(default)]
+    misses: u64,
+
+    /// Milliseconds since the UNIX epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    accessed_at: Option<u64>,
+
+    #[serde(flatten)]
+    metadata: Metadata,
+}
+
+impl Sidecar {
+    fn record_hit(&mut self) {
+        self.hits += 1;
+        self.accessed_at = unix_timestamp_millis();
+    }
+
+    fn record_miss(&mut self, url: &Url, metadata: &Metadata) {
+        self.url = Some(url.to_string());
+        self.misses += 1;
+        self.accessed_at = unix_timestamp_millis();
+        self.metadata = metadata.clone();
     }
 }
 
 pub enum Status<T> {
     Hit(T),
-    Expired,
+
+    /// The cached content is older than the maximum age. It is returned
+    /// together with its [`Metadata`], so that it can be revalidated.
+    Expired(T, Metadata),
+
     Miss,
 }
 
+/// HTTP validators of a cached file, which are stored alongside the file.
+/// They are sent with subsequent requests to revalidate expired files
+/// without downloading them again.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// The value of the `ETag` response header, sent as `If-None-Match`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+
+    /// The value of the `Last-Modified` response header, sent as
+    /// `If-Modified-Since`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Settings {
     pub auto_purge_interval: Duration,
     pub backend: Backend,
     pub max_age: Duration,
+
+    /// The maximum size of all cached files in bytes, `None` disables the
+    /// limit
+    pub max_size: Option<u64>,
+
+    /// The age after which expired files are deleted by the auto-purge. Until
+    /// then, they are used in offline mode or when the remote can't be
+    /// reached.
+    pub stale_max_age: Duration,
 }
 
 impl From<Backend> for Settings {
@@ -253,6 +645,8 @@ impl From<Backend> for Settings {
         Self {
             auto_purge_interval: DEFAULT_AUTO_PURGE_INTERVAL,
             max_age: DEFAULT_CACHE_MAX_AGE,
+            max_size: Some(DEFAULT_CACHE_MAX_SIZE),
+            stale_max_age: DEFAULT_CACHE_STALE_MAX_AGE,
             backend,
         }
     }
@@ -266,6 +660,12 @@ impl Settings {
         .into()
     }
 
+    /// Keeps cached files in memory only. This is useful for long-running
+    /// processes, which should not share cached files with other processes.
+    pub fn memory() -> Self {
+        Backend::Memory.into()
+    }
+
     pub fn disabled() -> Self {
         Backend::Disabled.into()
     }
@@ -278,17 +678,9 @@ impl Settings {
             Backend::Disk { base_path } => {
                 fs::create_dir_all(base_path).await.context(IoWriteSnafu)?;
 
-                Ok(Cache::new(
-                    self.backend,
-                    self.max_age,
-                    self.auto_purge_interval,
-                ))
+                Ok(Cache::new(self))
             }
-            Backend::Disabled => Ok(Cache::new(
-                self.backend,
-                self.max_age,
-                self.auto_purge_interval,
-            )),
+            Backend::Memory | Backend::Disabled => Ok(Cache::new(self)),
         }
     }
 }
@@ -296,12 +688,17 @@ impl Settings {
 #[derive(Debug, Clone)]
 pub enum Backend {
     Disk { base_path: PathBuf },
+    Memory,
     Disabled,
 }
 
 pub enum DeleteFilter {
     All,
     OnlyExpired,
+
+    /// Only files older than the stale maximum age, see
+    /// [`Settings::stale_max_age`]
+    OnlyStale,
 }
 
 async fn write_cache_auto_purge_file(path: &Path) -> Result<()> {
@@ -318,6 +715,23 @@ async fn write_cache_auto_purge_file(path: &Path) -> Result<()> {
     .context(IoWriteSnafu)
 }
 
+fn unix_timestamp_millis() -> Option<u64> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|elapsed| elapsed.as_millis() as u64)
+}
+
+fn is_metadata_file(path: &Path) -> bool {
+    path.to_str()
+        .is_some_and(|path| path.ends_with(CACHE_METADATA_SUFFIX))
+}
+
+fn is_temporary_file(path: &Path) -> bool {
+    path.to_str()
+        .is_some_and(|path| path.ends_with(CACHE_TEMPORARY_SUFFIX))
+}
+
 fn is_protected_file(filename: OsString) -> bool {
     // Non-UTF-8 filenames can't possibly be on the protected list
     let Some(filename) = filename.to_str() else {
@@ -325,3 +739,160 @@ fn is_protected_file(filename: OsString) -> bool {
     };
     CACHE_PROTECTED_FILES.contains(&filename)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::utils::temp_dir::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn store_and_revalidate() {
+        let dir = TempDir::new("cache");
+        let base_path = dir.path();
+        let url = Url::parse("https://example.com/stacks.yaml").unwrap();
+        let metadata = Metadata {
+            etag: Some("\"abc\"".into()),
+            last_modified: None,
+        };
+
+        let mut settings = Settings::disk(base_path);
+        let cache = settings.clone().try_into_cache().await.unwrap();
+        cache.store(&url, b"stacks: {}", &metadata).await.unwrap();
+
+        assert!(
+            matches!(cache.retrieve(&url).await.unwrap(), Status::Hit(content) if content == b"stacks: {}")
+        );
+        assert_eq!(cache.list().await.unwrap().len(), 1);
+
+        // Make sure the file is older than the maximum age
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        settings.max_age = Duration::ZERO;
+        let cache = settings.try_into_cache().await.unwrap();
+
+        match cache.retrieve(&url).await.unwrap() {
+            Status::Expired(content, expired_metadata) => {
+                assert_eq!(content, b"stacks: {}");
+                assert_eq!(expired_metadata, metadata);
+            }
+            _ => panic!("cached file should be expired"),
+        }
+
+        cache.purge(DeleteFilter::All).await.unwrap();
+        assert!(fs::read_dir(base_path)
+            .await
+            .unwrap()
+            .next_entry()
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn auto_purge_keeps_expired_files() {
+        let dir = TempDir::new("cache");
+        let base_path = dir.path();
+        let url = Url::parse("https://example.com/stacks.yaml").unwrap();
+
+        let mut settings = Settings::disk(base_path);
+        settings.max_age = Duration::ZERO;
+        settings.auto_purge_interval = Duration::ZERO;
+
+        let cache = settings.clone().try_into_cache().await.unwrap();
+        cache
+            .store(&url, b"stacks: {}", &Metadata::default())
+            .await
+            .unwrap();
+
+        // Make sure the file is older than the maximum age
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        cache.auto_purge().await.unwrap();
+        assert!(matches!(
+            cache.retrieve(&url).await.unwrap(),
+            Status::Expired(..)
+        ));
+
+        settings.stale_max_age = Duration::ZERO;
+        let cache = settings.try_into_cache().await.unwrap();
+        cache.auto_purge().await.unwrap();
+        assert!(matches!(cache.retrieve(&url).await.unwrap(), Status::Miss));
+    }
+
+    #[tokio::test]
+    async fn memory_backend() {
+        let url = Url::parse("https://example.com/chart.tgz").unwrap();
+        // Not valid UTF-8
+        let content = [0x1f, 0x8b, 0x08, 0xff];
+
+        let mut settings = Settings::memory();
+        let cache = settings.clone().try_into_cache().await.unwrap();
+        assert!(matches!(cache.retrieve(&url).await.unwrap(), Status::Miss));
+
+        cache
+            .store(&url, &content, &Metadata::default())
+            .await
+            .unwrap();
+        assert!(
+            matches!(cache.retrieve(&url).await.unwrap(), Status::Hit(cached) if cached == content)
+        );
+        assert_eq!(cache.list().await.unwrap().len(), 1);
+
+        // Memory caches are not shared, a new cache starts empty
+        settings.max_age = Duration::ZERO;
+        let other_cache = settings.try_into_cache().await.unwrap();
+        assert!(matches!(
+            other_cache.retrieve(&url).await.unwrap(),
+            Status::Miss
+        ));
+
+        cache.purge(DeleteFilter::All).await.unwrap();
+        assert!(cache.list().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn evict_least_recently_used() {
+        let dir = TempDir::new("cache");
+        let base_path = dir.path();
+        let urls: Vec<_> = ["a", "b", "c"]
+            .iter()
+            .map(|name| Url::parse(&format!("https://example.com/{name}.yaml")).unwrap())
+            .collect();
+
+        let mut settings = Settings::disk(base_path);
+        settings.max_size = Some(10);
+        let cache = settings.try_into_cache().await.unwrap();
+
+        for url in &urls[..2] {
+            cache
+                .store(url, b"123456", &Metadata::default())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        // Both files don't fit, so the least recently used one is evicted
+        let entries = cache.list().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].url.as_deref(), Some(urls[1].as_str()));
+
+        assert!(matches!(
+            cache.retrieve(&urls[0]).await.unwrap(),
+            Status::Miss
+        ));
+        assert!(matches!(
+            cache.retrieve(&urls[1]).await.unwrap(),
+            Status::Hit(_)
+        ));
+
+        // The file which was just stored is kept, even if it is used less
+        cache
+            .store(&urls[2], b"123456", &Metadata::default())
+            .await
+            .unwrap();
+
+        let entries = cache.list().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].url.as_deref(), Some(urls[2].as_str()));
+        assert_eq!((entries[0].hits, entries[0].misses), (0, 1));
+    }
+}
@@ -0,0 +1,158 @@
+use std::path::{Path, PathBuf};
+
+use snafu::{ensure, ResultExt, Snafu};
+use tokio::{fs, process::Command};
+use tracing::{debug, instrument};
+
+use crate::{
+    utils::{check::binary_present, path::GitFile},
+    xfer::HttpSettings,
+};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("missing required binary \"git\""))]
+    MissingBinary,
+
+    #[snafu(display("failed to create temporary Git repository"))]
+    CreateRepository { source: std::io::Error },
+
+    #[snafu(display("failed to read CA certificates from {path}", path = path.display()))]
+    ReadCaCert {
+        source: std::io::Error,
+        path: PathBuf,
+    },
+
+    #[snafu(display("failed to write CA certificate bundle"))]
+    WriteCaBundle { source: std::io::Error },
+
+    #[snafu(display("failed to run git command"))]
+    CommandFailedToRun { source: std::io::Error },
+
+    #[snafu(display("failed to successfully run git command ({error})"))]
+    CommandErroredOut { error: String },
+}
+
+/// Retrieves the contents of a single file from a Git repository. Only the
+/// requested reference is fetched, without any history, into a temporary
+/// bare repository which is removed afterwards. The proxy and CA certificate
+/// of the `http_settings` are passed on to git, the CA certificate is trusted
+/// in addition to the system certificates.
+#[instrument]
+pub(super) async fn fetch_file(file: &GitFile, http_settings: &HttpSettings) -> Result<Vec<u8>> {
+    ensure!(binary_present("git"), MissingBinarySnafu);
+
+    let repository_dir =
+        std::env::temp_dir().join(format!("stackable-git-{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&repository_dir)
+        .await
+        .context(CreateRepositorySnafu)?;
+
+    let result = fetch_file_into(file, &repository_dir, http_settings).await;
+
+    if let Err(err) = fs::remove_dir_all(&repository_dir).await {
+        debug!(
+            "Failed to remove temporary Git repository {}: {err}",
+            repository_dir.display()
+        );
+    }
+
+    result
+}
+
+async fn fetch_file_into(
+    file: &GitFile,
+    repository_dir: &Path,
+    http_settings: &HttpSettings,
+) -> Result<Vec<u8>> {
+    debug!("Fetching {} from {}", file.reference, file.repository);
+
+    git(repository_dir, &["init", "--quiet", "--bare"]).await?;
+
+    if let Some(proxy) = &http_settings.proxy {
+        git(repository_dir, &["config", "http.proxy", proxy]).await?;
+    }
+
+    if let Some(ca_cert) = &http_settings.ca_cert {
+        let ca_bundle = write_ca_bundle(repository_dir, ca_cert).await?;
+        let ca_bundle = ca_bundle.to_string_lossy();
+        git(repository_dir, &["config", "http.sslCAInfo", &ca_bundle]).await?;
+    }
+
+    git(
+        repository_dir,
+        &[
+            "fetch",
+            "--quiet",
+            "--depth",
+            "1",
+            // Neither the repository nor the reference must be interpreted
+            // as options
+            "--end-of-options",
+            file.repository.as_str(),
+            &file.reference,
+        ],
+    )
+    .await?;
+
+    git(
+        repository_dir,
+        &["cat-file", "blob", &format!("FETCH_HEAD:{}", file.path)],
+    )
+    .await
+}
+
+/// Writes the system CA certificates followed by the ones in `ca_cert` into a
+/// bundle in `repository_dir`. `http.sslCAInfo` replaces the certificates git
+/// trusts, so the system certificates need to be part of the bundle to trust
+/// the additional certificates on top. If no system bundle can be found, only
+/// the certificates in `ca_cert` are trusted.
+async fn write_ca_bundle(repository_dir: &Path, ca_cert: &Path) -> Result<PathBuf> {
+    let mut bundle = match openssl_probe::probe().cert_file {
+        Some(path) => fs::read(&path).await.context(ReadCaCertSnafu { path })?,
+        None => {
+            debug!(
+                "No system CA certificates found, only trusting {}",
+                ca_cert.display()
+            );
+            Vec::new()
+        }
+    };
+
+    bundle.push(b'\n');
+    bundle.extend(
+        fs::read(ca_cert)
+            .await
+            .context(ReadCaCertSnafu { path: ca_cert })?,
+    );
+
+    let path = repository_dir.join("ca-bundle.pem");
+    fs::write(&path, bundle).await.context(WriteCaBundleSnafu)?;
+
+    Ok(path)
+}
+
+/// Runs git with `args` in `repository_dir` and returns its standard output.
+/// Only the https, http and file protocols are allowed, so that repositories
+/// (or their redirects) can't make git run arbitrary commands.
+async fn git(repository_dir: &Path, args: &[&str]) -> Result<Vec<u8>> {
+    let output = Command::new("git")
+        .env("GIT_ALLOW_PROTOCOL", "https:http:file")
+        .arg("-C")
+        .arg(repository_dir)
+        .args(args)
+        .output()
+        .await
+        .context(CommandFailedToRunSnafu)?;
+
+    ensure!(
+        output.status.success(),
+        CommandErroredOutSnafu {
+            error: String::from_utf8_lossy(&output.stderr).trim()
+        }
+    );
+
+    Ok(output.stdout)
+}
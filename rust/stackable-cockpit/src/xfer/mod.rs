@@ -1,16 +1,30 @@
-use std::path::PathBuf;
+use std::{fmt::Display, future::Future, path::PathBuf, string::FromUtf8Error, time::Duration};
 
-use snafu::{ResultExt, Snafu};
+use reqwest::{
+    header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
+use snafu::{ensure, ResultExt, Snafu};
 use tokio::fs;
+use tracing::{debug, warn};
 use url::Url;
 
 pub mod cache;
 pub mod processor;
 
+mod git;
+mod oci;
+
 use crate::{
-    utils::path::PathOrUrl,
+    constants::{
+        DEFAULT_HTTP_CONNECT_TIMEOUT, DEFAULT_HTTP_READ_TIMEOUT, DEFAULT_HTTP_RETRIES,
+        DEFAULT_HTTP_RETRY_DELAY, HTTP_USER_AGENT,
+    },
+    utils::path::{self, PathOrUrl},
     xfer::{
-        cache::{Cache, Settings, Status},
+        cache::{Cache, Metadata, Settings, Status},
         processor::{Processor, ProcessorError},
     },
 };
@@ -25,6 +39,30 @@ pub enum Error {
         path: PathBuf,
     },
 
+    #[snafu(display("failed to read CA certificate from {path:?}"))]
+    ReadCaCert {
+        source: std::io::Error,
+        path: PathBuf,
+    },
+
+    #[snafu(display("failed to parse CA certificate from {path:?}"))]
+    ParseCaCert {
+        source: reqwest::Error,
+        path: PathBuf,
+    },
+
+    #[snafu(display("no PEM encoded CA certificate found in {path:?}"))]
+    EmptyCaCert { path: PathBuf },
+
+    #[snafu(display("invalid proxy {proxy:?}"))]
+    InvalidProxy {
+        source: reqwest::Error,
+        proxy: String,
+    },
+
+    #[snafu(display("failed to build http client"))]
+    BuildClient { source: reqwest::Error },
+
     #[snafu(display("failed to create cache from provided settings"))]
     CacheSettings { source: cache::Error },
 
@@ -40,14 +78,111 @@ pub enum Error {
     #[snafu(display("failed to retrieve remote file contents"))]
     FetchRemoteContent { source: reqwest::Error },
 
+    #[snafu(display(
+        "remote responded to the unconditional request for {url} with 304 Not Modified"
+    ))]
+    UnexpectedNotModified { url: Url },
+
+    #[snafu(display("failed to retrieve file from Git repository {url}"))]
+    FetchGitFile { source: git::Error, url: Url },
+
+    #[snafu(display("failed to retrieve OCI artifact {url}"))]
+    FetchOciArtifact { source: oci::Error, url: Url },
+
+    #[snafu(display("file contents are not valid UTF-8"))]
+    DecodeFileContent { source: FromUtf8Error },
+
     #[snafu(display("failed to process file contents"))]
     ProcessFileContent { source: ProcessorError },
+
+    #[snafu(display("file from {url} is not cached and can't be retrieved in offline mode"))]
+    NotCachedOffline { url: Url },
+
+    #[snafu(display("failed to serialize value cached as {key}"))]
+    SerializeValue { source: serde_json::Error, key: Url },
+
+    #[snafu(display(
+        "integrity check of {url} failed, expected SHA-256 digest {expected} but got {actual}"
+    ))]
+    IntegrityMismatch {
+        url: Url,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// Settings of the HTTP client, which are used for all remote files, Helm and
+/// OCI indices and OCI artifacts.
+#[derive(Debug, Clone)]
+pub struct HttpSettings {
+    /// Maximum time to establish a connection
+    pub connect_timeout: Duration,
+
+    /// Maximum time to wait for data once the connection is established
+    pub read_timeout: Duration,
+
+    /// How often failed requests are retried. Only transient failures, like
+    /// connection errors and server errors, are retried.
+    pub retries: u32,
+
+    /// Path of a PEM file containing additional CA certificates to trust
+    pub ca_cert: Option<PathBuf>,
+
+    /// Proxy URL used for all requests. If not set, the `HTTPS_PROXY`,
+    /// `HTTP_PROXY` and `NO_PROXY` environment variables are used. Hosts
+    /// listed in `NO_PROXY` are never proxied.
+    pub proxy: Option<String>,
+}
+
+impl Default for HttpSettings {
+    fn default() -> Self {
+        Self {
+            connect_timeout: DEFAULT_HTTP_CONNECT_TIMEOUT,
+            read_timeout: DEFAULT_HTTP_READ_TIMEOUT,
+            retries: DEFAULT_HTTP_RETRIES,
+            ca_cert: None,
+            proxy: None,
+        }
+    }
+}
+
+impl HttpSettings {
+    /// Builds a HTTP client using these settings.
+    fn build_client(&self) -> Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(HTTP_USER_AGENT)
+            .connect_timeout(self.connect_timeout)
+            .read_timeout(self.read_timeout);
+
+        if let Some(path) = &self.ca_cert {
+            let pem = std::fs::read(path).context(ReadCaCertSnafu { path })?;
+            let certs =
+                reqwest::Certificate::from_pem_bundle(&pem).context(ParseCaCertSnafu { path })?;
+            ensure!(!certs.is_empty(), EmptyCaCertSnafu { path });
+
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+
+        if let Some(proxy) = &self.proxy {
+            // Hosts listed in NO_PROXY are still accessed directly
+            let proxy = reqwest::Proxy::all(proxy)
+                .context(InvalidProxySnafu { proxy })?
+                .no_proxy(reqwest::NoProxy::from_env());
+            builder = builder.proxy(proxy);
+        }
+
+        builder.build().context(BuildClientSnafu)
+    }
 }
 
 #[derive(Debug)]
 pub struct Client {
     pub(crate) client: reqwest::Client,
     pub(crate) cache: Cache,
+    pub(crate) offline: bool,
+    pub(crate) http_settings: HttpSettings,
 }
 
 impl Client {
@@ -57,14 +192,76 @@ impl Client {
             .try_into_cache()
             .await
             .context(CacheSettingsSnafu)?;
-        let client = reqwest::Client::new();
 
-        Ok(Self { client, cache })
+        Ok(Self::new_with(cache))
     }
 
     pub fn new_with(cache: Cache) -> Self {
-        let client = reqwest::Client::new();
-        Self { client, cache }
+        // Like reqwest::Client::new, this only fails if the TLS backend can't
+        // be initialized
+        Self::try_new_with(cache, HttpSettings::default()).expect("failed to build http client")
+    }
+
+    /// Creates a new [`Client`] using the `cache` and custom `http_settings`.
+    /// This fails if the CA certificate can't be read or the proxy is invalid.
+    pub fn try_new_with(cache: Cache, http_settings: HttpSettings) -> Result<Self> {
+        Ok(Self {
+            client: http_settings.build_client()?,
+            cache,
+            offline: false,
+            http_settings,
+        })
+    }
+
+    /// Prevents any network access if `offline` is set. Remote files are then
+    /// only served from the cache, even if they are expired.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Sets how often failed requests are retried. Only transient failures,
+    /// like connection errors and server errors, are retried.
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.http_settings.retries = retries;
+        self
+    }
+
+    /// Retrieves a value previously stored using [`Client::store_value`].
+    /// This is used for data which is assembled from multiple remote files,
+    /// like chart indices. Expired values are only returned in offline mode.
+    /// Values which can't be deserialized are treated as missing.
+    pub async fn cached_value<T>(&self, key: &Url) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let content = match self.cache.retrieve(key).await.context(CacheRetrieveSnafu)? {
+            Status::Hit(content) => content,
+            Status::Expired(content, _) if self.offline => content,
+            Status::Expired(..) | Status::Miss => return Ok(None),
+        };
+
+        match serde_json::from_slice(&content) {
+            Ok(value) => Ok(Some(value)),
+            Err(err) => {
+                debug!("Ignoring invalid cached value {key}: {err}");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Stores `value` as JSON in the cache under `key`.
+    pub async fn store_value<T>(&self, key: &Url, value: &T) -> Result<()>
+    where
+        T: Serialize,
+    {
+        let content =
+            serde_json::to_vec(value).context(SerializeValueSnafu { key: key.clone() })?;
+
+        self.cache
+            .store(key, &content, &Metadata::default())
+            .await
+            .context(CacheStoreSnafu)
     }
 
     /// Retrieves data from `path_or_url` which can either be a [`PathBuf`]
@@ -74,54 +271,507 @@ impl Client {
     where
         P: Processor<Input = String>,
     {
+        let content = self.get_bytes(path_or_url).await?;
+        let content = String::from_utf8(content).context(DecodeFileContentSnafu)?;
+
+        processor.process(content).context(ProcessFileContentSnafu)
+    }
+
+    /// Retrieves the raw data from `path_or_url` which can either be a
+    /// [`PathBuf`], a [`Url`], a file in a Git repository or an OCI artifact.
+    /// This should be used for binary data, like chart archives.
+    pub async fn get_bytes(&self, path_or_url: &PathOrUrl) -> Result<Vec<u8>> {
         match path_or_url {
-            PathOrUrl::Path(path) => processor
-                .process(self.get_from_local_file(path).await?)
-                .context(ProcessFileContentSnafu),
-            PathOrUrl::Url(url) => processor
-                .process(self.get_from_cache_or_remote(url).await?)
-                .context(ProcessFileContentSnafu),
+            PathOrUrl::Path(path) => self.get_from_local_file(path).await,
+            PathOrUrl::Url(url) => self.get_from_cache_or_remote(url).await,
+            PathOrUrl::Git(file) => {
+                self.get_from_cache_or_fetch(&file.url, || async {
+                    git::fetch_file(file, &self.http_settings)
+                        .await
+                        .context(FetchGitFileSnafu {
+                            url: file.url.clone(),
+                        })
+                })
+                .await
+            }
+            PathOrUrl::Oci(artifact) => {
+                self.get_from_cache_or_fetch(&artifact.url, || async {
+                    self.retry(&artifact.url, oci::Error::is_transient, || {
+                        oci::fetch_artifact(&self.client, artifact)
+                    })
+                    .await
+                    .context(FetchOciArtifactSnafu {
+                        url: artifact.url.clone(),
+                    })
+                })
+                .await
+            }
         }
     }
 
-    async fn get_from_local_file(&self, path: &PathBuf) -> Result<String> {
-        fs::read_to_string(path)
-            .await
-            .context(ReadLocalFileSnafu { path })
+    async fn get_from_local_file(&self, path: &PathBuf) -> Result<Vec<u8>> {
+        fs::read(path).await.context(ReadLocalFileSnafu { path })
     }
 
     /// Internal method which either looks up the requested file in the cache
     /// or retrieves it from the remote located at `url` when the cache missed
-    /// or is expired.
-    async fn get_from_cache_or_remote(&self, url: &Url) -> Result<String> {
-        match self.cache.retrieve(url).await.context(CacheRetrieveSnafu)? {
-            Status::Hit(content) => Ok(content),
-            Status::Expired | Status::Miss => {
-                let content = self.get_from_remote(url).await?;
-                self.cache
-                    .store(url, &content)
-                    .await
-                    .context(CacheStoreSnafu)?;
+    /// or is expired. Expired files are revalidated using their cached
+    /// metadata, so that unchanged files are not downloaded again. If the
+    /// remote can't be reached, the expired file is used instead. In offline
+    /// mode, the remote is never contacted. If `url` pins the digest of the
+    /// file, both cached and downloaded files are verified against it.
+    async fn get_from_cache_or_remote(&self, url: &Url) -> Result<Vec<u8>> {
+        let status = self.cache.retrieve(url).await.context(CacheRetrieveSnafu)?;
+
+        let (content, metadata) = match status {
+            Status::Hit(content) => return verify_integrity(url, content),
+            Status::Expired(cached_content, _) if self.offline => {
+                debug!("Using expired cached file from {url} in offline mode");
+                return verify_integrity(url, cached_content);
+            }
+            Status::Expired(cached_content, cached_metadata) => {
+                match self.get_from_remote(url, Some(&cached_metadata)).await {
+                    Ok(Remote::Modified(content, metadata)) => (content, metadata),
+                    Ok(Remote::NotModified(metadata)) => {
+                        debug!("Cached file from {url} is still up to date");
+                        (cached_content, metadata.unwrap_or(cached_metadata))
+                    }
+                    Err(err) => {
+                        warn!("Failed to retrieve {url}, using expired cached file instead: {err}");
+                        return verify_integrity(url, cached_content);
+                    }
+                }
+            }
+            Status::Miss if self.offline => {
+                return NotCachedOfflineSnafu { url: url.clone() }.fail()
+            }
+            Status::Miss => match self.get_from_remote(url, None).await? {
+                Remote::Modified(content, metadata) => (content, metadata),
+                // We didn't send any validators, so there is no cached file a
+                // 304 Not Modified could refer to
+                Remote::NotModified(_) => {
+                    return UnexpectedNotModifiedSnafu { url: url.clone() }.fail()
+                }
+            },
+        };
+
+        // Verify before storing, so that tampered files never end up in the
+        // cache
+        let content = verify_integrity(url, content)?;
+
+        self.cache
+            .store(url, &content, &metadata)
+            .await
+            .context(CacheStoreSnafu)?;
+
+        Ok(content)
+    }
+
+    /// Internal method which looks up the file cached under `key` or
+    /// retrieves it using `fetch`, for sources which can't be revalidated
+    /// like Git repositories and OCI registries. Like remote files, expired
+    /// files are used if `fetch` fails or in offline mode.
+    async fn get_from_cache_or_fetch<F, Fut>(&self, key: &Url, fetch: F) -> Result<Vec<u8>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<u8>>>,
+    {
+        let content = match self.cache.retrieve(key).await.context(CacheRetrieveSnafu)? {
+            Status::Hit(content) => return Ok(content),
+            Status::Expired(cached_content, _) if self.offline => {
+                debug!("Using expired cached file from {key} in offline mode");
+                return Ok(cached_content);
+            }
+            Status::Expired(cached_content, _) => match fetch().await {
+                Ok(content) => content,
+                Err(err) => {
+                    warn!("Failed to retrieve {key}, using expired cached file instead: {err}");
+                    return Ok(cached_content);
+                }
+            },
+            Status::Miss if self.offline => {
+                return NotCachedOfflineSnafu { url: key.clone() }.fail()
+            }
+            Status::Miss => fetch().await?,
+        };
+
+        self.cache
+            .store(key, &content, &Metadata::default())
+            .await
+            .context(CacheStoreSnafu)?;
+
+        Ok(content)
+    }
+
+    /// Internal call which executes a HTTP GET request to `url`, which is
+    /// retried on transient failures.
+    async fn get_from_remote(&self, url: &Url, validators: Option<&Metadata>) -> Result<Remote> {
+        self.retry(
+            url,
+            |err| matches!(err, Error::FetchRemoteContent { source } if is_transient(source)),
+            || self.try_get_from_remote(url, validators),
+        )
+        .await
+    }
 
-                Ok(content)
+    /// Runs `request` for `url` until it succeeds, fails with an error which
+    /// is not `transient` or all retries are used up. The delay between
+    /// retries doubles with every attempt.
+    async fn retry<T, E, F, Fut>(
+        &self,
+        url: &Url,
+        transient: impl Fn(&E) -> bool,
+        mut request: F,
+    ) -> Result<T, E>
+    where
+        E: Display,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let retries = self.http_settings.retries;
+        let mut attempt = 0;
+
+        loop {
+            match request().await {
+                Err(err) if attempt < retries && transient(&err) => {
+                    let delay = DEFAULT_HTTP_RETRY_DELAY * 2u32.pow(attempt);
+                    attempt += 1;
+
+                    debug!(
+                        "Failed to retrieve {url}, retrying in {delay:?} ({attempt}/{retries}): {err}"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
             }
         }
     }
 
-    /// Internal call which executes a HTTP GET request to `url`.
-    async fn get_from_remote(&self, url: &Url) -> Result<String> {
-        let req = self
-            .client
-            .get(url.clone())
-            .build()
-            .context(BuildRequestSnafu)?;
+    /// Executes a single HTTP GET request to `url`. If `validators` are
+    /// provided, the request is conditional and the remote can respond with
+    /// 304 Not Modified.
+    async fn try_get_from_remote(
+        &self,
+        url: &Url,
+        validators: Option<&Metadata>,
+    ) -> Result<Remote> {
+        let mut req = self.client.get(url.clone());
+
+        if let Some(validators) = validators {
+            if let Some(etag) = &validators.etag {
+                req = req.header(IF_NONE_MATCH, etag);
+            }
+
+            if let Some(last_modified) = &validators.last_modified {
+                req = req.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let req = req.build().context(BuildRequestSnafu)?;
 
         let result = self
             .client
             .execute(req)
             .await
+            .and_then(|result| result.error_for_status())
             .context(FetchRemoteContentSnafu)?;
 
-        result.text().await.context(FetchRemoteContentSnafu)
+        let metadata = metadata_from_headers(result.headers());
+
+        if result.status() == StatusCode::NOT_MODIFIED {
+            let has_validators = metadata.etag.is_some() || metadata.last_modified.is_some();
+            return Ok(Remote::NotModified(has_validators.then_some(metadata)));
+        }
+
+        let content = result.bytes().await.context(FetchRemoteContentSnafu)?;
+        Ok(Remote::Modified(content.to_vec(), metadata))
+    }
+}
+
+/// The response of a (conditional) request to a remote file.
+enum Remote {
+    /// The file was (re-)downloaded
+    Modified(Vec<u8>, Metadata),
+
+    /// The cached file is still up to date. The remote can send updated
+    /// metadata.
+    NotModified(Option<Metadata>),
+}
+
+/// Returns if the request failed because of a transient failure, which can
+/// resolve itself when retrying the request.
+pub(crate) fn is_transient(err: &reqwest::Error) -> bool {
+    if err.is_connect() || err.is_timeout() {
+        return true;
+    }
+
+    err.status()
+        .is_some_and(|status| status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS)
+}
+
+/// Extracts the validators used to revalidate cached files from the response
+/// `headers`.
+fn metadata_from_headers(headers: &HeaderMap) -> Metadata {
+    let header = |name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string)
+    };
+
+    Metadata {
+        etag: header(ETAG),
+        last_modified: header(LAST_MODIFIED),
+    }
+}
+
+/// Checks that the SHA-256 digest of `content` matches the digest pinned by
+/// the `#sha256=<hex>` suffix of `url`. URLs without such a suffix are not
+/// verified.
+fn verify_integrity(url: &Url, content: Vec<u8>) -> Result<Vec<u8>> {
+    if let Some(expected) = path::integrity(url) {
+        let actual = format!("{:x}", Sha256::digest(&content));
+        ensure!(
+            actual == expected,
+            IntegrityMismatchSnafu {
+                url: url.clone(),
+                expected,
+                actual,
+            }
+        );
+    }
+
+    Ok(content)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use crate::{utils::temp_dir::TempDir, xfer::processor::Text};
+
+    use super::*;
+
+    /// Answers a single request to the returned URL with `response` and
+    /// returns the received request.
+    async fn serve_once(response: &'static str) -> (Url, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!(
+            "http://{}/stacks.yaml",
+            listener.local_addr().unwrap()
+        ))
+        .unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buf).await.unwrap();
+                assert!(
+                    read > 0,
+                    "connection closed before the request was complete"
+                );
+                request.extend_from_slice(&buf[..read]);
+            }
+
+            stream.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        (url, server)
+    }
+
+    #[tokio::test]
+    async fn revalidate_expired_file() {
+        let dir = TempDir::new("xfer");
+        let (url, server) = serve_once(
+            "HTTP/1.1 304 Not Modified\r\nETag: \"v2\"\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        )
+        .await;
+
+        let mut settings = Settings::disk(dir.path());
+        settings.max_age = Duration::ZERO;
+
+        let cache = settings.clone().try_into_cache().await.unwrap();
+        let metadata = Metadata {
+            etag: Some("\"v1\"".into()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".into()),
+        };
+        cache.store(&url, b"stacks: {}", &metadata).await.unwrap();
+
+        // Make sure the file is older than the maximum age
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let client = Client::new_with(cache).retries(0);
+        let content = client.get(&PathOrUrl::Url(url.clone()), &Text).await;
+        assert_eq!(content.unwrap(), "stacks: {}");
+
+        let request = server.await.unwrap().to_lowercase();
+        assert!(
+            request.contains("\r\nif-none-match: \"v1\"\r\n"),
+            "{request}"
+        );
+        assert!(
+            request.contains("\r\nif-modified-since: wed, 21 oct 2015 07:28:00 gmt\r\n"),
+            "{request}"
+        );
+
+        // The cached file is kept, but its metadata is refreshed
+        let cache = settings.try_into_cache().await.unwrap();
+        match cache.retrieve(&url).await.unwrap() {
+            Status::Expired(content, metadata) => {
+                assert_eq!(content, b"stacks: {}");
+                assert_eq!(metadata.etag.as_deref(), Some("\"v2\""));
+            }
+            _ => panic!("cached file should be expired"),
+        }
+    }
+
+    #[tokio::test]
+    async fn expired_fallback_and_offline() {
+        let dir = TempDir::new("xfer");
+        let base_path = dir.path();
+
+        // Nothing listens on this port, so any request fails immediately
+        let url = Url::parse("http://127.0.0.1:1/stacks.yaml").unwrap();
+        let missing_url = Url::parse("http://127.0.0.1:1/demos.yaml").unwrap();
+
+        let mut settings = Settings::disk(base_path);
+        settings.max_age = Duration::ZERO;
+
+        let cache = settings.try_into_cache().await.unwrap();
+        cache
+            .store(&url, b"stacks: {}", &Metadata::default())
+            .await
+            .unwrap();
+
+        // Make sure the file is older than the maximum age
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let client = Client::new_with(cache).retries(0);
+        let content = client.get(&PathOrUrl::Url(url.clone()), &Text).await;
+        assert_eq!(content.unwrap(), "stacks: {}");
+
+        let client = client.offline(true);
+        let content = client.get(&PathOrUrl::Url(url), &Text).await;
+        assert_eq!(content.unwrap(), "stacks: {}");
+
+        let result = client.get(&PathOrUrl::Url(missing_url), &Text).await;
+        assert!(matches!(result, Err(Error::NotCachedOffline { .. })));
+    }
+
+    #[tokio::test]
+    async fn store_and_retrieve_value() {
+        let dir = TempDir::new("xfer");
+        let base_path = dir.path();
+        let key = Url::parse("https://example.com/index.yaml#chart-source-metadata").unwrap();
+
+        let cache = Settings::disk(base_path).try_into_cache().await.unwrap();
+        let client = Client::new_with(cache);
+
+        let value: Option<Vec<String>> = client.cached_value(&key).await.unwrap();
+        assert!(value.is_none());
+
+        let versions = vec!["24.11.0".to_string(), "24.11.1".to_string()];
+        client.store_value(&key, &versions).await.unwrap();
+
+        let value: Option<Vec<String>> = client.cached_value(&key).await.unwrap();
+        assert_eq!(value, Some(versions));
+
+        // Values of a different type are treated as missing
+        let value: Option<u32> = client.cached_value(&key).await.unwrap();
+        assert!(value.is_none());
+    }
+
+    #[tokio::test]
+    async fn verify_pinned_integrity() {
+        let dir = TempDir::new("xfer");
+        let base_path = dir.path();
+
+        let digest = format!("{:x}", Sha256::digest(b"stacks: {}"));
+        let url = Url::parse(&format!("http://127.0.0.1:1/stacks.yaml#sha256={digest}")).unwrap();
+        let tampered_url =
+            Url::parse(&format!("http://127.0.0.1:1/demos.yaml#sha256={digest}")).unwrap();
+
+        let cache = Settings::disk(base_path).try_into_cache().await.unwrap();
+        cache
+            .store(&url, b"stacks: {}", &Metadata::default())
+            .await
+            .unwrap();
+        cache
+            .store(&tampered_url, b"demos: {}", &Metadata::default())
+            .await
+            .unwrap();
+
+        let client = Client::new_with(cache).retries(0);
+        let content = client.get(&PathOrUrl::Url(url), &Text).await;
+        assert_eq!(content.unwrap(), "stacks: {}");
+
+        let result = client.get(&PathOrUrl::Url(tampered_url), &Text).await;
+        assert!(matches!(result, Err(Error::IntegrityMismatch { .. })));
+    }
+
+    #[tokio::test]
+    async fn get_file_from_git_repository() {
+        if !crate::utils::check::binary_present("git") {
+            return;
+        }
+
+        let dir = TempDir::new("xfer");
+        let base_path = dir.path();
+        let work_dir = base_path.join("work");
+        let repository_dir = base_path.join("demos.git");
+        fs::create_dir_all(work_dir.join("stacks")).await.unwrap();
+        fs::write(work_dir.join("stacks/stacks-v2.yaml"), "stacks: {}")
+            .await
+            .unwrap();
+
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(["-C", work_dir.to_str().unwrap()])
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "{output:?}");
+        };
+
+        git(&["init", "--quiet", "--initial-branch", "main"]);
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "--message", "Add stacks"]);
+        git(&[
+            "clone",
+            "--quiet",
+            "--bare",
+            ".",
+            repository_dir.to_str().unwrap(),
+        ]);
+
+        let path_or_url: PathOrUrl = format!(
+            "git+file://{}#main:stacks/stacks-v2.yaml",
+            repository_dir.display()
+        )
+        .parse()
+        .unwrap();
+
+        let cache = Settings::disk(base_path.join("cache"))
+            .try_into_cache()
+            .await
+            .unwrap();
+        let client = Client::new_with(cache);
+        let content = client.get(&path_or_url, &Text).await;
+        assert_eq!(content.unwrap(), "stacks: {}");
+
+        // The file is now served from the cache
+        let client = client.offline(true);
+        let content = client.get(&path_or_url, &Text).await;
+        assert_eq!(content.unwrap(), "stacks: {}");
     }
 }
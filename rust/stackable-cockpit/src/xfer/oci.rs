@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+use reqwest::{
+    header::{ACCEPT, AUTHORIZATION, WWW_AUTHENTICATE},
+    RequestBuilder, Response, StatusCode,
+};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use snafu::{ensure, OptionExt, ResultExt, Snafu};
+use tracing::{debug, instrument};
+use url::Url;
+
+use crate::utils::path::OciArtifact;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The media types of manifests which are accepted from the registry.
+const MANIFEST_MEDIA_TYPES: &str =
+    "application/vnd.oci.image.manifest.v1+json, application/vnd.docker.distribution.manifest.v2+json";
+
+/// The annotation which contains the file name of a layer, as set by tools
+/// like ORAS.
+const TITLE_ANNOTATION: &str = "org.opencontainers.image.title";
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("failed to construct registry URL"))]
+    BuildUrl { source: url::ParseError },
+
+    #[snafu(display("failed to retrieve artifact manifest"))]
+    FetchManifest { source: reqwest::Error },
+
+    #[snafu(display("failed to retrieve registry token"))]
+    FetchToken { source: reqwest::Error },
+
+    #[snafu(display("registry requires unsupported authentication {challenge:?}"))]
+    UnsupportedAuthentication { challenge: String },
+
+    #[snafu(display("registry token response doesn't contain a token"))]
+    MissingToken,
+
+    #[snafu(display("artifact doesn't contain any layers"))]
+    NoLayers,
+
+    #[snafu(display(
+        "artifact contains multiple layers, select one using the #<file> suffix ({files})"
+    ))]
+    AmbiguousLayer { files: String },
+
+    #[snafu(display("artifact doesn't contain a layer titled {file:?}"))]
+    NoSuchLayer { file: String },
+
+    #[snafu(display("failed to retrieve artifact layer"))]
+    FetchBlob { source: reqwest::Error },
+
+    #[snafu(display("unsupported layer digest {digest:?}, only sha256 is supported"))]
+    UnsupportedDigest { digest: String },
+
+    #[snafu(display("layer digest mismatch, expected {expected} but got {actual}"))]
+    DigestMismatch { expected: String, actual: String },
+}
+
+impl Error {
+    /// Returns if the request failed because of a transient failure, which
+    /// can resolve itself when retrying the request.
+    pub(super) fn is_transient(&self) -> bool {
+        match self {
+            Error::FetchManifest { source }
+            | Error::FetchToken { source }
+            | Error::FetchBlob { source } => super::is_transient(source),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    layers: Vec<Descriptor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Descriptor {
+    digest: String,
+
+    #[serde(default)]
+    annotations: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+/// Retrieves a single layer of an OCI artifact from its registry, using the
+/// anonymous token flow if the registry requires it. The content of the layer
+/// is verified against its digest.
+#[instrument(skip(client))]
+pub(super) async fn fetch_artifact(
+    client: &reqwest::Client,
+    artifact: &OciArtifact,
+) -> Result<Vec<u8>> {
+    // Like most container tooling, registries on localhost are accessed
+    // without TLS
+    let scheme = match artifact.registry.split(':').next() {
+        Some("localhost" | "127.0.0.1") => "http",
+        _ => "https",
+    };
+    let base_url = Url::parse(&format!(
+        "{scheme}://{registry}/v2/{repository}/",
+        registry = artifact.registry,
+        repository = artifact.repository
+    ))
+    .context(BuildUrlSnafu)?;
+
+    let manifest_url = base_url
+        .join(&format!("manifests/{}", artifact.reference))
+        .context(BuildUrlSnafu)?;
+
+    debug!("Fetching OCI manifest from {manifest_url}");
+    let response = client
+        .get(manifest_url.clone())
+        .header(ACCEPT, MANIFEST_MEDIA_TYPES)
+        .send()
+        .await
+        .context(FetchManifestSnafu)?;
+
+    let token = match response.status() {
+        StatusCode::UNAUTHORIZED => Some(fetch_token(client, artifact, &response).await?),
+        _ => None,
+    };
+
+    let authorize = |request: RequestBuilder| match &token {
+        Some(token) => request.header(AUTHORIZATION, format!("Bearer {token}")),
+        None => request,
+    };
+
+    let response = match &token {
+        Some(_) => authorize(
+            client
+                .get(manifest_url)
+                .header(ACCEPT, MANIFEST_MEDIA_TYPES),
+        )
+        .send()
+        .await
+        .context(FetchManifestSnafu)?,
+        None => response,
+    };
+
+    let manifest: Manifest = response
+        .error_for_status()
+        .context(FetchManifestSnafu)?
+        .json()
+        .await
+        .context(FetchManifestSnafu)?;
+
+    let layer = select_layer(&manifest, artifact.file.as_deref())?;
+    let expected = layer
+        .digest
+        .strip_prefix("sha256:")
+        .context(UnsupportedDigestSnafu {
+            digest: &layer.digest,
+        })?;
+
+    let blob_url = base_url
+        .join(&format!("blobs/{}", layer.digest))
+        .context(BuildUrlSnafu)?;
+
+    debug!("Fetching OCI layer from {blob_url}");
+    let content = authorize(client.get(blob_url))
+        .send()
+        .await
+        .and_then(Response::error_for_status)
+        .context(FetchBlobSnafu)?
+        .bytes()
+        .await
+        .context(FetchBlobSnafu)?;
+
+    let actual = format!("{:x}", Sha256::digest(&content));
+    ensure!(actual == expected, DigestMismatchSnafu { expected, actual });
+
+    Ok(content.to_vec())
+}
+
+/// Requests an anonymous pull token as described by the `WWW-Authenticate`
+/// challenge of the `unauthorized` response.
+async fn fetch_token(
+    client: &reqwest::Client,
+    artifact: &OciArtifact,
+    unauthorized: &Response,
+) -> Result<String> {
+    let challenge = unauthorized
+        .headers()
+        .get(WWW_AUTHENTICATE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    let params =
+        parse_challenge(challenge).context(UnsupportedAuthenticationSnafu { challenge })?;
+    let realm = params
+        .get("realm")
+        .context(UnsupportedAuthenticationSnafu { challenge })?;
+
+    let mut token_url = Url::parse(realm).context(BuildUrlSnafu)?;
+    {
+        let mut query = token_url.query_pairs_mut();
+
+        if let Some(service) = params.get("service") {
+            query.append_pair("service", service);
+        }
+
+        match params.get("scope") {
+            Some(scope) => query.append_pair("scope", scope),
+            None => query.append_pair("scope", &format!("repository:{}:pull", artifact.repository)),
+        };
+    }
+
+    let response: TokenResponse = client
+        .get(token_url)
+        .send()
+        .await
+        .and_then(Response::error_for_status)
+        .context(FetchTokenSnafu)?
+        .json()
+        .await
+        .context(FetchTokenSnafu)?;
+
+    response
+        .token
+        .or(response.access_token)
+        .context(MissingTokenSnafu)
+}
+
+/// Parses the parameters of a `Bearer` challenge, e.g.
+/// `Bearer realm="https://oci.stackable.tech/service/token",service="harbor-registry"`.
+fn parse_challenge(challenge: &str) -> Option<HashMap<String, String>> {
+    let mut rest = challenge.strip_prefix("Bearer ")?.trim();
+    let mut params = HashMap::new();
+
+    while !rest.is_empty() {
+        let (key, value) = rest.split_once('=')?;
+        let value = value.trim_start();
+
+        let (value, remainder) = match value.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"')?,
+            None => value.split_once(',').unwrap_or((value, "")),
+        };
+
+        params.insert(key.trim().to_ascii_lowercase(), value.to_string());
+        rest = remainder.trim_start().trim_start_matches(',').trim_start();
+    }
+
+    Some(params)
+}
+
+/// Selects the layer titled `file`, or the only layer of the `manifest` if no
+/// file is requested.
+fn select_layer<'a>(manifest: &'a Manifest, file: Option<&str>) -> Result<&'a Descriptor> {
+    match file {
+        Some(file) => manifest
+            .layers
+            .iter()
+            .find(|layer| layer.annotations.get(TITLE_ANNOTATION).map(String::as_str) == Some(file))
+            .context(NoSuchLayerSnafu { file }),
+        None => match manifest.layers.as_slice() {
+            [] => NoLayersSnafu.fail(),
+            [layer] => Ok(layer),
+            layers => AmbiguousLayerSnafu {
+                files: layers
+                    .iter()
+                    .filter_map(|layer| layer.annotations.get(TITLE_ANNOTATION))
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            }
+            .fail(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_bearer_challenge() {
+        let params = parse_challenge(
+            r#"Bearer realm="https://oci.stackable.tech/service/token",service="harbor-registry",scope="repository:sdp/stacks:pull,push""#,
+        )
+        .unwrap();
+
+        assert_eq!(params["realm"], "https://oci.stackable.tech/service/token");
+        assert_eq!(params["service"], "harbor-registry");
+        assert_eq!(params["scope"], "repository:sdp/stacks:pull,push");
+
+        assert!(parse_challenge(r#"Basic realm="registry""#).is_none());
+    }
+}
@@ -1,9 +1,18 @@
-use std::{collections::HashMap, marker::PhantomData};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    marker::PhantomData,
+    sync::Arc,
+};
 
 use serde::de::DeserializeOwned;
 use snafu::{ResultExt, Snafu};
+use stackable_operator::kvp::Labels;
 
-use crate::utils::templating;
+use crate::utils::{
+    k8s::Client,
+    templating::{self, ClusterAccess, ClusterFacts, GeneratedPasswords},
+};
 
 pub type Result<T, E = ProcessorError> = std::result::Result<T, E>;
 
@@ -12,6 +21,9 @@ pub enum ProcessorError {
     #[snafu(display("failed to deserialize YAML content"))]
     DeserializeYaml { source: serde_yaml::Error },
 
+    #[snafu(display("failed to deserialize JSON content"))]
+    DeserializeJson { source: serde_json::Error },
+
     #[snafu(display("failed to render templated content"))]
     RenderTemplate { source: tera::Error },
 }
@@ -87,21 +99,112 @@ impl<T> Yaml<T> {
     }
 }
 
-/// Process the contents by rendering templated parts of the contents.
+/// Process the contents by parsing it as JSON.
 #[derive(Debug)]
-pub struct Template<'a>(&'a HashMap<String, String>);
+pub struct Json<T>(PhantomData<T>);
+
+impl<T> Processor for Json<T>
+where
+    T: DeserializeOwned,
+{
+    type Input = String;
+    type Output = T;
+
+    fn process(&self, input: Self::Input) -> Result<Self::Output> {
+        serde_json::from_str(&input).context(DeserializeJsonSnafu)
+    }
+}
+
+impl<T> Default for Json<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Json<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Process the contents by rendering templated parts of the contents.
+/// Template functions which access the cluster, like `lookup_secret`, are
+/// only available if access is provided using [`Template::with_cluster`].
+#[derive(Clone)]
+pub struct Template<'a> {
+    parameters: &'a HashMap<String, String>,
+    cluster: Option<ClusterAccess>,
+    offline: bool,
+}
+
+impl Debug for Template<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Template")
+            .field("parameters", &self.parameters)
+            .finish_non_exhaustive()
+    }
+}
 
 impl Processor for Template<'_> {
     type Input = String;
     type Output = String;
 
     fn process(&self, input: Self::Input) -> Result<Self::Output> {
-        templating::render(&input, self.0).context(RenderTemplateSnafu)
+        match self.offline {
+            true => templating::render_offline(&input, self.parameters),
+            false => templating::render(&input, self.parameters, self.cluster.as_ref()),
+        }
+        .context(RenderTemplateSnafu)
     }
 }
 
 impl<'a> Template<'a> {
     pub fn new(parameters: &'a HashMap<String, String>) -> Self {
-        Self(parameters)
+        Self {
+            parameters,
+            cluster: None,
+            offline: false,
+        }
+    }
+
+    /// Provides access to the cluster using `client`. The `facts` are
+    /// available as the `cluster` object and generated values are stored in
+    /// the product namespace of the `facts`.
+    pub fn with_cluster(mut self, client: &Client, facts: ClusterFacts) -> Self {
+        self.cluster = Some(ClusterAccess {
+            client: client.clone(),
+            facts,
+            read_only: false,
+            labels: BTreeMap::new(),
+            passwords: Arc::default(),
+        });
+        self
+    }
+
+    /// Adds the `labels` to the Secret storing named passwords if it gets
+    /// created. The named passwords used and stored are tracked in
+    /// `passwords`.
+    pub fn with_labels(mut self, labels: &Labels, passwords: Arc<GeneratedPasswords>) -> Self {
+        if let Some(cluster) = &mut self.cluster {
+            cluster.labels = labels.clone().into();
+            cluster.passwords = passwords;
+        }
+        self
+    }
+
+    /// Prevents storing generated values in the cluster. This should be used
+    /// when manifests are only rendered, but not installed.
+    pub fn read_only(mut self) -> Self {
+        if let Some(cluster) = &mut self.cluster {
+            cluster.read_only = true;
+        }
+        self
+    }
+
+    /// Renders without access to a cluster, using placeholder facts and
+    /// values instead. See [`templating::render_offline`].
+    pub fn offline(mut self) -> Self {
+        self.offline = true;
+        self
     }
 }
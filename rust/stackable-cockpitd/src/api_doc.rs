@@ -1,5 +1,5 @@
 use stackable_cockpit::{
-    common::manifest::ManifestSpec,
+    common::manifest::{ManifestSource, ManifestSpec, WaitCondition, WaitTarget},
     platform::{
         cluster::ResourceRequests, demo, product::ProductSpec, release, stacklet::Stacklet,
     },
@@ -32,7 +32,8 @@ use crate::{
         middleware::authentication::log_in,
     ),
     components(schemas(
-        demo::DemoSpec, ManifestSpec, Parameter, release::ReleaseSpec,
+        demo::DemoSpec, ManifestSpec, ManifestSource, WaitCondition, WaitTarget,
+        Parameter, release::ReleaseSpec,
         Stacklet, DisplayCondition, synthetic_types::ObjectMeta,
         Session, SessionToken, ResourceRequests, ProductSpec
     ))
@@ -1,6 +1,12 @@
-use axum::{extract::Path, routing::get, Json, Router};
+use axum::{
+    extract::{Path, Query},
+    routing::get,
+    Json, Router,
+};
 use stackable_cockpit::platform::demo::DemoSpec;
 
+use crate::handlers::ListQuery;
+
 /// Creates the demo sub-router.
 pub fn router() -> Router {
     Router::new()
@@ -8,12 +14,12 @@ pub fn router() -> Router {
         .route("/:name", get(get_demo))
 }
 
-/// Retrieves all demos.
-#[utoipa::path(get, path = "/demos", responses(
+/// Retrieves all demos matching the optional filter `query`.
+#[utoipa::path(get, path = "/demos", params(ListQuery), responses(
     (status = 200, description = "Retrieving a list of demos succeeded", body = [DemoSpecV2]),
     (status = 404, description = "Retrieving a list of demos failed")
 ))]
-pub async fn get_demos() -> Json<Vec<DemoSpec>> {
+pub async fn get_demos(Query(_query): Query<ListQuery>) -> Json<Vec<DemoSpec>> {
     todo!()
 }
 
@@ -1,3 +1,7 @@
+use serde::Deserialize;
+use stackable_cockpit::common::list::ListFilter;
+use utoipa::IntoParams;
+
 pub mod demos;
 pub mod releases;
 pub mod root;
@@ -9,3 +13,36 @@ pub mod ui;
 #[cfg(not(feature = "ui"))]
 #[path = "ui_disabled.rs"]
 pub mod ui;
+
+/// Query parameters to filter the lists of demos, stacks and releases.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListQuery {
+    /// Comma-separated list of labels the entries must have
+    label: Option<String>,
+
+    /// Text the name or description of the entries must contain
+    search: Option<String>,
+
+    /// Release the entries must belong to, e.g. `24.11` or `24`
+    release: Option<String>,
+}
+
+impl From<ListQuery> for ListFilter {
+    fn from(query: ListQuery) -> Self {
+        let labels = query
+            .label
+            .iter()
+            .flat_map(|labels| labels.split(','))
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(ToString::to_string)
+            .collect();
+
+        Self {
+            labels,
+            search: query.search,
+            release: query.release,
+        }
+    }
+}
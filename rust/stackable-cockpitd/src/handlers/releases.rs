@@ -1,6 +1,12 @@
-use axum::{extract::Path, routing::get, Json, Router};
+use axum::{
+    extract::{Path, Query},
+    routing::get,
+    Json, Router,
+};
 use stackable_cockpit::platform::release::ReleaseSpec;
 
+use crate::handlers::ListQuery;
+
 /// Creates the release sub-router.
 pub fn router() -> Router {
     Router::new()
@@ -8,12 +14,12 @@ pub fn router() -> Router {
         .route("/:name", get(get_release))
 }
 
-/// Retrieves all releases.
-#[utoipa::path(get, path = "/releases", responses(
+/// Retrieves all releases matching the optional filter `query`.
+#[utoipa::path(get, path = "/releases", params(ListQuery), responses(
     (status = 200, description = "Retrieving a list of releases succeeded", body = [ReleaseSpec]),
     (status = 404, description = "Retrieving a list of releases failed")
 ))]
-pub async fn get_releases() -> Json<Vec<ReleaseSpec>> {
+pub async fn get_releases(Query(_query): Query<ListQuery>) -> Json<Vec<ReleaseSpec>> {
     todo!()
 }
 
@@ -1,6 +1,12 @@
-use axum::{extract::Path, routing::get, Json, Router};
+use axum::{
+    extract::{Path, Query},
+    routing::get,
+    Json, Router,
+};
 use stackable_cockpit::platform::stack::StackSpec;
 
+use crate::handlers::ListQuery;
+
 /// Creates the stack sub-router.
 pub fn router() -> Router {
     Router::new()
@@ -8,8 +14,8 @@ pub fn router() -> Router {
         .route("/:stack_name", get(get_stack))
 }
 
-/// Retrieves all stacks.
-pub async fn get_stacks() -> Json<Vec<StackSpec>> {
+/// Retrieves all stacks matching the optional filter `query`.
+pub async fn get_stacks(Query(_query): Query<ListQuery>) -> Json<Vec<StackSpec>> {
     todo!()
 }
 
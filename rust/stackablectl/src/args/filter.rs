@@ -0,0 +1,26 @@
+use clap::Args;
+use stackable_cockpit::common::list::ListFilter;
+
+#[derive(Debug, Args)]
+#[command(next_help_heading = "Filter options")]
+pub struct CommonFilterArgs {
+    /// Only list entries with this label, can be specified multiple times
+    #[arg(long = "label", value_name = "LABEL")]
+    pub labels: Vec<String>,
+
+    /// Only list entries whose name or description contains this text
+    #[arg(long, value_name = "TEXT")]
+    pub search: Option<String>,
+}
+
+impl CommonFilterArgs {
+    /// Returns the filter for the list commands. Entries are additionally
+    /// filtered by `release`, if provided.
+    pub fn filter(&self, release: Option<&str>) -> ListFilter {
+        ListFilter {
+            labels: self.labels.clone(),
+            search: self.search.clone(),
+            release: release.map(ToString::to_string),
+        }
+    }
+}
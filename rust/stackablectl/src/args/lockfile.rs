@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueHint};
+use stackable_cockpit::platform::lockfile::{self, Lockfile};
+
+use crate::constants::DEFAULT_LOCKFILE_NAME;
+
+#[derive(Debug, Args)]
+#[command(next_help_heading = "Lockfile options")]
+pub struct CommonLockfileArgs {
+    /// Use exactly the files and versions recorded in the lockfile
+    #[arg(
+        long,
+        long_help = "Use exactly the files and versions recorded in the lockfile
+
+The installation fails if any demo, stack, release or manifest file changed
+since the lockfile was created using \"stackablectl lock\". Helm charts are
+installed and compared using the locked versions. Unless --release is provided,
+the release recorded in the lockfile is used."
+    )]
+    pub locked: bool,
+
+    /// Path of the lockfile used by --locked
+    #[arg(long, value_name = "LOCKFILE", default_value = DEFAULT_LOCKFILE_NAME, value_hint = ValueHint::FilePath)]
+    pub lockfile: PathBuf,
+}
+
+impl CommonLockfileArgs {
+    /// Reads the lockfile, or returns [`None`] if --locked is not set.
+    pub async fn read(&self) -> Result<Option<Lockfile>, lockfile::Error> {
+        if !self.locked {
+            return Ok(None);
+        }
+
+        Lockfile::read(&self.lockfile).await.map(Some)
+    }
+}
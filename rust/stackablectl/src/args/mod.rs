@@ -1,9 +1,17 @@
 mod cluster;
 mod file;
+mod filter;
+mod lockfile;
 mod namespace;
+mod prune;
 mod repo;
+mod transfer;
 
 pub use cluster::*;
 pub use file::*;
+pub use filter::*;
+pub use lockfile::*;
 pub use namespace::*;
+pub use prune::*;
 pub use repo::*;
+pub use transfer::*;
@@ -0,0 +1,48 @@
+use clap::Args;
+use stackable_cockpit::platform::prune::{ManagedObject, PruneMode};
+
+#[derive(Debug, Args)]
+#[command(next_help_heading = "Prune options")]
+pub struct CommonPruneArgs {
+    /// Delete objects of previous installations which are not part of the
+    /// manifests anymore
+    #[arg(
+        long,
+        long_help = "Delete objects of previous installations which are not part of the
+manifests anymore
+
+After the installation, all objects labeled as part of the demo or stack are
+compared against the objects which were just applied. Leftover objects, e.g.
+because they were removed from the manifests, are deleted. Objects installed
+via Helm charts are not pruned."
+    )]
+    pub prune: bool,
+
+    /// Only display the objects which would be pruned, without deleting them
+    #[arg(long, conflicts_with = "prune")]
+    pub prune_dry_run: bool,
+}
+
+impl CommonPruneArgs {
+    /// Returns the requested prune mode, or [`None`] if pruning is disabled.
+    pub fn mode(&self) -> Option<PruneMode> {
+        match (self.prune, self.prune_dry_run) {
+            (true, _) => Some(PruneMode::Delete),
+            (false, true) => Some(PruneMode::DryRun),
+            (false, false) => None,
+        }
+    }
+
+    /// Describes the `pruned` objects so that they can be appended to the
+    /// command output. Returns [`None`] if pruning is disabled.
+    pub fn summary(&self, pruned: &[ManagedObject]) -> Option<String> {
+        let heading = match self.mode()? {
+            _ if pruned.is_empty() => return Some("No objects to prune".into()),
+            PruneMode::Delete => "Pruned objects:",
+            PruneMode::DryRun => "Objects which would be pruned:",
+        };
+
+        let objects: Vec<String> = pruned.iter().map(|object| format!("  {object}")).collect();
+        Some(format!("{heading}\n{}", objects.join("\n")))
+    }
+}
@@ -0,0 +1,59 @@
+use std::{path::PathBuf, time::Duration};
+
+use clap::{Args, ValueHint};
+use stackable_cockpit::{
+    constants::{DEFAULT_HTTP_CONNECT_TIMEOUT, DEFAULT_HTTP_READ_TIMEOUT, DEFAULT_HTTP_RETRIES},
+    xfer::HttpSettings,
+};
+
+#[derive(Debug, Args)]
+#[command(next_help_heading = "Transfer options")]
+pub struct CommonTransferArgs {
+    /// Maximum number of seconds to establish a connection
+    #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_HTTP_CONNECT_TIMEOUT.as_secs(), global = true)]
+    pub connect_timeout: u64,
+
+    /// Maximum number of seconds to wait for data from an established connection
+    #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_HTTP_READ_TIMEOUT.as_secs(), global = true)]
+    pub read_timeout: u64,
+
+    /// Number of retries of failed requests
+    #[arg(long, value_name = "RETRIES", default_value_t = DEFAULT_HTTP_RETRIES, global = true)]
+    #[arg(long_help = "Number of retries of failed requests
+
+Only transient failures, like connection errors, timeouts and server errors
+are retried. The delay between retries doubles with every attempt.")]
+    pub retries: u32,
+
+    /// Path of a PEM file with additional CA certificates to trust
+    #[arg(long, value_name = "CA_CERT", value_hint = ValueHint::FilePath, global = true)]
+    #[arg(
+        long_help = "Path of a PEM file with additional CA certificates to trust
+
+The certificates are trusted in addition to the system certificates for all
+requests, including Helm repositories, OCI registries and Git repositories."
+    )]
+    pub ca_cert: Option<PathBuf>,
+
+    /// Proxy URL used for all requests
+    #[arg(long, value_name = "URL", value_hint = ValueHint::Url, global = true)]
+    #[arg(long_help = "Proxy URL used for all requests
+
+If not set, the proxy is configured using the 'HTTPS_PROXY', 'HTTP_PROXY' and
+'NO_PROXY' environment variables. Hosts listed in 'NO_PROXY' are accessed
+directly in both cases.")]
+    pub proxy: Option<String>,
+}
+
+impl CommonTransferArgs {
+    /// Returns the HTTP settings used by the transfer client and Helm.
+    pub fn http_settings(&self) -> HttpSettings {
+        HttpSettings {
+            connect_timeout: Duration::from_secs(self.connect_timeout),
+            read_timeout: Duration::from_secs(self.read_timeout),
+            retries: self.retries,
+            ca_cert: self.ca_cert.clone(),
+            proxy: self.proxy.clone(),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::env;
 use clap::{Parser, Subcommand, ValueEnum};
 use directories::ProjectDirs;
 use snafu::{ResultExt, Snafu};
-use tracing::{debug, instrument, Level};
+use tracing::{debug, instrument, warn, Level};
 
 use stackable_cockpit::{
     constants::{HELM_REPO_NAME_DEV, HELM_REPO_NAME_STABLE, HELM_REPO_NAME_TEST},
@@ -12,12 +12,17 @@ use stackable_cockpit::{
     utils::path::{
         IntoPathOrUrl, IntoPathsOrUrls, ParsePathsOrUrls, PathOrUrl, PathOrUrlParseError,
     },
-    xfer::cache::Settings,
+    xfer::{
+        self,
+        cache::{Cache, Settings},
+    },
 };
 
 use crate::{
-    args::{CommonFileArgs, CommonRepoArgs},
-    cmds::{cache, completions, debug, demo, operator, release, stack, stacklet},
+    args::{CommonFileArgs, CommonRepoArgs, CommonTransferArgs},
+    cmds::{
+        cache, completions, debug, demo, lint, lock, operator, release, stack, stacklet, template,
+    },
     constants::{
         DEMOS_REPOSITORY_DEMOS_SUBPATH, DEMOS_REPOSITORY_STACKS_SUBPATH, DEMOS_REPOSITORY_URL_BASE,
         ENV_KEY_DEMO_FILES, ENV_KEY_RELEASE_FILES, ENV_KEY_STACK_FILES, REMOTE_RELEASE_FILE,
@@ -49,11 +54,37 @@ pub enum Error {
     #[snafu(display("cache command error"))]
     Cache { source: cache::CmdError },
 
+    #[snafu(display("template command error"))]
+    Template { source: template::CmdError },
+
+    #[snafu(display("lint command error"))]
+    Lint { source: lint::CmdError },
+
+    #[snafu(display("lock command error"))]
+    Lock { source: lock::CmdError },
+
     #[snafu(display("debug command error"))]
     Debug { source: debug::CmdError },
 
     #[snafu(display("helm error"))]
     Helm { source: helm::Error },
+
+    #[snafu(display("failed to build cache settings"))]
+    CacheSettings { source: CacheSettingsError },
+
+    #[snafu(display("failed to initialize cache"))]
+    InitCache { source: xfer::cache::Error },
+}
+
+impl Error {
+    /// Returns output which should be printed even though the command
+    /// failed, like the report of the lint command.
+    pub fn output(&self) -> Option<&str> {
+        match self {
+            Error::Lint { source } => source.output(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Parser)]
@@ -74,12 +105,41 @@ Cached files are saved at '$XDG_CACHE_HOME/stackablectl', which is usually
     )]
     pub no_cache: bool,
 
+    /// Do not access the network to retrieve remote files
+    #[arg(
+        long,
+        global = true,
+        conflicts_with = "no_cache",
+        long_help = "Do not access the network to retrieve remote files
+
+Remote demo, stack and release files as well as Helm and OCI index files are
+only read from the cache, even if they are expired. Retrieving files which are
+not cached results in an error."
+    )]
+    pub offline: bool,
+
+    /// Maximum size of the cache in MiB
+    #[arg(
+        long,
+        global = true,
+        value_name = "MIB",
+        conflicts_with = "no_cache",
+        long_help = "Maximum size of the cache in MiB
+
+When the cache grows larger, the least recently used files are removed. The
+cache is limited to 512 MiB by default."
+    )]
+    pub cache_max_size: Option<u64>,
+
     #[command(flatten)]
     pub files: CommonFileArgs,
 
     #[command(flatten)]
     pub repos: CommonRepoArgs,
 
+    #[command(flatten)]
+    pub transfer: CommonTransferArgs,
+
     #[command(subcommand)]
     pub subcommand: Commands,
 }
@@ -163,7 +223,17 @@ impl Cli {
             )
             .ok_or(CacheSettingsError::UserDir)?;
 
-            Ok(Settings::disk(project_dir.cache_dir()))
+            let mut settings = Settings::disk(project_dir.cache_dir());
+
+            if let Some(max_size) = self.cache_max_size {
+                settings.max_size = Some(
+                    max_size
+                        .checked_mul(1024 * 1024)
+                        .ok_or(CacheSettingsError::MaxSizeTooLarge { max_size })?,
+                );
+            }
+
+            Ok(settings)
         }
     }
 
@@ -172,36 +242,64 @@ impl Cli {
         // FIXME (Techassi): There might be a better way to handle this with
         // the match later in this function.
 
+        // The transfer settings also apply to Helm, including commands which
+        // render charts without adding the Helm repos
+        if !matches!(self.subcommand, Commands::Completions(_)) {
+            helm::configure_transfer(&self.transfer.http_settings()).context(HelmSnafu)?;
+        }
+
         // Add Helm repos only when required
         match &self.subcommand {
             Commands::Completions(_) => (),
             Commands::Cache(_) => (),
+            Commands::Template(_) => (),
+            Commands::Lint(_) => (),
+            Commands::Lock(_) => (),
+            Commands::Stack(args) if args.is_read_only() => (),
+            Commands::Demo(args) if args.is_read_only() => (),
+            _ if self.offline => (),
             _ => self.add_helm_repos().context(HelmSnafu)?,
         }
 
         let cache = self
             .cache_settings()
-            .unwrap()
+            .context(CacheSettingsSnafu)?
             .try_into_cache()
             .await
-            .unwrap();
+            .context(InitCacheSnafu)?;
 
         // TODO (Techassi): Do we still want to auto purge when running cache commands?
-        cache.auto_purge().await.unwrap();
+        // Offline mode relies on cached files, so they are never purged
+        // automatically. Failing to purge doesn't affect the command itself.
+        if !self.offline {
+            if let Err(err) = cache.auto_purge().await {
+                warn!("Failed to auto-purge cache: {err}");
+            }
+        }
 
         match &self.subcommand {
-            Commands::Operator(args) => args.run(self).await.context(OperatorSnafu),
+            Commands::Operator(args) => args.run(self, cache).await.context(OperatorSnafu),
             Commands::Release(args) => args.run(self, cache).await.context(ReleaseSnafu),
             Commands::Stack(args) => args.run(self, cache).await.context(StackSnafu),
             Commands::Stacklet(args) => args.run(self).await.context(StackletSnafu),
             Commands::Demo(args) => args.run(self, cache).await.context(DemoSnafu),
             Commands::Completions(args) => args.run().context(CompletionsSnafu),
             Commands::Cache(args) => args.run(self, cache).await.context(CacheSnafu),
+            Commands::Template(args) => args.run(self).context(TemplateSnafu),
+            Commands::Lint(args) => args.run(self, cache).await.context(LintSnafu),
+            Commands::Lock(args) => args.run(self, cache).await.context(LockSnafu),
             Commands::ExperimentalDebug(args) => args.run(self).await.context(DebugSnafu),
         }
     }
 
     // Output utility functions
+    /// Returns a transfer client using the provided `cache`, which respects
+    /// the global `--offline` flag and transfer options.
+    pub fn transfer_client(&self, cache: Cache) -> Result<xfer::Client, xfer::Error> {
+        let client = xfer::Client::try_new_with(cache, self.transfer.http_settings())?;
+        Ok(client.offline(self.offline))
+    }
+
     pub fn result(&self) -> Output<ResultContext> {
         Output::new(ResultContext::default(), true).expect("Failed to create output renderer")
     }
@@ -252,6 +350,16 @@ CRDs."
     /// Interact with locally cached files
     Cache(cache::CacheArgs),
 
+    /// Interact with the templating used in demo and stack manifests
+    #[command(alias("tpl"))]
+    Template(template::TemplateArgs),
+
+    /// Check demo, stack and release files for errors
+    Lint(lint::LintArgs),
+
+    /// Lock a demo or stack for reproducible installs
+    Lock(lock::LockArgs),
+
     /// EXPERIMENTAL: Launch a debug container for a Pod
     #[command(long_about = "EXPERIMENTAL: Launch a debug container for a Pod.
 
@@ -280,6 +388,9 @@ pub enum OutputType {
 pub enum CacheSettingsError {
     #[snafu(display("unable to resolve user directories"))]
     UserDir,
+
+    #[snafu(display("maximum cache size of {max_size} MiB is too large"))]
+    MaxSizeTooLarge { max_size: u64 },
 }
 
 /// Returns a list of paths or urls based on the default (remote) file and
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use clap::{Args, Subcommand};
 use comfy_table::{presets::UTF8_FULL, ColumnConstraint, Table, Width};
@@ -20,6 +20,9 @@ pub enum CacheCommands {
     #[command(aliases(["ls"]))]
     List,
 
+    /// Show statistics of cached files
+    Stats,
+
     /// Clean cached files
     #[command(aliases(["rm", "purge"]))]
     Clean(CacheCleanArgs),
@@ -45,6 +48,7 @@ impl CacheArgs {
     pub async fn run(&self, cli: &Cli, cache: Cache) -> Result<String, CmdError> {
         match &self.subcommand {
             CacheCommands::List => list_cmd(cache, cli).await,
+            CacheCommands::Stats => stats_cmd(cache, cli).await,
             CacheCommands::Clean(args) => clean_cmd(args, cache).await,
         }
     }
@@ -54,27 +58,22 @@ impl CacheArgs {
 async fn list_cmd(cache: Cache, cli: &Cli) -> Result<String, CmdError> {
     info!("Listing cached files");
 
-    let files = cache.list().await.context(ListCachedFilesSnafu)?;
+    let mut files = cache.list().await.context(ListCachedFilesSnafu)?;
 
     if files.is_empty() {
         return Ok("No cached files".into());
     }
 
+    files.sort_by_key(entry_name);
+
     let mut table = Table::new();
     table
-        .set_header(vec!["FILE", "LAST SYNC"])
+        .set_header(vec!["URL", "LAST SYNC"])
         .set_constraints(vec![ColumnConstraint::UpperBoundary(Width::Percentage(80))])
         .load_preset(UTF8_FULL);
 
-    for (path, modified) in files {
-        let file_path = path.to_str().unwrap_or("Invalid UTF-8 Path").to_string();
-        let modified = modified
-            .elapsed()
-            .unwrap_or(Duration::ZERO)
-            .as_secs()
-            .to_string();
-
-        table.add_row(vec![file_path, format!("{modified} seconds ago")]);
+    for entry in &files {
+        table.add_row(vec![entry_name(entry), seconds_ago(entry.stored_at)]);
     }
 
     let mut result = cli.result();
@@ -86,6 +85,56 @@ async fn list_cmd(cache: Cache, cli: &Cli) -> Result<String, CmdError> {
     Ok(result.render())
 }
 
+#[instrument(skip_all)]
+async fn stats_cmd(cache: Cache, cli: &Cli) -> Result<String, CmdError> {
+    info!("Showing cache statistics");
+
+    let mut files = cache.list().await.context(ListCachedFilesSnafu)?;
+
+    if files.is_empty() {
+        return Ok("No cached files".into());
+    }
+
+    // Most recently used files first
+    files.sort_by_key(|entry| std::cmp::Reverse(entry.accessed_at));
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["URL", "SIZE", "AGE", "LAST USED", "HITS", "MISSES"])
+        .set_constraints(vec![ColumnConstraint::UpperBoundary(Width::Percentage(60))])
+        .load_preset(UTF8_FULL);
+
+    for entry in &files {
+        table.add_row(vec![
+            entry_name(entry),
+            format_size(entry.size),
+            seconds_ago(entry.stored_at),
+            seconds_ago(entry.accessed_at),
+            entry.hits.to_string(),
+            entry.misses.to_string(),
+        ]);
+    }
+
+    let size = files.iter().map(|entry| entry.size).sum();
+    let hits: u64 = files.iter().map(|entry| entry.hits).sum();
+    let misses: u64 = files.iter().map(|entry| entry.misses).sum();
+
+    let mut result = cli.result();
+
+    result
+        .with_command_hint(
+            "stackablectl cache clean --old",
+            "to clean outdated cached files",
+        )
+        .with_output(format!(
+            "{table}\n\n{count} cached files using {size}, {hits} hits and {misses} misses",
+            count = files.len(),
+            size = format_size(size),
+        ));
+
+    Ok(result.render())
+}
+
 #[instrument(skip_all)]
 async fn clean_cmd(args: &CacheCleanArgs, cache: Cache) -> Result<String, CmdError> {
     info!("Cleaning cached files");
@@ -103,3 +152,39 @@ async fn clean_cmd(args: &CacheCleanArgs, cache: Cache) -> Result<String, CmdErr
 
     Ok("Cleaned cached files".into())
 }
+
+/// Returns the URL of the cached file, or its file name if the URL is not
+/// known.
+fn entry_name(entry: &cache::Entry) -> String {
+    entry.url.clone().unwrap_or_else(|| match &entry.path {
+        Some(path) => path.to_string_lossy().into_owned(),
+        None => entry.file_name.clone(),
+    })
+}
+
+fn seconds_ago(time: SystemTime) -> String {
+    let seconds = time.elapsed().unwrap_or(Duration::ZERO).as_secs();
+    format!("{seconds} seconds ago")
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KiB", "MiB", "GiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+
+    for next_unit in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+
+        size /= 1024.0;
+        unit = next_unit;
+    }
+
+    format!("{size:.1} {unit}")
+}
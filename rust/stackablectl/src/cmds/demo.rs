@@ -11,7 +11,9 @@ use stackable_cockpit::{
     common::list,
     constants::{DEFAULT_OPERATOR_NAMESPACE, DEFAULT_PRODUCT_NAMESPACE},
     platform::{
-        demo::{self, DemoInstallParameters},
+        demo::{self, DemoDiffParameters, DemoInstallParameters},
+        install_record::{self, InstallRecord, InstallRecordKind},
+        lockfile::{self, Lockfile},
         operator::ChartSourceType,
         release, stack,
     },
@@ -23,8 +25,12 @@ use stackable_cockpit::{
 };
 
 use crate::{
-    args::{CommonClusterArgs, CommonClusterArgsError, CommonNamespaceArgs},
+    args::{
+        CommonClusterArgs, CommonClusterArgsError, CommonFilterArgs, CommonLockfileArgs,
+        CommonNamespaceArgs, CommonPruneArgs,
+    },
     cli::{Cli, OutputType},
+    utils::render_object_diffs,
 };
 
 #[derive(Debug, Args)]
@@ -50,12 +56,21 @@ pub enum DemoCommands {
     /// Install a specific demo
     #[command(aliases(["i", "in"]))]
     Install(DemoInstallArgs),
+
+    /// Show the changes installing a specific demo would make to the cluster
+    Diff(DemoDiffArgs),
+
+    /// List installed demos
+    Installed(DemoInstalledArgs),
 }
 
 #[derive(Debug, Args)]
 pub struct DemoListArgs {
     #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
     output_type: OutputType,
+
+    #[command(flatten)]
+    filter: CommonFilterArgs,
 }
 
 #[derive(Debug, Args)]
@@ -99,6 +114,30 @@ to specify operator versions."
     )]
     skip_release: bool,
 
+    /// Roll back all changes made to the cluster if the installation fails
+    #[arg(
+        long,
+        long_help = "Roll back all changes made to the cluster if the installation fails
+
+Objects and Helm releases created during the installation are removed again in
+reverse order. Objects and Helm releases which existed before the installation
+are left untouched."
+    )]
+    rollback_on_failure: bool,
+
+    /// Skip estimating the required resources if the demo doesn't specify them
+    #[arg(
+        long,
+        long_help = "Skip estimating the required resources if the demo doesn't specify them
+
+By default, the manifests (including Helm charts) of demos without resource
+requests are rendered to estimate the resources, which are then validated
+against the free cluster resources. This requires network access and can take
+a while. With this flag, the validation is skipped for demos without resource
+requests."
+    )]
+    skip_resource_estimate: bool,
+
     /// List of parameters to use when installing the stack
     #[arg(long)]
     stack_parameters: Vec<String>,
@@ -110,15 +149,68 @@ to specify operator versions."
     #[command(flatten)]
     local_cluster: CommonClusterArgs,
 
+    #[command(flatten)]
+    prune: CommonPruneArgs,
+
+    #[command(flatten)]
+    lockfile: CommonLockfileArgs,
+
     #[command(flatten)]
     namespaces: CommonNamespaceArgs,
 }
 
+#[derive(Debug, Args)]
+pub struct DemoDiffArgs {
+    /// Demo to compare against the cluster
+    #[arg(
+        name = "DEMO",
+        long_help = "Demo to compare against the cluster
+
+The manifests of the demo and its stack are rendered and applied using a
+server-side dry-run. Helm charts are rendered client-side. A unified diff is
+printed for every object, ignoring managed fields and status.
+
+Use \"stackablectl demo list\" to display a list of available demos."
+    )]
+    demo_name: String,
+
+    /// List of parameters to use when installing the stack
+    #[arg(long)]
+    stack_parameters: Vec<String>,
+
+    /// List of parameters to use when installing the demo
+    #[arg(long)]
+    parameters: Vec<String>,
+
+    #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
+    output_type: OutputType,
+
+    /// Namespace in the cluster used to deploy the products
+    #[arg(short = 'n', long, default_value = DEFAULT_PRODUCT_NAMESPACE, visible_aliases(["product-ns"]))]
+    product_namespace: String,
+
+    #[command(flatten)]
+    lockfile: CommonLockfileArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct DemoInstalledArgs {
+    #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
+    output_type: OutputType,
+
+    /// Namespace in the cluster used to deploy the products
+    #[arg(short = 'n', long, default_value = DEFAULT_PRODUCT_NAMESPACE, visible_aliases(["product-ns"]))]
+    product_namespace: String,
+}
+
 #[derive(Debug, Args)]
 pub struct DemoUninstallArgs {}
 
 #[derive(Debug, Snafu)]
 pub enum CmdError {
+    #[snafu(display("failed to create transfer client"))]
+    TransferClient { source: xfer::Error },
+
     #[snafu(display("failed to serialize YAML output"))]
     SerializeYamlOutput { source: serde_yaml::Error },
 
@@ -152,26 +244,88 @@ pub enum CmdError {
         demo_name: String,
     },
 
+    #[snafu(display("failed to diff demo {demo_name:?}"))]
+    DiffDemo {
+        source: demo::Error,
+        demo_name: String,
+    },
+
     #[snafu(display("failed to build labels for demo resources"))]
     BuildLabels { source: LabelError },
 
+    #[snafu(display("failed to list install records"))]
+    ListInstallRecords { source: install_record::Error },
+
     #[snafu(display("failed to create Kubernetes client"))]
     KubeClientCreate { source: k8s::Error },
+
+    #[snafu(display("failed to read lockfile"))]
+    ReadLockfile { source: lockfile::Error },
 }
 
 impl DemoArgs {
+    /// Returns if the command only renders manifests without installing
+    /// them. These commands don't add Helm repos.
+    pub fn is_read_only(&self) -> bool {
+        matches!(self.subcommand, DemoCommands::Diff(_))
+    }
+
     #[instrument]
     pub async fn run(&self, cli: &Cli, cache: Cache) -> Result<String, CmdError> {
         debug!("Handle demo args");
 
-        let transfer_client = xfer::Client::new_with(cache);
+        match &self.subcommand {
+            DemoCommands::List(args) => {
+                let (list, ..) = self.build_demo_list(cli, cache, None).await?;
+                list_cmd(args, cli, list).await
+            }
+            DemoCommands::Describe(args) => {
+                let (list, ..) = self.build_demo_list(cli, cache, None).await?;
+                describe_cmd(args, cli, list).await
+            }
+            DemoCommands::Install(args) => {
+                let lockfile = args.lockfile.read().await.context(ReadLockfileSnafu)?;
+                let (list, transfer_client, release_branch) =
+                    self.build_demo_list(cli, cache, lockfile.as_ref()).await?;
+
+                install_cmd(args, cli, list, &transfer_client, &release_branch, lockfile).await
+            }
+            DemoCommands::Diff(args) => {
+                let lockfile = args.lockfile.read().await.context(ReadLockfileSnafu)?;
+                let (list, transfer_client, release_branch) =
+                    self.build_demo_list(cli, cache, lockfile.as_ref()).await?;
+
+                diff_cmd(args, cli, list, &transfer_client, &release_branch, lockfile).await
+            }
+            // Installed demos are retrieved from the cluster and thus don't
+            // require the demo, stack and release lists
+            DemoCommands::Installed(args) => installed_cmd(args, cli).await,
+        }
+    }
+
+    /// Builds the list of demos of the requested release. Locked installs
+    /// and diffs use the release of the `lockfile`, unless a release is
+    /// requested explicitly. Returns the transfer client used to build the
+    /// list and the release branch together with the list.
+    async fn build_demo_list(
+        &self,
+        cli: &Cli,
+        cache: Cache,
+        lockfile: Option<&Lockfile>,
+    ) -> Result<(demo::List, xfer::Client, String), CmdError> {
+        let release = self
+            .release
+            .as_ref()
+            .or(lockfile.map(|lockfile| &lockfile.release));
+
+        let transfer_client = cli.transfer_client(cache).context(TransferClientSnafu)?;
 
         let release_files = cli.get_release_files().context(PathOrUrlParseSnafu)?;
         let release_list = release::ReleaseList::build(&release_files, &transfer_client)
             .await
             .context(BuildListSnafu)?;
 
-        let release_branch = match &self.release {
+        let release_branch = match release {
             Some(release) => {
                 ensure!(
                     release_list.contains_key(release),
@@ -200,13 +354,7 @@ impl DemoArgs {
             .await
             .context(BuildListSnafu)?;
 
-        match &self.subcommand {
-            DemoCommands::List(args) => list_cmd(args, cli, list).await,
-            DemoCommands::Describe(args) => describe_cmd(args, cli, list).await,
-            DemoCommands::Install(args) => {
-                install_cmd(args, cli, list, &transfer_client, &release_branch).await
-            }
-        }
+        Ok((list, transfer_client, release_branch))
     }
 }
 
@@ -215,6 +363,10 @@ impl DemoArgs {
 async fn list_cmd(args: &DemoListArgs, cli: &Cli, list: demo::List) -> Result<String, CmdError> {
     info!("Listing demos");
 
+    // Demos are already limited to the selected release by the branch they
+    // are loaded from
+    let list = list.filter(&args.filter.filter(None));
+
     match args.output_type {
         OutputType::Plain | OutputType::Table => {
             let (arrangement, preset) = match args.output_type {
@@ -224,7 +376,7 @@ async fn list_cmd(args: &DemoListArgs, cli: &Cli, list: demo::List) -> Result<St
 
             let mut table = Table::new();
             table
-                .set_header(vec!["#", "NAME", "STACK", "DESCRIPTION"])
+                .set_header(vec!["#", "NAME", "STACK", "LABELS", "DESCRIPTION"])
                 .set_content_arrangement(arrangement)
                 .load_preset(preset);
 
@@ -233,6 +385,7 @@ async fn list_cmd(args: &DemoListArgs, cli: &Cli, list: demo::List) -> Result<St
                     (index + 1).to_string(),
                     demo_name.clone(),
                     demo_spec.stack.clone(),
+                    demo_spec.labels.join(", "),
                     demo_spec.description.clone(),
                 ]);
                 table.add_row(row);
@@ -315,13 +468,14 @@ async fn describe_cmd(
 }
 
 /// Install a specific demo
-#[instrument(skip(list, transfer_client))]
+#[instrument(skip(list, transfer_client, lockfile))]
 async fn install_cmd(
     args: &DemoInstallArgs,
     cli: &Cli,
     list: demo::List,
     transfer_client: &xfer::Client,
     release_branch: &str,
+    lockfile: Option<Lockfile>,
 ) -> Result<String, CmdError> {
     info!(%release_branch, "Installing demo {}", args.demo_name);
 
@@ -333,15 +487,15 @@ async fn install_cmd(
     })?;
 
     // TODO (Techassi): Try to move all this boilerplate code to build the lists out of here
-    let files = cli
+    let stack_files = cli
         .get_stack_files(release_branch)
         .context(PathOrUrlParseSnafu)?;
-    let stack_list = stack::StackList::build(&files, transfer_client)
+    let stack_list = stack::StackList::build(&stack_files, transfer_client)
         .await
         .context(BuildListSnafu)?;
 
-    let files = cli.get_release_files().context(PathOrUrlParseSnafu)?;
-    let release_list = release::ReleaseList::build(&files, transfer_client)
+    let release_files = cli.get_release_files().context(PathOrUrlParseSnafu)?;
+    let release_list = release::ReleaseList::build(&release_files, transfer_client)
         .await
         .context(BuildListSnafu)?;
 
@@ -353,42 +507,43 @@ async fn install_cmd(
 
     let client = Client::new().await.context(KubeClientCreateSnafu)?;
 
-    // Construct labels which get attached to all dynamic objects which
-    // are part of the demo and stack.
-    let labels = Labels::try_from([
-        ("stackable.tech/managed-by", "stackablectl"),
-        ("stackable.tech/demo", &args.demo_name),
-        ("stackable.tech/vendor", "Stackable"),
-    ])
-    .context(BuildLabelsSnafu)?;
-
-    let mut stack_labels = labels.clone();
-    stack_labels
-        .parse_insert(("stackable.tech/stack", &demo.stack))
-        .context(BuildLabelsSnafu)?;
+    let (labels, stack_labels) = build_labels(&args.demo_name, &demo.stack)?;
 
     let install_parameters = DemoInstallParameters {
+        demo_name: args.demo_name.clone(),
         operator_namespace: args.namespaces.operator_namespace.clone(),
         product_namespace: args.namespaces.product_namespace.clone(),
         stack_parameters: args.stack_parameters.clone(),
         parameters: args.parameters.clone(),
         skip_release: args.skip_release,
+        rollback_on_failure: args.rollback_on_failure,
+        skip_resource_estimate: args.skip_resource_estimate,
+        prune: args.prune.mode(),
         stack_labels,
         labels,
         chart_source: ChartSourceType::from(cli.chart_type()),
+        spec_sources: list
+            .sources()
+            .iter()
+            .chain(stack_list.sources())
+            .chain(release_list.sources())
+            .cloned()
+            .collect(),
+        lockfile,
     };
 
-    demo.install(
-        stack_list,
-        release_list,
-        install_parameters,
-        &client,
-        transfer_client,
-    )
-    .await
-    .context(InstallDemoSnafu {
-        demo_name: args.demo_name.clone(),
-    })?;
+    let pruned = demo
+        .install(
+            stack_list,
+            release_list,
+            install_parameters,
+            &client,
+            transfer_client,
+        )
+        .await
+        .context(InstallDemoSnafu {
+            demo_name: args.demo_name.clone(),
+        })?;
 
     let operator_cmd = format!(
         "stackablectl operator installed{}",
@@ -411,10 +566,177 @@ async fn install_cmd(
         }
     );
 
+    let mut message = format!("Installed demo '{}'", args.demo_name);
+    if let Some(summary) = args.prune.summary(&pruned) {
+        message.push_str(&format!("\n\n{summary}"));
+    }
+
     output
         .with_command_hint(operator_cmd, "display the installed operators")
         .with_command_hint(stacklet_cmd, "display the installed stacklets")
-        .with_output(format!("Installed demo '{}'", args.demo_name));
+        .with_output(message);
 
     Ok(output.render())
 }
+
+/// Compares a specific demo against the live objects in the cluster
+#[instrument(skip(list, transfer_client, lockfile))]
+async fn diff_cmd(
+    args: &DemoDiffArgs,
+    cli: &Cli,
+    list: demo::List,
+    transfer_client: &xfer::Client,
+    release_branch: &str,
+    lockfile: Option<Lockfile>,
+) -> Result<String, CmdError> {
+    info!(%release_branch, "Diffing demo {}", args.demo_name);
+
+    let demo = list.get(&args.demo_name).ok_or(CmdError::NoSuchDemo {
+        name: args.demo_name.clone(),
+    })?;
+
+    let stack_files = cli
+        .get_stack_files(release_branch)
+        .context(PathOrUrlParseSnafu)?;
+    let stack_list = stack::StackList::build(&stack_files, transfer_client)
+        .await
+        .context(BuildListSnafu)?;
+
+    let client = Client::new().await.context(KubeClientCreateSnafu)?;
+    let (labels, stack_labels) = build_labels(&args.demo_name, &demo.stack)?;
+
+    let diff_parameters = DemoDiffParameters {
+        product_namespace: args.product_namespace.clone(),
+        stack_parameters: args.stack_parameters.clone(),
+        parameters: args.parameters.clone(),
+        stack_labels,
+        labels,
+        lockfile,
+    };
+
+    let diffs = demo
+        .diff(&stack_list, diff_parameters, &client, transfer_client)
+        .await
+        .context(DiffDemoSnafu {
+            demo_name: args.demo_name.clone(),
+        })?;
+
+    match args.output_type {
+        OutputType::Plain | OutputType::Table => {
+            let mut result = cli.result();
+
+            result
+                .with_command_hint(
+                    format!("stackablectl demo install {}", args.demo_name),
+                    "install the demo",
+                )
+                .with_output(render_object_diffs(&diffs));
+
+            Ok(result.render())
+        }
+        OutputType::Json => serde_json::to_string(&diffs).context(SerializeJsonOutputSnafu),
+        OutputType::Yaml => serde_yaml::to_string(&diffs).context(SerializeYamlOutputSnafu),
+    }
+}
+
+/// Constructs the labels which get attached to all dynamic objects which are
+/// part of the demo, and the labels for objects which are part of the stack.
+fn build_labels(demo_name: &str, stack_name: &str) -> Result<(Labels, Labels), CmdError> {
+    let labels = Labels::try_from([
+        ("stackable.tech/managed-by", "stackablectl"),
+        ("stackable.tech/demo", demo_name),
+        ("stackable.tech/vendor", "Stackable"),
+    ])
+    .context(BuildLabelsSnafu)?;
+
+    let mut stack_labels = labels.clone();
+    stack_labels
+        .parse_insert(("stackable.tech/stack", stack_name))
+        .context(BuildLabelsSnafu)?;
+
+    Ok((labels, stack_labels))
+}
+
+/// Lists the install records of demos installed in the product namespace
+#[instrument]
+async fn installed_cmd(args: &DemoInstalledArgs, cli: &Cli) -> Result<String, CmdError> {
+    info!("Listing installed demos");
+
+    let client = Client::new().await.context(KubeClientCreateSnafu)?;
+
+    let records = InstallRecord::list(
+        InstallRecordKind::Demo,
+        Some(&args.product_namespace),
+        &client,
+    )
+    .await
+    .context(ListInstallRecordsSnafu)?;
+
+    match args.output_type {
+        OutputType::Plain | OutputType::Table => {
+            let mut result = cli.result();
+
+            if records.is_empty() {
+                result
+                    .with_command_hint(
+                        "stackablectl demo install <DEMO_NAME>",
+                        "install an end-to-end demo",
+                    )
+                    .with_output("No installed demos found");
+
+                return Ok(result.render());
+            }
+
+            let (arrangement, preset) = match args.output_type {
+                OutputType::Plain => (ContentArrangement::Disabled, NOTHING),
+                _ => (ContentArrangement::Dynamic, UTF8_FULL),
+            };
+
+            let mut table = Table::new();
+            table
+                .set_header(vec![
+                    "DEMO",
+                    "STACK",
+                    "RELEASE",
+                    "NAMESPACE",
+                    "PARAMETERS",
+                    "LAST INSTALLED",
+                ])
+                .set_content_arrangement(arrangement)
+                .load_preset(preset);
+
+            for record in records {
+                let parameters = record
+                    .parameters
+                    .iter()
+                    .map(|(name, value)| format!("{name}={value}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                table.add_row(vec![
+                    record.name,
+                    record.stack.unwrap_or_default(),
+                    record.release,
+                    record.product_namespace,
+                    parameters,
+                    record
+                        .updated_at
+                        .0
+                        .format("%Y-%m-%d %H:%M:%S UTC")
+                        .to_string(),
+                ]);
+            }
+
+            result
+                .with_command_hint(
+                    "stackablectl stacklet list",
+                    "display the installed stacklets",
+                )
+                .with_output(table.to_string());
+
+            Ok(result.render())
+        }
+        OutputType::Json => serde_json::to_string(&records).context(SerializeJsonOutputSnafu),
+        OutputType::Yaml => serde_yaml::to_string(&records).context(SerializeYamlOutputSnafu),
+    }
+}
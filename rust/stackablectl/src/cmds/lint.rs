@@ -0,0 +1,164 @@
+use clap::Args;
+use comfy_table::{
+    presets::{NOTHING, UTF8_FULL},
+    ContentArrangement, Table,
+};
+use snafu::{OptionExt, ResultExt, Snafu};
+use tracing::{info, instrument};
+
+use stackable_cockpit::{
+    platform::{lint, release},
+    utils::path::PathOrUrlParseError,
+    xfer::{self, cache::Cache},
+};
+
+use crate::cli::{Cli, OutputType};
+
+#[derive(Debug, Args)]
+#[command(long_about = "Check demo, stack and release files for errors.
+
+This loads the same files as the demo, stack and release commands, including
+files provided by --demo-file, --stack-file and --release-file. Manifests are
+retrieved and rendered using the default parameter values, but nothing is
+installed and no access to a Kubernetes cluster is required.")]
+pub struct LintArgs {
+    /// Target a specific Stackable release
+    #[arg(long)]
+    release: Option<String>,
+
+    #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
+    output_type: OutputType,
+}
+
+#[derive(Debug, Snafu)]
+pub enum CmdError {
+    #[snafu(display("failed to create transfer client"))]
+    TransferClient { source: xfer::Error },
+
+    #[snafu(display("failed to serialize YAML output"))]
+    SerializeYamlOutput { source: serde_yaml::Error },
+
+    #[snafu(display("failed to serialize JSON output"))]
+    SerializeJsonOutput { source: serde_json::Error },
+
+    #[snafu(display("failed to parse path/url"))]
+    PathOrUrlParse { source: PathOrUrlParseError },
+
+    #[snafu(display("failed to build release list"))]
+    BuildList {
+        source: stackable_cockpit::common::list::Error,
+    },
+
+    #[snafu(display("no release found, use --release to select one"))]
+    LatestRelease,
+
+    #[snafu(display("found {errors} error(s) in demo, stack and release files"))]
+    LintFailed { errors: usize, output: String },
+}
+
+impl CmdError {
+    /// Returns the rendered lint report if linting found errors.
+    pub fn output(&self) -> Option<&str> {
+        match self {
+            CmdError::LintFailed { output, .. } => Some(output),
+            _ => None,
+        }
+    }
+}
+
+impl LintArgs {
+    #[instrument(skip(cli, cache))]
+    pub async fn run(&self, cli: &Cli, cache: Cache) -> Result<String, CmdError> {
+        info!("Linting demo, stack and release files");
+
+        let transfer_client = cli.transfer_client(cache).context(TransferClientSnafu)?;
+        let release_files = cli.get_release_files().context(PathOrUrlParseSnafu)?;
+
+        // Demo and stack files are located on the branch of the release
+        let release_branch = match &self.release {
+            Some(release) if release == "dev" => "main".to_string(),
+            Some(release) => format!("release-{release}"),
+            None => {
+                let release_list = release::ReleaseList::build(&release_files, &transfer_client)
+                    .await
+                    .context(BuildListSnafu)?;
+
+                let (release_name, _) = release_list.first().context(LatestReleaseSnafu)?;
+                format!("release-{release_name}")
+            }
+        };
+
+        let demo_files = cli
+            .get_demo_files(&release_branch)
+            .context(PathOrUrlParseSnafu)?;
+        let stack_files = cli
+            .get_stack_files(&release_branch)
+            .context(PathOrUrlParseSnafu)?;
+
+        let report = lint::lint(&demo_files, &stack_files, &release_files, &transfer_client).await;
+
+        let output = match self.output_type {
+            OutputType::Plain | OutputType::Table => {
+                let (arrangement, preset) = match self.output_type {
+                    OutputType::Plain => (ContentArrangement::Disabled, NOTHING),
+                    _ => (ContentArrangement::Dynamic, UTF8_FULL),
+                };
+
+                let mut table = Table::new();
+                table
+                    .set_header(vec!["SEVERITY", "KIND", "NAME", "LOCATION", "MESSAGE"])
+                    .set_content_arrangement(arrangement)
+                    .load_preset(preset);
+
+                for finding in &report.findings {
+                    table.add_row(vec![
+                        finding.severity.to_string(),
+                        finding.kind.to_string(),
+                        finding.name.clone().unwrap_or_else(|| finding.file.clone()),
+                        finding.location.clone().unwrap_or_default(),
+                        finding.message.clone(),
+                    ]);
+                }
+
+                let errors = report
+                    .findings
+                    .iter()
+                    .filter(|finding| finding.severity == lint::Severity::Error)
+                    .count();
+                let warnings = report.findings.len() - errors;
+
+                let mut result = cli.result();
+
+                result
+                    .with_command_hint(
+                        "stackablectl lint --output json",
+                        "print the report in a machine-readable format",
+                    )
+                    .with_output(format!(
+                        "{table}\n\nChecked {checked} demos, stacks and releases, found {errors} error(s) and {warnings} warning(s)",
+                        checked = report.checked
+                    ));
+
+                result.render()
+            }
+            OutputType::Json => serde_json::to_string(&report).context(SerializeJsonOutputSnafu)?,
+            OutputType::Yaml => serde_yaml::to_string(&report).context(SerializeYamlOutputSnafu)?,
+        };
+
+        if !report.has_errors() {
+            return Ok(output);
+        }
+
+        // The report is returned as part of the error, so that the command
+        // exits with a non-zero code while still listing the findings
+        LintFailedSnafu {
+            errors: report
+                .findings
+                .iter()
+                .filter(|finding| finding.severity == lint::Severity::Error)
+                .count(),
+            output,
+        }
+        .fail()
+    }
+}
@@ -0,0 +1,258 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand, ValueHint};
+use snafu::{ensure, OptionExt, ResultExt, Snafu};
+use tracing::{debug, info, instrument};
+
+use stackable_cockpit::{
+    common::list,
+    platform::{
+        demo,
+        lockfile::{self, Lockfile},
+        release,
+        stack::{self, StackSpec},
+    },
+    utils::path::PathOrUrlParseError,
+    xfer::{self, cache::Cache},
+};
+
+use crate::{cli::Cli, constants::DEFAULT_LOCKFILE_NAME};
+
+#[derive(Debug, Args)]
+#[command(long_about = "Lock a demo or stack for reproducible installs.
+
+The lockfile records the content hashes of all demo, stack and release files,
+the operator versions of the release, the resolved versions of Helm charts and
+the content hashes of all manifests. Version ranges of Helm charts, like
+'>0.0.0-0', are resolved to the highest matching version.
+
+Use \"stackablectl demo install <DEMO> --locked\" or \"stackablectl stack install
+<STACK> --locked\" to install exactly the locked files and versions.")]
+pub struct LockArgs {
+    #[command(subcommand)]
+    subcommand: LockCommands,
+
+    /// Target a specific Stackable release
+    #[arg(long, global = true)]
+    release: Option<String>,
+
+    /// Path of the lockfile to write
+    #[arg(long, global = true, value_name = "LOCKFILE", default_value = DEFAULT_LOCKFILE_NAME, value_hint = ValueHint::FilePath)]
+    lockfile: PathBuf,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LockCommands {
+    /// Lock a specific demo and its stack
+    Demo(LockDemoArgs),
+
+    /// Lock a specific stack
+    Stack(LockStackArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct LockDemoArgs {
+    /// Demo to lock
+    #[arg(
+        name = "DEMO",
+        long_help = "Demo to lock
+
+Use \"stackablectl demo list\" to display a list of available demos."
+    )]
+    demo_name: String,
+}
+
+#[derive(Debug, Args)]
+pub struct LockStackArgs {
+    /// Stack to lock
+    #[arg(
+        name = "STACK",
+        long_help = "Stack to lock
+
+Use \"stackablectl stack list\" to display a list of available stacks."
+    )]
+    stack_name: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum CmdError {
+    #[snafu(display("failed to create transfer client"))]
+    TransferClient { source: xfer::Error },
+
+    #[snafu(display("path/url parse error"))]
+    PathOrUrlParse { source: PathOrUrlParseError },
+
+    #[snafu(display("failed to build demo/stack/release list"))]
+    BuildList { source: list::Error },
+
+    #[snafu(display("no release '{release}'"))]
+    NoSuchRelease { release: String },
+
+    #[snafu(display("failed to get latest release"))]
+    LatestRelease,
+
+    #[snafu(display("no demo with name '{name}'"))]
+    NoSuchDemo { name: String },
+
+    #[snafu(display("no stack with name '{name}'"))]
+    NoSuchStack { name: String },
+
+    #[snafu(display("failed to resolve stacks included by stack {stack_name:?}"))]
+    ResolveIncludes {
+        source: stack::Error,
+        stack_name: String,
+    },
+
+    #[snafu(display("failed to lock {name:?}"))]
+    Lock {
+        source: lockfile::Error,
+        name: String,
+    },
+
+    #[snafu(display("failed to write lockfile"))]
+    WriteLockfile { source: lockfile::Error },
+}
+
+impl LockArgs {
+    #[instrument(skip(cli, cache))]
+    pub async fn run(&self, cli: &Cli, cache: Cache) -> Result<String, CmdError> {
+        debug!("Handle lock args");
+
+        let transfer_client = cli.transfer_client(cache).context(TransferClientSnafu)?;
+
+        let release_files = cli.get_release_files().context(PathOrUrlParseSnafu)?;
+        let release_list = release::ReleaseList::build(&release_files, &transfer_client)
+            .await
+            .context(BuildListSnafu)?;
+
+        let release_branch = match &self.release {
+            Some(release) => {
+                ensure!(
+                    release_list.contains_key(release),
+                    NoSuchReleaseSnafu { release }
+                );
+
+                if release == "dev" {
+                    "main".to_string()
+                } else {
+                    format!("release-{release}")
+                }
+            }
+            None => {
+                let (release_name, _) = release_list.first().context(LatestReleaseSnafu)?;
+                format!("release-{release_name}")
+            }
+        };
+
+        let stack_files = cli
+            .get_stack_files(&release_branch)
+            .context(PathOrUrlParseSnafu)?;
+        let stack_list = stack::StackList::build(&stack_files, &transfer_client)
+            .await
+            .context(BuildListSnafu)?;
+
+        let (lockfile, install_cmd) = match &self.subcommand {
+            LockCommands::Demo(args) => {
+                info!("Locking demo {}", args.demo_name);
+
+                let demo_files = cli
+                    .get_demo_files(&release_branch)
+                    .context(PathOrUrlParseSnafu)?;
+                let demo_list = demo::List::build(&demo_files, &transfer_client)
+                    .await
+                    .context(BuildListSnafu)?;
+
+                let demo = demo_list.get(&args.demo_name).context(NoSuchDemoSnafu {
+                    name: &args.demo_name,
+                })?;
+                let stack = resolve_stack(&demo.stack, &stack_list)?;
+
+                // The same files are verified when installing the demo
+                let spec_sources: Vec<_> = demo_list
+                    .sources()
+                    .iter()
+                    .chain(stack_list.sources())
+                    .chain(release_list.sources())
+                    .cloned()
+                    .collect();
+
+                let lockfile = Lockfile::for_demo(
+                    &args.demo_name,
+                    demo,
+                    &stack,
+                    &release_list,
+                    &spec_sources,
+                    &transfer_client,
+                )
+                .await
+                .context(LockSnafu {
+                    name: &args.demo_name,
+                })?;
+
+                (
+                    lockfile,
+                    format!("stackablectl demo install {} --locked", args.demo_name),
+                )
+            }
+            LockCommands::Stack(args) => {
+                info!("Locking stack {}", args.stack_name);
+
+                let stack = resolve_stack(&args.stack_name, &stack_list)?;
+
+                let spec_sources: Vec<_> = stack_list
+                    .sources()
+                    .iter()
+                    .chain(release_list.sources())
+                    .cloned()
+                    .collect();
+
+                let lockfile = Lockfile::for_stack(
+                    &args.stack_name,
+                    &stack,
+                    &release_list,
+                    &spec_sources,
+                    &transfer_client,
+                )
+                .await
+                .context(LockSnafu {
+                    name: &args.stack_name,
+                })?;
+
+                (
+                    lockfile,
+                    format!("stackablectl stack install {} --locked", args.stack_name),
+                )
+            }
+        };
+
+        lockfile
+            .write(&self.lockfile)
+            .await
+            .context(WriteLockfileSnafu)?;
+
+        let mut result = cli.result();
+
+        result
+            .with_command_hint(install_cmd, "install exactly the locked files and versions")
+            .with_output(format!(
+                "Locked {} '{}' in {}",
+                lockfile.kind,
+                lockfile.name,
+                self.lockfile.display()
+            ));
+
+        Ok(result.render())
+    }
+}
+
+/// Returns the stack named `stack_name` from the `stack_list`, with all
+/// stacks it includes resolved.
+fn resolve_stack(stack_name: &str, stack_list: &stack::StackList) -> Result<StackSpec, CmdError> {
+    let stack = stack_list
+        .get(stack_name)
+        .context(NoSuchStackSnafu { name: stack_name })?;
+
+    stack
+        .resolve_includes(stack_name, stack_list)
+        .context(ResolveIncludesSnafu { stack_name })
+}
@@ -2,7 +2,10 @@ pub mod cache;
 pub mod completions;
 pub mod debug;
 pub mod demo;
+pub mod lint;
+pub mod lock;
 pub mod operator;
 pub mod release;
 pub mod stack;
 pub mod stacklet;
+pub mod template;
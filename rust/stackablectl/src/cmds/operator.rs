@@ -26,6 +26,7 @@ use stackable_cockpit::{
         chartsource::ChartSourceMetadata,
         k8s::{self, Client},
     },
+    xfer::{self, cache::Cache},
 };
 
 use crate::{
@@ -132,6 +133,9 @@ pub struct OperatorInstalledArgs {
 
 #[derive(Debug, Snafu)]
 pub enum CmdError {
+    #[snafu(display("failed to create transfer client"))]
+    TransferClient { source: xfer::Error },
+
     #[snafu(display("invalid repository name"))]
     InvalidRepoName { source: InvalidRepoNameError },
 
@@ -176,10 +180,12 @@ pub enum CmdError {
 pub struct OperatorVersionList(HashMap<String, Vec<String>>);
 
 impl OperatorArgs {
-    pub async fn run(&self, cli: &Cli) -> Result<String, CmdError> {
+    pub async fn run(&self, cli: &Cli, cache: Cache) -> Result<String, CmdError> {
+        let transfer_client = cli.transfer_client(cache).context(TransferClientSnafu)?;
+
         match &self.subcommand {
-            OperatorCommands::List(args) => list_cmd(args, cli).await,
-            OperatorCommands::Describe(args) => describe_cmd(args, cli).await,
+            OperatorCommands::List(args) => list_cmd(args, cli, &transfer_client).await,
+            OperatorCommands::Describe(args) => describe_cmd(args, cli, &transfer_client).await,
             OperatorCommands::Install(args) => install_cmd(args, cli).await,
             OperatorCommands::Uninstall(args) => uninstall_cmd(args, cli),
             OperatorCommands::Installed(args) => installed_cmd(args, cli),
@@ -187,13 +193,18 @@ impl OperatorArgs {
     }
 }
 
-#[instrument]
-async fn list_cmd(args: &OperatorListArgs, cli: &Cli) -> Result<String, CmdError> {
+#[instrument(skip(transfer_client))]
+async fn list_cmd(
+    args: &OperatorListArgs,
+    cli: &Cli,
+    transfer_client: &xfer::Client,
+) -> Result<String, CmdError> {
     debug!("Listing operators");
 
     // Build map which maps artifacts to a chart source
     let source_index_files =
-        build_source_index_file_list(&ChartSourceType::from(cli.chart_type())).await?;
+        build_source_index_file_list(&ChartSourceType::from(cli.chart_type()), transfer_client)
+            .await?;
 
     // Iterate over all valid operators and create a list of versions grouped
     // by stable, test and dev lines
@@ -244,13 +255,18 @@ async fn list_cmd(args: &OperatorListArgs, cli: &Cli) -> Result<String, CmdError
     }
 }
 
-#[instrument]
-async fn describe_cmd(args: &OperatorDescribeArgs, cli: &Cli) -> Result<String, CmdError> {
+#[instrument(skip(transfer_client))]
+async fn describe_cmd(
+    args: &OperatorDescribeArgs,
+    cli: &Cli,
+    transfer_client: &xfer::Client,
+) -> Result<String, CmdError> {
     debug!("Describing operator {}", args.operator_name);
 
     // Build map which maps artifacts to a chart source
     let source_index_files =
-        build_source_index_file_list(&ChartSourceType::from(cli.chart_type())).await?;
+        build_source_index_file_list(&ChartSourceType::from(cli.chart_type()), transfer_client)
+            .await?;
 
     // Create a list of versions for this operator
     let versions_list = build_versions_list_for_operator(&args.operator_name, &source_index_files)?;
@@ -453,9 +469,10 @@ fn installed_cmd(args: &OperatorInstalledArgs, cli: &Cli) -> Result<String, CmdE
 }
 
 /// Builds a map which maps artifact tags to a chart source.
-#[instrument]
+#[instrument(skip(transfer_client))]
 async fn build_source_index_file_list<'a>(
     chart_source: &ChartSourceType,
+    transfer_client: &xfer::Client,
 ) -> Result<HashMap<&'a str, ChartSourceMetadata>, CmdError> {
     debug!("Building source index file list");
 
@@ -463,7 +480,9 @@ async fn build_source_index_file_list<'a>(
 
     match chart_source {
         ChartSourceType::OCI => {
-            source_index_files = oci::get_oci_index().await.context(OciSnafu)?;
+            source_index_files = oci::get_oci_index(transfer_client)
+                .await
+                .context(OciSnafu)?;
 
             debug!("OCI Repository entries: {:?}", source_index_files);
         }
@@ -478,7 +497,7 @@ async fn build_source_index_file_list<'a>(
 
                 source_index_files.insert(
                     helm_repo_name,
-                    helm::get_helm_index(helm_repo_url)
+                    helm::get_helm_index(helm_repo_url, transfer_client)
                         .await
                         .context(HelmSnafu)?,
                 );
@@ -7,7 +7,7 @@ use snafu::{ResultExt, Snafu};
 use tracing::{debug, info, instrument};
 
 use stackable_cockpit::{
-    common::list,
+    common::list::{self, ListFilter},
     constants::DEFAULT_OPERATOR_NAMESPACE,
     platform::{namespace, operator::ChartSourceType, release},
     utils::{
@@ -51,6 +51,15 @@ pub enum ReleaseCommands {
 pub struct ReleaseListArgs {
     #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
     output_type: OutputType,
+
+    /// Only list this release, or all releases starting with it, e.g. 24
+    /// lists all releases of 2024
+    #[arg(long)]
+    release: Option<String>,
+
+    /// Only list releases whose name or description contains this text
+    #[arg(long, value_name = "TEXT")]
+    search: Option<String>,
 }
 
 #[derive(Debug, Args)]
@@ -97,6 +106,9 @@ pub struct ReleaseUninstallArgs {
 
 #[derive(Debug, Snafu)]
 pub enum CmdError {
+    #[snafu(display("failed to create transfer client"))]
+    TransferClient { source: xfer::Error },
+
     #[snafu(display("failed to serialize YAML output"))]
     SerializeYamlOutput { source: serde_yaml::Error },
 
@@ -132,7 +144,7 @@ impl ReleaseArgs {
     pub async fn run(&self, cli: &Cli, cache: Cache) -> Result<String, CmdError> {
         debug!("Handle release args");
 
-        let transfer_client = xfer::Client::new_with(cache);
+        let transfer_client = cli.transfer_client(cache).context(TransferClientSnafu)?;
         let files = cli.get_release_files().context(PathOrUrlParseSnafu)?;
         let release_list = release::ReleaseList::build(&files, &transfer_client)
             .await
@@ -159,6 +171,12 @@ async fn list_cmd(
 ) -> Result<String, CmdError> {
     info!("Listing releases");
 
+    let release_list = release_list.filter(&ListFilter {
+        search: args.search.clone(),
+        release: args.release.clone(),
+        ..Default::default()
+    });
+
     match args.output_type {
         OutputType::Plain | OutputType::Table => {
             if release_list.is_empty() {
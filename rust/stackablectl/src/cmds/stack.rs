@@ -5,15 +5,17 @@ use comfy_table::{
 };
 use snafu::{ensure, OptionExt as _, ResultExt, Snafu};
 use stackable_operator::kvp::{LabelError, Labels};
-use tracing::{debug, info, instrument};
+use tracing::{debug, info, instrument, warn};
 
 use stackable_cockpit::{
     common::list,
     constants::{DEFAULT_OPERATOR_NAMESPACE, DEFAULT_PRODUCT_NAMESPACE},
     platform::{
+        install_record::{self, InstallRecord, InstallRecordKind},
+        lockfile::{self, Lockfile},
         operator::ChartSourceType,
         release,
-        stack::{self, StackInstallParameters},
+        stack::{self, StackDiffParameters, StackInstallParameters},
     },
     utils::{
         k8s::{self, Client},
@@ -23,8 +25,12 @@ use stackable_cockpit::{
 };
 
 use crate::{
-    args::{CommonClusterArgs, CommonClusterArgsError, CommonNamespaceArgs},
+    args::{
+        CommonClusterArgs, CommonClusterArgsError, CommonFilterArgs, CommonLockfileArgs,
+        CommonNamespaceArgs, CommonPruneArgs,
+    },
     cli::{Cli, OutputType},
+    utils::render_object_diffs,
 };
 
 #[derive(Debug, Args)]
@@ -50,12 +56,24 @@ pub enum StackCommands {
     /// Install a specific stack
     #[command(aliases(["i", "in"]))]
     Install(StackInstallArgs),
+
+    /// Show the changes installing a specific stack would make to the cluster
+    Diff(StackDiffArgs),
+
+    /// Estimate the resources a specific stack requires
+    Resources(StackResourcesArgs),
+
+    /// List installed stacks
+    Installed(StackInstalledArgs),
 }
 
 #[derive(Debug, Args)]
 pub struct StackListArgs {
     #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
     output_type: OutputType,
+
+    #[command(flatten)]
+    filter: CommonFilterArgs,
 }
 
 #[derive(Debug, Args)]
@@ -85,6 +103,30 @@ to specify operator versions."
     )]
     skip_release: bool,
 
+    /// Roll back all changes made to the cluster if the installation fails
+    #[arg(
+        long,
+        long_help = "Roll back all changes made to the cluster if the installation fails
+
+Objects and Helm releases created during the installation are removed again in
+reverse order. Objects and Helm releases which existed before the installation
+are left untouched."
+    )]
+    rollback_on_failure: bool,
+
+    /// Skip estimating the required resources if the stack doesn't specify them
+    #[arg(
+        long,
+        long_help = "Skip estimating the required resources if the stack doesn't specify them
+
+By default, the manifests (including Helm charts) of stacks without resource
+requests are rendered to estimate the resources, which are then validated
+against the free cluster resources. This requires network access and can take
+a while. With this flag, the validation is skipped for stacks without resource
+requests."
+    )]
+    skip_resource_estimate: bool,
+
     /// List of parameters to use when installing the stack
     #[arg(long)]
     stack_parameters: Vec<String>,
@@ -106,12 +148,112 @@ Use \"stackablectl stack describe <STACK>\" to list available parameters for eac
     #[command(flatten)]
     local_cluster: CommonClusterArgs,
 
+    #[command(flatten)]
+    prune: CommonPruneArgs,
+
+    #[command(flatten)]
+    lockfile: CommonLockfileArgs,
+
     #[command(flatten)]
     namespaces: CommonNamespaceArgs,
 }
 
+#[derive(Debug, Args)]
+pub struct StackDiffArgs {
+    /// Stack to compare against the cluster
+    #[arg(
+        name = "STACK",
+        long_help = "Stack to compare against the cluster
+
+The manifests of the stack are rendered and applied using a server-side
+dry-run. Helm charts are rendered client-side. A unified diff is printed for
+every object, ignoring managed fields and status. The release is not compared.
+
+Use \"stackablectl stack list\" to display a list of available stacks."
+    )]
+    stack_name: String,
+
+    /// List of parameters to use when installing the stack
+    #[arg(long)]
+    #[arg(long_help = "List of parameters to use when installing the stack
+
+All parameters must have the format '<parameter>=<value>'. Multiple parameters
+can be specified and are space separated. Valid parameters are:
+
+- adminPassword=admin123
+- adminUser=superuser
+- 'endpoint=https://example.com port=1234'
+
+Use \"stackablectl stack describe <STACK>\" to list available parameters for each stack.")]
+    parameters: Vec<String>,
+
+    #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
+    output_type: OutputType,
+
+    /// Namespace in the cluster used to deploy the products
+    #[arg(short = 'n', long, default_value = DEFAULT_PRODUCT_NAMESPACE, visible_aliases(["product-ns"]))]
+    product_namespace: String,
+
+    #[command(flatten)]
+    lockfile: CommonLockfileArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct StackResourcesArgs {
+    /// Stack to estimate the resources of
+    #[arg(
+        name = "STACK",
+        long_help = "Stack to estimate the resources of
+
+The manifests of the stack (including Helm charts) are rendered without
+installing them. If the cluster is reachable, templates can look up existing
+Secrets and cluster facts. Otherwise, placeholder values are used. The CPU, memory and storage requests of Deployments,
+StatefulSets, PersistentVolumeClaims and the role groups of Stackable
+clusters are summed up. Defaults of the operators are not included.
+
+Use \"-o yaml\" to print resource requests which can be used in the stack spec.
+
+Use \"stackablectl stack list\" to display a list of available stacks."
+    )]
+    stack_name: String,
+
+    /// List of parameters to use when installing the stack
+    #[arg(long)]
+    #[arg(long_help = "List of parameters to use when installing the stack
+
+All parameters must have the format '<parameter>=<value>'. Multiple parameters
+can be specified and are space separated. Valid parameters are:
+
+- adminPassword=admin123
+- adminUser=superuser
+- 'endpoint=https://example.com port=1234'
+
+Use \"stackablectl stack describe <STACK>\" to list available parameters for each stack.")]
+    parameters: Vec<String>,
+
+    #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
+    output_type: OutputType,
+
+    /// Namespace in the cluster used to deploy the products
+    #[arg(short = 'n', long, default_value = DEFAULT_PRODUCT_NAMESPACE, visible_aliases(["product-ns"]))]
+    product_namespace: String,
+}
+
+#[derive(Debug, Args)]
+pub struct StackInstalledArgs {
+    #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
+    output_type: OutputType,
+
+    /// Namespace in the cluster used to deploy the products
+    #[arg(short = 'n', long, default_value = DEFAULT_PRODUCT_NAMESPACE, visible_aliases(["product-ns"]))]
+    product_namespace: String,
+}
+
 #[derive(Debug, Snafu)]
 pub enum CmdError {
+    #[snafu(display("failed to create transfer client"))]
+    TransferClient { source: xfer::Error },
+
     #[snafu(display("path/url parse error"))]
     PathOrUrlParse { source: PathOrUrlParseError },
 
@@ -139,25 +281,100 @@ pub enum CmdError {
         stack_name: String,
     },
 
+    #[snafu(display("failed to diff stack {stack_name:?}"))]
+    DiffStack {
+        source: stack::Error,
+        stack_name: String,
+    },
+
+    #[snafu(display("failed to estimate resources of stack {stack_name:?}"))]
+    EstimateResources {
+        source: stack::Error,
+        stack_name: String,
+    },
+
     #[snafu(display("failed to build labels for stack resources"))]
     BuildLabels { source: LabelError },
 
+    #[snafu(display("failed to list install records"))]
+    ListInstallRecords { source: install_record::Error },
+
     #[snafu(display("failed to create Kubernetes client"))]
     KubeClientCreate { source: k8s::Error },
+
+    #[snafu(display("failed to read lockfile"))]
+    ReadLockfile { source: lockfile::Error },
 }
 
 impl StackArgs {
+    /// Returns if the command only renders manifests without installing
+    /// them. These commands don't add Helm repos.
+    pub fn is_read_only(&self) -> bool {
+        matches!(
+            self.subcommand,
+            StackCommands::Diff(_) | StackCommands::Resources(_)
+        )
+    }
+
     pub async fn run(&self, cli: &Cli, cache: Cache) -> Result<String, CmdError> {
         debug!("Handle stack args");
 
-        let transfer_client = xfer::Client::new_with(cache);
+        match &self.subcommand {
+            StackCommands::List(args) => {
+                let (stack_list, _) = self.build_stack_list(cli, cache, None).await?;
+                list_cmd(args, cli, stack_list, self.release.as_deref())
+            }
+            StackCommands::Describe(args) => {
+                let (stack_list, _) = self.build_stack_list(cli, cache, None).await?;
+                describe_cmd(args, cli, stack_list)
+            }
+            StackCommands::Install(args) => {
+                let lockfile = args.lockfile.read().await.context(ReadLockfileSnafu)?;
+                let (stack_list, transfer_client) =
+                    self.build_stack_list(cli, cache, lockfile.as_ref()).await?;
+
+                install_cmd(args, cli, stack_list, &transfer_client, lockfile).await
+            }
+            StackCommands::Diff(args) => {
+                let lockfile = args.lockfile.read().await.context(ReadLockfileSnafu)?;
+                let (stack_list, transfer_client) =
+                    self.build_stack_list(cli, cache, lockfile.as_ref()).await?;
+
+                diff_cmd(args, cli, stack_list, &transfer_client, lockfile).await
+            }
+            StackCommands::Resources(args) => {
+                let (stack_list, transfer_client) = self.build_stack_list(cli, cache, None).await?;
+                resources_cmd(args, cli, stack_list, &transfer_client).await
+            }
+            // Installed stacks are retrieved from the cluster and thus don't
+            // require the stack and release lists
+            StackCommands::Installed(args) => installed_cmd(args, cli).await,
+        }
+    }
+
+    /// Builds the list of stacks of the requested release. Locked installs
+    /// and diffs use the release of the `lockfile`, unless a release is
+    /// requested explicitly. Returns the transfer client used to build the
+    /// list together with the list.
+    async fn build_stack_list(
+        &self,
+        cli: &Cli,
+        cache: Cache,
+        lockfile: Option<&Lockfile>,
+    ) -> Result<(stack::StackList, xfer::Client), CmdError> {
+        let release = self
+            .release
+            .as_ref()
+            .or(lockfile.map(|lockfile| &lockfile.release));
+
+        let transfer_client = cli.transfer_client(cache).context(TransferClientSnafu)?;
 
         let release_files = cli.get_release_files().context(PathOrUrlParseSnafu)?;
         let release_list = release::ReleaseList::build(&release_files, &transfer_client)
             .await
             .context(BuildListSnafu)?;
 
-        let release_branch = match &self.release {
+        let release_branch = match release {
             Some(release) => {
                 ensure!(
                     release_list.contains_key(release),
@@ -183,13 +400,7 @@ impl StackArgs {
             .await
             .context(BuildListSnafu)?;
 
-        match &self.subcommand {
-            StackCommands::List(args) => list_cmd(args, cli, stack_list),
-            StackCommands::Describe(args) => describe_cmd(args, cli, stack_list),
-            StackCommands::Install(args) => {
-                install_cmd(args, cli, stack_list, &transfer_client).await
-            }
-        }
+        Ok((stack_list, transfer_client))
     }
 }
 
@@ -198,9 +409,12 @@ fn list_cmd(
     args: &StackListArgs,
     cli: &Cli,
     stack_list: stack::StackList,
+    release: Option<&str>,
 ) -> Result<String, CmdError> {
     info!("Listing stacks");
 
+    let stack_list = stack_list.filter(&args.filter.filter(release));
+
     match args.output_type {
         OutputType::Plain | OutputType::Table => {
             let (arrangement, preset) = match args.output_type {
@@ -210,7 +424,7 @@ fn list_cmd(
 
             let mut table = Table::new();
             table
-                .set_header(vec!["#", "STACK", "RELEASE", "DESCRIPTION"])
+                .set_header(vec!["#", "STACK", "RELEASE", "LABELS", "DESCRIPTION"])
                 .set_content_arrangement(arrangement)
                 .load_preset(preset);
 
@@ -219,6 +433,7 @@ fn list_cmd(
                     (index + 1).to_string(),
                     stack_name.clone(),
                     stack.release.clone(),
+                    stack.labels.join(", "),
                     stack.description.clone(),
                 ]);
             }
@@ -282,6 +497,7 @@ fn describe_cmd(
                     .add_row(vec!["STACK", args.stack_name.as_str()])
                     .add_row(vec!["DESCRIPTION", stack.description.as_str()])
                     .add_row(vec!["RELEASE", stack.release.as_str()])
+                    .add_row(vec!["INCLUDES", stack.includes.join(", ").as_str()])
                     .add_row(vec!["OPERATORS", stack.operators.join(", ").as_str()])
                     .add_row(vec!["LABELS", stack.labels.join(", ").as_str()])
                     .add_row(vec!["PARAMETERS", parameter_table.to_string().as_str()]);
@@ -305,12 +521,13 @@ fn describe_cmd(
     }
 }
 
-#[instrument(skip(cli, stack_list, transfer_client))]
+#[instrument(skip(cli, stack_list, transfer_client, lockfile))]
 async fn install_cmd(
     args: &StackInstallArgs,
     cli: &Cli,
     stack_list: stack::StackList,
     transfer_client: &xfer::Client,
+    lockfile: Option<Lockfile>,
 ) -> Result<String, CmdError> {
     info!("Installing stack {}", args.stack_name);
 
@@ -331,14 +548,7 @@ async fn install_cmd(
 
             let client = Client::new().await.context(KubeClientCreateSnafu)?;
 
-            // Construct labels which get attached to all dynamic objects which
-            // are part of the stack.
-            let labels = Labels::try_from([
-                ("stackable.tech/managed-by", "stackablectl"),
-                ("stackable.tech/stack", &args.stack_name),
-                ("stackable.tech/vendor", "Stackable"),
-            ])
-            .context(BuildLabelsSnafu)?;
+            let labels = build_labels(&args.stack_name)?;
 
             let install_parameters = StackInstallParameters {
                 operator_namespace: args.namespaces.operator_namespace.clone(),
@@ -346,13 +556,30 @@ async fn install_cmd(
                 stack_name: args.stack_name.clone(),
                 parameters: args.parameters.clone(),
                 skip_release: args.skip_release,
+                rollback_on_failure: args.rollback_on_failure,
+                skip_resource_estimate: args.skip_resource_estimate,
+                prune: args.prune.mode(),
                 demo_name: None,
                 labels,
                 chart_source: ChartSourceType::from(cli.chart_type()),
+                // All stack and release files are persisted in the install record
+                spec_sources: stack_list
+                    .sources()
+                    .iter()
+                    .chain(release_list.sources())
+                    .cloned()
+                    .collect(),
+                lockfile,
             };
 
-            stack_spec
-                .install(release_list, install_parameters, &client, transfer_client)
+            let pruned = stack_spec
+                .install(
+                    &stack_list,
+                    release_list,
+                    install_parameters,
+                    &client,
+                    transfer_client,
+                )
                 .await
                 .context(InstallStackSnafu {
                     stack_name: args.stack_name.clone(),
@@ -379,13 +606,258 @@ async fn install_cmd(
                 }
             );
 
+            let mut message = format!("Installed stack '{}'", args.stack_name);
+            if let Some(summary) = args.prune.summary(&pruned) {
+                message.push_str(&format!("\n\n{summary}"));
+            }
+
             output
                 .with_command_hint(operator_cmd, "display the installed operators")
                 .with_command_hint(stacklet_cmd, "display the installed stacklets")
-                .with_output(format!("Installed stack '{}'", args.stack_name));
+                .with_output(message);
 
             Ok(output.render())
         }
         None => Ok("No such stack".into()),
     }
 }
+
+/// Compares a specific stack against the live objects in the cluster
+#[instrument(skip(stack_list, transfer_client, lockfile))]
+async fn diff_cmd(
+    args: &StackDiffArgs,
+    cli: &Cli,
+    stack_list: stack::StackList,
+    transfer_client: &xfer::Client,
+    lockfile: Option<Lockfile>,
+) -> Result<String, CmdError> {
+    info!("Diffing stack {}", args.stack_name);
+
+    match stack_list.get(&args.stack_name) {
+        Some(stack_spec) => {
+            let client = Client::new().await.context(KubeClientCreateSnafu)?;
+
+            let diff_parameters = StackDiffParameters {
+                stack_name: args.stack_name.clone(),
+                product_namespace: args.product_namespace.clone(),
+                parameters: args.parameters.clone(),
+                labels: build_labels(&args.stack_name)?,
+                lockfile,
+            };
+
+            let diffs = stack_spec
+                .diff(&stack_list, diff_parameters, &client, transfer_client)
+                .await
+                .context(DiffStackSnafu {
+                    stack_name: args.stack_name.clone(),
+                })?;
+
+            match args.output_type {
+                OutputType::Plain | OutputType::Table => {
+                    let mut result = cli.result();
+
+                    result
+                        .with_command_hint(
+                            format!("stackablectl stack install {}", args.stack_name),
+                            "install the stack",
+                        )
+                        .with_output(render_object_diffs(&diffs));
+
+                    Ok(result.render())
+                }
+                OutputType::Json => serde_json::to_string(&diffs).context(SerializeJsonOutputSnafu),
+                OutputType::Yaml => serde_yaml::to_string(&diffs).context(SerializeYamlOutputSnafu),
+            }
+        }
+        None => Ok("No such stack".into()),
+    }
+}
+
+#[instrument(skip(cli, stack_list, transfer_client))]
+async fn resources_cmd(
+    args: &StackResourcesArgs,
+    cli: &Cli,
+    stack_list: stack::StackList,
+    transfer_client: &xfer::Client,
+) -> Result<String, CmdError> {
+    info!("Estimating resources of stack {}", args.stack_name);
+
+    match stack_list.get(&args.stack_name) {
+        Some(stack_spec) => {
+            // The cluster is optional, without it the manifests are rendered
+            // using placeholder values
+            let client = match Client::new().await {
+                Ok(client) => Some(client),
+                Err(err) => {
+                    warn!("Failed to connect to the cluster ({err}), rendering manifests offline");
+                    None
+                }
+            };
+
+            let estimate = stack_spec
+                .estimate_resources(
+                    &args.stack_name,
+                    &stack_list,
+                    &args.parameters,
+                    &args.product_namespace,
+                    client.as_ref(),
+                    transfer_client,
+                )
+                .await
+                .context(EstimateResourcesSnafu {
+                    stack_name: args.stack_name.clone(),
+                })?;
+
+            match args.output_type {
+                OutputType::Plain | OutputType::Table => {
+                    let (arrangement, preset) = match args.output_type {
+                        OutputType::Plain => (ContentArrangement::Disabled, NOTHING),
+                        _ => (ContentArrangement::Dynamic, UTF8_FULL),
+                    };
+
+                    let mut table = Table::new();
+                    table
+                        .set_header(vec!["KIND", "NAME", "REPLICAS", "CPU", "MEMORY", "STORAGE"])
+                        .set_content_arrangement(arrangement)
+                        .load_preset(preset);
+
+                    for workload in &estimate.workloads {
+                        let total = workload.total();
+
+                        table.add_row(vec![
+                            workload.kind.clone(),
+                            workload.name.clone(),
+                            workload.replicas.to_string(),
+                            total.cpu().to_string(),
+                            total.memory().to_string(),
+                            total.storage().to_string(),
+                        ]);
+                    }
+
+                    let total = estimate.total();
+                    table.add_row(vec![
+                        "TOTAL".to_string(),
+                        String::new(),
+                        String::new(),
+                        total.cpu().to_string(),
+                        total.memory().to_string(),
+                        total.storage().to_string(),
+                    ]);
+
+                    let mut result = cli.result();
+
+                    result
+                        .with_command_hint(
+                            format!("stackablectl stack resources {} -o yaml", args.stack_name),
+                            "print resource requests for the stack spec",
+                        )
+                        .with_command_hint(
+                            format!("stackablectl stack install {}", args.stack_name),
+                            "install the stack",
+                        )
+                        .with_output(table.to_string());
+
+                    Ok(result.render())
+                }
+                OutputType::Json => serde_json::to_string(&estimate.to_resource_requests())
+                    .context(SerializeJsonOutputSnafu),
+                OutputType::Yaml => serde_yaml::to_string(&estimate.to_resource_requests())
+                    .context(SerializeYamlOutputSnafu),
+            }
+        }
+        None => Ok("No such stack".into()),
+    }
+}
+
+/// Constructs the labels which get attached to all dynamic objects which are
+/// part of the stack.
+fn build_labels(stack_name: &str) -> Result<Labels, CmdError> {
+    Labels::try_from([
+        ("stackable.tech/managed-by", "stackablectl"),
+        ("stackable.tech/stack", stack_name),
+        ("stackable.tech/vendor", "Stackable"),
+    ])
+    .context(BuildLabelsSnafu)
+}
+
+/// Lists the install records of stacks installed in the product namespace
+#[instrument]
+async fn installed_cmd(args: &StackInstalledArgs, cli: &Cli) -> Result<String, CmdError> {
+    info!("Listing installed stacks");
+
+    let client = Client::new().await.context(KubeClientCreateSnafu)?;
+
+    let records = InstallRecord::list(
+        InstallRecordKind::Stack,
+        Some(&args.product_namespace),
+        &client,
+    )
+    .await
+    .context(ListInstallRecordsSnafu)?;
+
+    match args.output_type {
+        OutputType::Plain | OutputType::Table => {
+            let mut result = cli.result();
+
+            if records.is_empty() {
+                result
+                    .with_command_hint(
+                        "stackablectl stack install <STACK_NAME>",
+                        "install a complete stack",
+                    )
+                    .with_output("No installed stacks found");
+
+                return Ok(result.render());
+            }
+
+            let (arrangement, preset) = match args.output_type {
+                OutputType::Plain => (ContentArrangement::Disabled, NOTHING),
+                _ => (ContentArrangement::Dynamic, UTF8_FULL),
+            };
+
+            let mut table = Table::new();
+            table
+                .set_header(vec![
+                    "STACK",
+                    "RELEASE",
+                    "NAMESPACE",
+                    "PARAMETERS",
+                    "LAST INSTALLED",
+                ])
+                .set_content_arrangement(arrangement)
+                .load_preset(preset);
+
+            for record in records {
+                let parameters = record
+                    .parameters
+                    .iter()
+                    .map(|(name, value)| format!("{name}={value}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                table.add_row(vec![
+                    record.name,
+                    record.release,
+                    record.product_namespace,
+                    parameters,
+                    record
+                        .updated_at
+                        .0
+                        .format("%Y-%m-%d %H:%M:%S UTC")
+                        .to_string(),
+                ]);
+            }
+
+            result
+                .with_command_hint(
+                    "stackablectl stacklet list",
+                    "display the installed stacklets",
+                )
+                .with_output(table.to_string());
+
+            Ok(result.render())
+        }
+        OutputType::Json => serde_json::to_string(&records).context(SerializeJsonOutputSnafu),
+        OutputType::Yaml => serde_yaml::to_string(&records).context(SerializeYamlOutputSnafu),
+    }
+}
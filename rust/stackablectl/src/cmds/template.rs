@@ -0,0 +1,99 @@
+use clap::{Args, Subcommand};
+use comfy_table::{
+    presets::{NOTHING, UTF8_FULL},
+    ContentArrangement, Table,
+};
+use snafu::{ResultExt, Snafu};
+use tracing::{info, instrument};
+
+use stackable_cockpit::utils::templating::{TemplateFunctionKind, TEMPLATE_FUNCTIONS};
+
+use crate::cli::{Cli, OutputType};
+
+#[derive(Debug, Args)]
+pub struct TemplateArgs {
+    #[command(subcommand)]
+    subcommand: TemplateCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TemplateCommands {
+    /// List functions and filters available in demo and stack manifests
+    #[command(alias("fn"))]
+    #[command(
+        long_about = "List functions and filters available in demo and stack manifests.
+
+In addition to the listed ones, the built-in functions and filters of Tera are
+available, see https://keats.github.io/tera/docs/#built-ins."
+    )]
+    Functions(TemplateFunctionsArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct TemplateFunctionsArgs {
+    #[arg(short, long = "output", value_enum, default_value_t = Default::default())]
+    output_type: OutputType,
+}
+
+#[derive(Debug, Snafu)]
+pub enum CmdError {
+    #[snafu(display("failed to serialize YAML output"))]
+    SerializeYamlOutput { source: serde_yaml::Error },
+
+    #[snafu(display("failed to serialize JSON output"))]
+    SerializeJsonOutput { source: serde_json::Error },
+}
+
+impl TemplateArgs {
+    pub fn run(&self, cli: &Cli) -> Result<String, CmdError> {
+        match &self.subcommand {
+            TemplateCommands::Functions(args) => functions_cmd(args, cli),
+        }
+    }
+}
+
+#[instrument(skip(cli))]
+fn functions_cmd(args: &TemplateFunctionsArgs, cli: &Cli) -> Result<String, CmdError> {
+    info!("Listing template functions");
+
+    match args.output_type {
+        OutputType::Plain | OutputType::Table => {
+            let (arrangement, preset) = match args.output_type {
+                OutputType::Plain => (ContentArrangement::Disabled, NOTHING),
+                _ => (ContentArrangement::Dynamic, UTF8_FULL),
+            };
+
+            let mut table = Table::new();
+            table
+                .set_header(vec!["NAME", "KIND", "USAGE", "DESCRIPTION"])
+                .set_content_arrangement(arrangement)
+                .load_preset(preset);
+
+            for function in TEMPLATE_FUNCTIONS {
+                let kind = match function.kind {
+                    TemplateFunctionKind::Function => "function",
+                    TemplateFunctionKind::Filter => "filter",
+                };
+
+                table.add_row(vec![
+                    function.name,
+                    kind,
+                    function.usage,
+                    function.description,
+                ]);
+            }
+
+            let mut result = cli.result();
+
+            result.with_output(table.to_string());
+
+            Ok(result.render())
+        }
+        OutputType::Json => {
+            serde_json::to_string(TEMPLATE_FUNCTIONS).context(SerializeJsonOutputSnafu)
+        }
+        OutputType::Yaml => {
+            serde_yaml::to_string(TEMPLATE_FUNCTIONS).context(SerializeYamlOutputSnafu)
+        }
+    }
+}
@@ -16,3 +16,5 @@ pub const HELM_REPO_URL_DEV: &str = "https://repo.stackable.tech/repository/helm
 pub const USER_DIR_APPLICATION_NAME: &str = "stackablectl";
 pub const USER_DIR_ORGANIZATION_NAME: &str = "Stackable";
 pub const USER_DIR_QUALIFIER: &str = "tech";
+
+pub const DEFAULT_LOCKFILE_NAME: &str = "stackable.lock";
@@ -39,10 +39,16 @@ async fn main() -> Result<(), Error> {
     match app.run().await {
         Ok(result) => print!("{result}"),
         Err(err) => {
+            // Some commands, like lint, still produce output when failing
+            if let Some(output) = err.output() {
+                print!("{output}");
+            }
+
             let mut output = app.error();
             output.with_error_report(err);
 
-            eprint!("{}", output.render())
+            eprint!("{}", output.render());
+            std::process::exit(1);
         }
     }
 
@@ -1,8 +1,9 @@
 use std::env;
 
 use snafu::Snafu;
-use stackable_cockpit::constants::{
-    HELM_REPO_NAME_DEV, HELM_REPO_NAME_STABLE, HELM_REPO_NAME_TEST,
+use stackable_cockpit::{
+    constants::{HELM_REPO_NAME_DEV, HELM_REPO_NAME_STABLE, HELM_REPO_NAME_TEST},
+    platform::diff::{ObjectDiff, ObjectDiffStatus},
 };
 
 use crate::constants::{HELM_REPO_URL_DEV, HELM_REPO_URL_STABLE, HELM_REPO_URL_TEST};
@@ -38,3 +39,31 @@ where
 pub fn use_colored_output(use_color: bool) -> bool {
     use_color && env::var_os("NO_COLOR").is_none()
 }
+
+/// Renders the object diffs of a demo or stack as plain text. Each object is
+/// listed with its status, followed by the unified diff for new and changed
+/// objects. A summary line is appended at the end.
+pub fn render_object_diffs(diffs: &[ObjectDiff]) -> String {
+    let mut output = String::new();
+
+    for diff in diffs {
+        output.push_str(&format!("=== {}: {}\n", diff.status, diff.object));
+
+        if !diff.diff.is_empty() {
+            output.push_str(&diff.diff);
+            output.push('\n');
+        }
+    }
+
+    let count = |status| diffs.iter().filter(|diff| diff.status == status).count();
+
+    output.push_str(&format!(
+        "{} object(s): {} new, {} changed, {} unchanged",
+        diffs.len(),
+        count(ObjectDiffStatus::New),
+        count(ObjectDiffStatus::Changed),
+        count(ObjectDiffStatus::Unchanged)
+    ));
+
+    output
+}